@@ -0,0 +1,55 @@
+//! Compares opening an in-memory wheel by borrowing its bytes against copying them.
+//! Run with `cargo bench --bench open`.
+
+use std::io::Cursor;
+use std::io::Write;
+use std::time::Instant;
+
+use pep_427::Wheel;
+use zip::write::FileOptions;
+use zip::ZipWriter;
+
+const FILE_COUNT: usize = 4096;
+const ITERATIONS: usize = 100;
+
+fn build_wheel() -> Vec<u8> {
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    for i in 0..FILE_COUNT {
+        writer
+            .start_file(format!("demo/file_{i}.py"), FileOptions::default())
+            .unwrap();
+        writer.write_all(&[b'#'; 1024]).unwrap();
+    }
+    writer
+        .start_file("demo-1.0.dist-info/WHEEL", FileOptions::default())
+        .unwrap();
+    writer
+        .write_all(b"Wheel-Version: 1.0\nGenerator: bench\nRoot-Is-Purelib: true\n")
+        .unwrap();
+    writer.finish().unwrap().into_inner()
+}
+
+fn main() {
+    let bytes = build_wheel();
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let mut wheel = Wheel::from_bytes("demo-1.0-py3-none-any.whl", bytes.as_slice()).unwrap();
+        wheel.wheel_file().unwrap();
+    }
+    println!(
+        "borrowed: {:?} per open",
+        start.elapsed() / ITERATIONS as u32
+    );
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let mut wheel =
+            Wheel::open("demo-1.0-py3-none-any.whl", Cursor::new(bytes.clone())).unwrap();
+        wheel.wheel_file().unwrap();
+    }
+    println!(
+        "copied:   {:?} per open",
+        start.elapsed() / ITERATIONS as u32
+    );
+}
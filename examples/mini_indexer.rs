@@ -0,0 +1,143 @@
+//! A minimal package index which turns a directory of wheels into a static
+//! [PEP 691](https://peps.python.org/pep-0691/) JSON simple repository.
+//! Run with `cargo run --example mini_indexer -- <wheel directory> <output directory>`.
+//!
+//! Writes `<output>/simple/index.json` listing every project,
+//! `<output>/simple/<project>/index.json` listing each project's wheels, and copies the wheels
+//! and their `METADATA` files into `<output>/files/`. Wheels which fail verification are skipped.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+use std::process;
+
+use pep_427::HashAlgorithm;
+use pep_427::Wheel;
+use serde_json::json;
+use serde_json::Value;
+use sha2::Digest;
+use sha2::Sha256;
+use zip::ZipArchive;
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let [_, wheel_dir, output_dir] = args.as_slice() else {
+        eprintln!("usage: mini_indexer <wheel directory> <output directory>");
+        process::exit(2);
+    };
+    if let Err(e) = index(Path::new(wheel_dir), Path::new(output_dir)) {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
+
+fn index(wheel_dir: &Path, output_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let mut paths = fs::read_dir(wheel_dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.sort();
+
+    // Normalized project names mapped to their files, sorted by file name.
+    let mut projects = BTreeMap::<String, Vec<Value>>::new();
+    let files_dir = output_dir.join("files");
+    fs::create_dir_all(&files_dir)?;
+    for path in paths {
+        let Some(filename) = path.file_name().and_then(|filename| filename.to_str()) else {
+            continue;
+        };
+        if !filename.ends_with(".whl") {
+            continue;
+        }
+        match index_file(&path, filename) {
+            Ok((project, file, metadata)) => {
+                fs::copy(&path, files_dir.join(filename))?;
+                fs::write(files_dir.join(format!("{filename}.metadata")), metadata)?;
+                projects.entry(project).or_default().push(file);
+            }
+            Err(e) => eprintln!("skipping {filename}: {e}"),
+        }
+    }
+
+    let simple = output_dir.join("simple");
+    for (project, files) in &projects {
+        let page = json!({
+            "meta": {"api-version": "1.0"},
+            "name": project,
+            "files": files,
+        });
+        fs::create_dir_all(simple.join(project))?;
+        fs::write(
+            simple.join(project).join("index.json"),
+            serde_json::to_string_pretty(&page)?,
+        )?;
+    }
+    let index = json!({
+        "meta": {"api-version": "1.0"},
+        "projects": projects.keys().map(|name| json!({"name": name})).collect::<Vec<_>>(),
+    });
+    fs::create_dir_all(&simple)?;
+    fs::write(
+        simple.join("index.json"),
+        serde_json::to_string_pretty(&index)?,
+    )?;
+    println!("indexed {} projects", projects.len());
+    Ok(())
+}
+
+/// Verifies a wheel, and describes it as a PEP 691 file entry. Returns the project name,
+/// the file entry, and the contents of `METADATA`.
+fn index_file(path: &Path, filename: &str) -> Result<(String, Value, Vec<u8>), Box<dyn Error>> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut File::open(path)?, &mut hasher)?;
+    let sha256 = hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+
+    let mut wheel = Wheel::open(filename, File::open(path)?)?;
+    wheel.verify()?;
+    let wheel_name = wheel.wheel_name().clone();
+    let headers = wheel.metadata_headers()?;
+
+    // METADATA is served on its own, so clients can resolve without downloading the wheel
+    // (PEP 658 and PEP 714).
+    let metadata_path = format!(
+        "{}-{}.dist-info/METADATA",
+        wheel_name.escaped_distribution(),
+        wheel_name.version
+    );
+    let checksums = wheel.checksums(HashAlgorithm::Sha256)?;
+    let metadata_sha256 = checksums
+        .digests
+        .get(&metadata_path)
+        .ok_or("wheel has no METADATA")?;
+    let mut metadata = Vec::new();
+    ZipArchive::new(File::open(path)?)?
+        .by_name(&metadata_path)?
+        .read_to_end(&mut metadata)?;
+
+    let mut file = json!({
+        "filename": filename,
+        "url": format!("../../files/{filename}"),
+        "hashes": {"sha256": sha256},
+        "core-metadata": {"sha256": metadata_sha256},
+    });
+    if let Some(requires_python) = headers.get("Requires-Python") {
+        file["requires-python"] = json!(requires_python);
+    }
+    println!(
+        "{filename}: {}",
+        wheel_name
+            .to_packaging_tags()
+            .into_iter()
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    Ok((wheel_name.distribution, file, metadata))
+}
@@ -0,0 +1,268 @@
+//! A minimal installer which installs a wheel into a virtual environment.
+//! Run with `cargo run --example mini_installer -- <wheel> <venv>`.
+//!
+//! This crate doesn't install wheels itself. This example shows how an installer can be built
+//! on its API, and is deliberately incomplete: it assumes a POSIX virtual environment, and
+//! doesn't resolve dependencies, compile bytecode, or uninstall a previously installed version.
+
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::fs::File;
+use std::io;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::process;
+use std::str::FromStr;
+
+use globset::Glob;
+use globset::GlobSetBuilder;
+use pep440_rs::Version;
+use pep_427::PathCategory;
+use pep_427::SpecifierSet;
+use pep_427::Wheel;
+
+/// Where `RECORD` paths are relative to, and how to get from there to the scheme directories.
+const SCRIPTS_FROM_SITE_PACKAGES: &str = "../../../bin";
+const DATA_FROM_SITE_PACKAGES: &str = "../../..";
+
+/// A `RECORD` row: the path, and its digest and size unless it isn't hashed.
+type RecordRow = (String, Option<(String, Option<usize>)>);
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let [_, wheel_path, venv] = args.as_slice() else {
+        eprintln!("usage: mini_installer <wheel> <venv>");
+        process::exit(2);
+    };
+    if let Err(e) = install(Path::new(wheel_path), Path::new(venv)) {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
+
+fn install(wheel_path: &Path, venv: &Path) -> Result<(), Box<dyn Error>> {
+    let python_version = venv_python_version(venv)?;
+    let site_packages = venv
+        .join("lib")
+        .join(format!("python{}.{}", python_version.0, python_version.1))
+        .join("site-packages");
+    let scripts = venv.join("bin");
+
+    let filename = wheel_path
+        .file_name()
+        .and_then(|filename| filename.to_str())
+        .ok_or("wheel path has no file name")?;
+    let mut wheel = Wheel::open(filename, File::open(wheel_path)?)?;
+    let wheel_name = wheel.wheel_name().clone();
+
+    // Check the wheel is for this interpreter before touching the environment.
+    if !wheel_name.supports_python_abi(python_version) {
+        return Err(format!("{filename} is not compatible with Python {python_version:?}").into());
+    }
+    let headers = wheel.metadata_headers()?;
+    if let Some(requires_python) = headers.get("Requires-Python") {
+        let specifiers = SpecifierSet::from_str(requires_python)?;
+        let version = Version::from_str(&format!("{}.{}", python_version.0, python_version.1))?;
+        if !specifiers.contains(&version) {
+            return Err(format!("{filename} requires Python {requires_python}").into());
+        }
+    }
+    let root_is_purelib = wheel.wheel_file()?.root_is_purelib;
+    println!(
+        "installing {} {} ({})",
+        headers.get("Name").unwrap_or(&wheel_name.distribution),
+        wheel_name.version,
+        if root_is_purelib {
+            "purelib"
+        } else {
+            "platlib"
+        },
+    );
+
+    let entries = wheel
+        .classify_entries()
+        .into_iter()
+        .map(|(path, category)| (path.to_owned(), category))
+        .collect::<Vec<_>>();
+    if let Some((path, _)) = entries
+        .iter()
+        .find(|(_, category)| *category == PathCategory::OutsideRoot)
+    {
+        return Err(format!("{path} would be installed outside the environment").into());
+    }
+
+    // Extract everything into a staging directory. Each file RECORD lists with a digest is
+    // verified as it's written, and unpacking fails on any file RECORD doesn't list, so only
+    // files RECORD lists without a digest (such as RECORD itself) and RECORD's signatures
+    // reach the environment unverified.
+    let staging = venv.join(format!(
+        ".mini-installer-{}",
+        wheel_name.escaped_distribution()
+    ));
+    let everything = GlobSetBuilder::new().add(Glob::new("**")?).build()?;
+    wheel.unpack_matching(&everything, &staging)?;
+
+    let record_file = wheel.record_file()?;
+    let digests = record_file
+        .records
+        .iter()
+        .map(|record| (record.filename.as_str(), record))
+        .collect::<HashMap<_, _>>();
+    let dist_info = format!(
+        "{}-{}.dist-info",
+        wheel_name.escaped_distribution(),
+        wheel_name.version
+    );
+    let data_prefix = format!(
+        "{}-{}.data/",
+        wheel_name.escaped_distribution(),
+        wheel_name.version
+    );
+
+    let mut installed = Vec::new();
+    for (path, category) in &entries {
+        if path == &format!("{dist_info}/RECORD") {
+            continue;
+        }
+        let in_data = |scheme: &str| {
+            path.strip_prefix(&data_prefix)
+                .and_then(|path| path.strip_prefix(scheme))
+                .and_then(|path| path.strip_prefix('/'))
+                .unwrap_or(path)
+                .to_owned()
+        };
+        let (record_path, destination) = match category {
+            PathCategory::Root | PathCategory::DistInfo => (path.clone(), site_packages.join(path)),
+            PathCategory::Data(scheme) if scheme == "purelib" || scheme == "platlib" => {
+                let path = in_data(scheme);
+                (path.clone(), site_packages.join(&path))
+            }
+            PathCategory::Data(scheme) if scheme == "data" => {
+                let path = in_data(scheme);
+                (
+                    format!("{DATA_FROM_SITE_PACKAGES}/{path}"),
+                    venv.join(&path),
+                )
+            }
+            PathCategory::DataScript => {
+                // Scripts may be rewritten, so they're recorded without a digest.
+                let script = in_data("scripts");
+                install_script(&staging.join(path), &scripts.join(&script), venv)?;
+                installed.push((format!("{SCRIPTS_FROM_SITE_PACKAGES}/{script}"), None));
+                continue;
+            }
+            PathCategory::Data(scheme) => {
+                println!("skipping {path}: the {scheme} scheme isn't supported");
+                continue;
+            }
+            _ => {
+                println!("skipping {path}");
+                continue;
+            }
+        };
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::rename(staging.join(path), &destination)?;
+        let digest = digests.get(path.as_str()).and_then(|record| {
+            let digest = record.digest.as_ref()?;
+            Some((
+                format!("{}={}", digest.method, digest.b64_digest),
+                record.file_size,
+            ))
+        });
+        installed.push((record_path, digest));
+    }
+
+    for entry_point in wheel.entry_points()?.group("console_scripts") {
+        let destination = scripts.join(&entry_point.name);
+        let attr = entry_point
+            .attr()
+            .ok_or_else(|| format!("console script {} has no attribute", entry_point.name))?;
+        let import_name = attr.split('.').next().unwrap_or(attr);
+        let script = format!(
+            "#!{}\nimport sys\nfrom {} import {import_name}\nif __name__ == \"__main__\":\n    sys.exit({attr}())\n",
+            venv.join("bin").join("python").display(),
+            entry_point.module(),
+        );
+        fs::create_dir_all(&scripts)?;
+        fs::write(&destination, script)?;
+        make_executable(&destination)?;
+        installed.push((
+            format!("{SCRIPTS_FROM_SITE_PACKAGES}/{}", entry_point.name),
+            None,
+        ));
+    }
+
+    let dist_info_path = site_packages.join(&dist_info);
+    fs::write(dist_info_path.join("INSTALLER"), "mini-installer\n")?;
+    installed.push((format!("{dist_info}/INSTALLER"), None));
+    installed.push((format!("{dist_info}/RECORD"), None));
+    write_record(&dist_info_path.join("RECORD"), &installed)?;
+    fs::remove_dir_all(&staging)?;
+
+    for warning in wheel.warnings() {
+        println!("warning: {warning}");
+    }
+    println!("installed {} files", installed.len());
+    Ok(())
+}
+
+/// Reads the Python version from the environment's `pyvenv.cfg`, e.g. `version = 3.11.4`.
+fn venv_python_version(venv: &Path) -> Result<(u8, u8), Box<dyn Error>> {
+    let config = fs::read_to_string(venv.join("pyvenv.cfg"))?;
+    let version = config
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .find(|(key, _)| matches!(key.trim(), "version" | "version_info"))
+        .map(|(_, value)| value.trim())
+        .ok_or("pyvenv.cfg has no version")?;
+    let mut parts = version.split('.');
+    let major = parts.next().ok_or("malformed version")?.parse()?;
+    let minor = parts.next().ok_or("malformed version")?.parse()?;
+    Ok((major, minor))
+}
+
+/// Installs a script from `.data/scripts/`, pointing a `#!python` shebang at the environment.
+fn install_script(source: &Path, destination: &Path, venv: &Path) -> Result<(), Box<dyn Error>> {
+    let mut contents = fs::read(source)?;
+    if let Some(rest) = contents.strip_prefix(b"#!python") {
+        let shebang = format!("#!{}", venv.join("bin").join("python").display());
+        contents = [shebang.as_bytes(), rest].concat();
+    }
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(destination, contents)?;
+    make_executable(destination)?;
+    Ok(())
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> io::Result<()> {
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+fn write_record(path: &Path, installed: &[RecordRow]) -> Result<(), Box<dyn Error>> {
+    let mut writer = csv::Writer::from_path(path)?;
+    for (path, digest) in installed {
+        match digest {
+            Some((digest, size)) => writer.write_record([
+                path.as_str(),
+                digest.as_str(),
+                &size.map(|size| size.to_string()).unwrap_or_default(),
+            ])?,
+            None => writer.write_record([path.as_str(), "", ""])?,
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
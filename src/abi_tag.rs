@@ -0,0 +1,291 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::WheelName;
+
+/// An ABI tag, e.g. `cp313t` or `abi3`, with CPython's ABI flags broken out.
+///
+/// Comparing ABI tags as strings goes wrong for builds with flags: a debug interpreter can
+/// load extensions for the release ABI, while a free-threaded one can't load either the
+/// release ABI or `abi3`. Use [`AbiTag::supports`] instead.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum AbiTag {
+    /// `none`: no extension modules, or none which depend on an ABI.
+    None,
+    /// `abi3`: CPython's stable ABI.
+    Abi3,
+    /// A version-specific CPython ABI, e.g. `cp311`, `cp313td`, or `cp37m`.
+    CPython {
+        version: (u8, u8),
+        /// `t`: built with the GIL disabled (CPython 3.13 and later).
+        free_threaded: bool,
+        /// `d`: built with `Py_DEBUG`.
+        debug: bool,
+        /// `m`: built with pymalloc (CPython before 3.8).
+        pymalloc: bool,
+    },
+    /// A PyPy ABI, e.g. `pypy310_pp73` for PyPy 7.3 implementing Python 3.10. PyPy 2 names
+    /// only its own version, e.g. `pypy_73`.
+    PyPy {
+        python_version: Option<(u8, u8)>,
+        pypy_version: (u8, u8),
+    },
+    /// A GraalPy ABI, e.g. `graalpy242_311_native` for GraalPy 24.2 implementing Python 3.11.
+    GraalPy {
+        graalpy_version: (u8, u8),
+        python_version: (u8, u8),
+    },
+    /// Any other ABI tag, lowercased, e.g. `ironpython_27`.
+    Other(String),
+}
+
+impl AbiTag {
+    /// Whether an interpreter with this ABI can load a wheel built for `wheel_abi`,
+    /// following `packaging.tags.cpython_tags`. Only the ABI is compared; the wheel's
+    /// interpreter tag decides which versions an `abi3` wheel applies to.
+    pub fn supports(&self, wheel_abi: &AbiTag) -> bool {
+        match (self, wheel_abi) {
+            (_, AbiTag::None) => true,
+            (
+                AbiTag::CPython {
+                    version,
+                    free_threaded,
+                    ..
+                },
+                AbiTag::Abi3,
+            ) => *version >= (3, 2) && !free_threaded,
+            (
+                AbiTag::CPython {
+                    version,
+                    free_threaded,
+                    debug,
+                    ..
+                },
+                AbiTag::CPython {
+                    version: wheel_version,
+                    free_threaded: wheel_free_threaded,
+                    debug: wheel_debug,
+                    pymalloc: wheel_pymalloc,
+                },
+            ) if version == wheel_version && free_threaded == wheel_free_threaded => {
+                // Since 3.8, debug builds can also load extensions for the release ABI.
+                self == wheel_abi
+                    || (*debug && *version >= (3, 8) && !wheel_debug && !wheel_pymalloc)
+            }
+            (interpreter, wheel_abi) => interpreter == wheel_abi,
+        }
+    }
+}
+
+impl From<&str> for AbiTag {
+    fn from(tag: &str) -> Self {
+        let tag = tag.to_ascii_lowercase();
+        match tag.as_str() {
+            "none" => return AbiTag::None,
+            "abi3" => return AbiTag::Abi3,
+            _ => {}
+        }
+        parse_cpython_abi(&tag)
+            .or_else(|| parse_pypy_abi(&tag))
+            .or_else(|| parse_graalpy_abi(&tag))
+            .unwrap_or(AbiTag::Other(tag))
+    }
+}
+
+/// Splits a run of digits like `310` into a version, the last `minor_len` digits being the minor
+/// version.
+fn parse_version(digits: &str, minor_len: usize) -> Option<(u8, u8)> {
+    if digits.len() <= minor_len || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (major, minor) = digits.split_at(digits.len() - minor_len);
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+/// Parses `pypy<python version>_pp<pypy version>`, or `pypy_<pypy version>` from PyPy 2.
+fn parse_pypy_abi(tag: &str) -> Option<AbiTag> {
+    let (python, pypy) = tag.strip_prefix("pypy")?.split_once('_')?;
+    let python_version = match pypy.strip_prefix("pp") {
+        Some(_) => Some(parse_version(python, python.len().checked_sub(1)?)?),
+        None if python.is_empty() => None,
+        None => return None,
+    };
+    let pypy = pypy.strip_prefix("pp").unwrap_or(pypy);
+    Some(AbiTag::PyPy {
+        python_version,
+        pypy_version: parse_version(pypy, pypy.len().checked_sub(1)?)?,
+    })
+}
+
+/// Parses `graalpy<graalpy version>_<python version>_native`.
+fn parse_graalpy_abi(tag: &str) -> Option<AbiTag> {
+    let rest = tag.strip_prefix("graalpy")?.strip_suffix("_native")?;
+    let (graalpy, python) = rest.split_once('_')?;
+    Some(AbiTag::GraalPy {
+        // GraalPy's major versions are two digits, e.g. 24.2 is `242`.
+        graalpy_version: parse_version(graalpy, graalpy.len().checked_sub(2)?)?,
+        python_version: parse_version(python, python.len().checked_sub(1)?)?,
+    })
+}
+
+/// Parses `cp` followed by a version and any of the `t`, `d`, and `m` flags, each at most once.
+fn parse_cpython_abi(tag: &str) -> Option<AbiTag> {
+    let rest = tag.strip_prefix("cp")?;
+    let digits_len = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (digits, flags) = rest.split_at(digits_len);
+    if digits.len() < 2 {
+        return None;
+    }
+    let (major, minor) = digits.split_at(1);
+    let version = (major.parse().ok()?, minor.parse().ok()?);
+
+    let (mut free_threaded, mut debug, mut pymalloc) = (false, false, false);
+    for flag in flags.chars() {
+        let seen = match flag {
+            't' => &mut free_threaded,
+            'd' => &mut debug,
+            'm' => &mut pymalloc,
+            _ => return None,
+        };
+        if *seen {
+            return None;
+        }
+        *seen = true;
+    }
+    Some(AbiTag::CPython {
+        version,
+        free_threaded,
+        debug,
+        pymalloc,
+    })
+}
+
+/// Formats the tag with its flags in the order CPython writes them, e.g. `cp313td`.
+impl fmt::Display for AbiTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbiTag::None => f.write_str("none"),
+            AbiTag::Abi3 => f.write_str("abi3"),
+            AbiTag::CPython {
+                version: (major, minor),
+                free_threaded,
+                debug,
+                pymalloc,
+            } => {
+                write!(f, "cp{major}{minor}")?;
+                for (flag, set) in [('t', free_threaded), ('d', debug), ('m', pymalloc)] {
+                    if *set {
+                        write!(f, "{flag}")?;
+                    }
+                }
+                Ok(())
+            }
+            AbiTag::PyPy {
+                python_version: Some((major, minor)),
+                pypy_version: (pypy_major, pypy_minor),
+            } => write!(f, "pypy{major}{minor}_pp{pypy_major}{pypy_minor}"),
+            AbiTag::PyPy {
+                python_version: None,
+                pypy_version: (pypy_major, pypy_minor),
+            } => write!(f, "pypy_{pypy_major}{pypy_minor}"),
+            AbiTag::GraalPy {
+                graalpy_version: (graalpy_major, graalpy_minor),
+                python_version: (major, minor),
+            } => write!(
+                f,
+                "graalpy{graalpy_major}{graalpy_minor}_{major}{minor}_native"
+            ),
+            AbiTag::Other(tag) => f.write_str(tag),
+        }
+    }
+}
+
+impl WheelName {
+    /// The ABI tags in this wheel's compressed ABI tag, e.g. `cp313` and `cp313t`.
+    pub fn abi_tags(&self) -> BTreeSet<AbiTag> {
+        self.abi_tag.split('.').map(AbiTag::from).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn cpython(version: (u8, u8), flags: &str) -> AbiTag {
+        AbiTag::CPython {
+            version,
+            free_threaded: flags.contains('t'),
+            debug: flags.contains('d'),
+            pymalloc: flags.contains('m'),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        for (tag, abi_tag) in [
+            ("none", AbiTag::None),
+            ("ABI3", AbiTag::Abi3),
+            ("cp313t", cpython((3, 13), "t")),
+            ("cp313td", cpython((3, 13), "td")),
+            ("cp37dm", cpython((3, 7), "dm")),
+            ("cp27mu", AbiTag::Other("cp27mu".to_string())),
+            ("cp313tt", AbiTag::Other("cp313tt".to_string())),
+            (
+                "pypy310_pp73",
+                AbiTag::PyPy {
+                    python_version: Some((3, 10)),
+                    pypy_version: (7, 3),
+                },
+            ),
+            (
+                "pypy_73",
+                AbiTag::PyPy {
+                    python_version: None,
+                    pypy_version: (7, 3),
+                },
+            ),
+            (
+                "graalpy242_311_native",
+                AbiTag::GraalPy {
+                    graalpy_version: (24, 2),
+                    python_version: (3, 11),
+                },
+            ),
+            ("ironpython_27", AbiTag::Other("ironpython_27".to_string())),
+        ] {
+            assert_eq!(AbiTag::from(tag), abi_tag, "{tag}");
+        }
+        assert_eq!(AbiTag::from("cp37md").to_string(), "cp37dm");
+        for tag in ["pypy39_pp73", "pypy_73", "graalpy231_310_native"] {
+            assert_eq!(AbiTag::from(tag).to_string(), tag);
+        }
+    }
+
+    #[test]
+    fn test_supports() {
+        let release = cpython((3, 13), "");
+        let free_threaded = cpython((3, 13), "t");
+        let debug = cpython((3, 13), "d");
+
+        assert!(release.supports(&AbiTag::None));
+        assert!(release.supports(&AbiTag::Abi3));
+        assert!(release.supports(&release));
+        assert!(!release.supports(&free_threaded));
+        assert!(!release.supports(&debug));
+
+        assert!(free_threaded.supports(&AbiTag::None));
+        assert!(!free_threaded.supports(&AbiTag::Abi3));
+        assert!(!free_threaded.supports(&release));
+        assert!(free_threaded.supports(&free_threaded));
+
+        assert!(debug.supports(&release));
+        assert!(debug.supports(&debug));
+        assert!(!cpython((3, 7), "dm").supports(&cpython((3, 7), "m")));
+        assert!(!release.supports(&cpython((3, 12), "")));
+    }
+}
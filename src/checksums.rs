@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Read;
+use std::io::Seek;
+
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::hash::encode_hex;
+use crate::hash::HASH_BUFFER_SIZE;
+use crate::HashAlgorithm;
+use crate::Wheel;
+use crate::WheelError;
+
+/// Digests of every file in a wheel, computed from the archive contents rather than `RECORD`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checksums {
+    pub algorithm: HashAlgorithm,
+    /// Archive paths mapped to lowercase hex digests, sorted by path.
+    pub digests: BTreeMap<String, String>,
+}
+
+/// Formats the checksums like `sha256sum` output, e.g. a `SHA256SUMS` file.
+impl fmt::Display for Checksums {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (path, digest) in &self.digests {
+            writeln!(f, "{digest}  {path}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Hashes every file in the archive with `algorithm`.
+    pub fn checksums(&mut self, algorithm: HashAlgorithm) -> Result<Checksums, WheelError> {
+        let mut buffer = vec![0; HASH_BUFFER_SIZE];
+        let mut digests = BTreeMap::new();
+        for i in 0..self.archive.len() {
+            let zip_file = self.archive.by_index(i)?;
+            if zip_file.is_dir() {
+                continue;
+            }
+            let path = zip_file.name().to_owned();
+            self.limits.check_size(&path, None, zip_file.size())?;
+            let mut zip_file = self.limits.take_entry(zip_file);
+            let (digest, size) = algorithm.hash_reader(&mut zip_file, &mut buffer)?;
+            self.limits.check_size(&path, None, size)?;
+            digests.insert(path, encode_hex(&digest));
+        }
+        Ok(Checksums { algorithm, digests })
+    }
+
+    /// A hex SHA-256 identifying the wheel's logical contents: every path in `RECORD` and its
+    /// recorded digest, sorted by path. Unlike a hash of the wheel file, it doesn't depend on
+    /// the order, timestamps, or compression of the archive's entries, so rebuilds of
+    /// identical contents share an identity.
+    ///
+    /// The identity trusts `RECORD` without hashing any files; use [`Wheel::verify`] first
+    /// for wheels from untrusted sources. `RECORD` itself, which has no digest, is excluded.
+    pub fn content_identity(&mut self) -> Result<String, WheelError> {
+        let record_path = self.dist_info_path("RECORD");
+        let mut rows = BTreeMap::new();
+        for record in self.record_file()?.records {
+            if record.filename == record_path {
+                continue;
+            }
+            let digest = record
+                .digest
+                .map(|digest| format!("{}={}", digest.method, digest.b64_digest))
+                .unwrap_or_default();
+            rows.insert(record.filename, digest);
+        }
+        let mut hasher = Sha256::new();
+        for (path, digest) in &rows {
+            hasher.update(path.as_bytes());
+            hasher.update(b"\0");
+            hasher.update(digest.as_bytes());
+            hasher.update(b"\n");
+        }
+        Ok(encode_hex(&hasher.finalize()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::io::Write;
+
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    use super::*;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_checksums() -> Result<(), WheelError> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .add_directory("demo", FileOptions::default())
+            .unwrap();
+        writer
+            .start_file("demo/__init__.py", FileOptions::default())
+            .unwrap();
+        writer.write_all(b"hello world").unwrap();
+        let reader = writer.finish().unwrap();
+
+        let mut wheel = Wheel::open("demo-1.0-py3-none-any.whl", reader)?;
+        let checksums = wheel.checksums(HashAlgorithm::Sha256)?;
+        assert_eq!(
+            checksums.to_string(),
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9  demo/__init__.py\n",
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_content_identity() -> Result<(), WheelError> {
+        let builder = WheelBuilder::new("demo-1.0-py3-none-any.whl")?;
+        let identity = builder
+            .clone()
+            .file("demo/__init__.py", "hello world")
+            .file("demo/core.py", "")
+            .open()?
+            .content_identity()?;
+        let reordered = builder
+            .clone()
+            .file("demo/core.py", "")
+            .file("demo/__init__.py", "hello world")
+            .open()?
+            .content_identity()?;
+        let changed = builder
+            .file("demo/__init__.py", "hello world!")
+            .file("demo/core.py", "")
+            .open()?
+            .content_identity()?;
+        assert_eq!(identity, reordered);
+        assert_ne!(identity, changed);
+        assert_eq!(identity.len(), 64);
+        Ok(())
+    }
+}
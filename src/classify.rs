@@ -0,0 +1,110 @@
+use std::io::Read;
+use std::io::Seek;
+
+use crate::Wheel;
+use crate::WheelName;
+
+/// Where an archive path would be installed, for deciding which files to allow.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum PathCategory {
+    /// A file at the root of the archive, installed into purelib or platlib
+    /// depending on `Root-Is-Purelib`.
+    Root,
+    /// A file in the `.dist-info` directory.
+    DistInfo,
+    /// A file in `.data/scripts/`, which installers make executable and put on `PATH`.
+    DataScript,
+    /// A file in any other `.data/<scheme>/` directory, e.g. `headers` or `data`, and the scheme.
+    Data(String),
+    /// A path which is absolute, contains `..`, or uses Windows separators or drive letters,
+    /// so it may be installed outside of its scheme directory.
+    OutsideRoot,
+    /// A path with a component starting with `.`, e.g. `.git/config` or `demo/.env`.
+    Dotfile,
+}
+
+impl WheelName {
+    /// Classifies a path from this wheel's archive or `RECORD` file.
+    ///
+    /// [`PathCategory::OutsideRoot`] takes precedence over [`PathCategory::Dotfile`],
+    /// which takes precedence over the rest.
+    pub fn classify_path(&self, path: &str) -> PathCategory {
+        classify_path(
+            path,
+            &format!("{}-{}", self.escaped_distribution(), self.version),
+        )
+    }
+}
+
+/// Classifies `path` in a wheel whose `.dist-info` and `.data` directories start with
+/// `stem`, e.g. `demo-1.0`.
+fn classify_path(path: &str, stem: &str) -> PathCategory {
+    let components = path.split('/').collect::<Vec<_>>();
+    if path.starts_with('/')
+        || path.contains('\\')
+        || components[0].contains(':')
+        || components.contains(&"..")
+    {
+        return PathCategory::OutsideRoot;
+    }
+    if components
+        .iter()
+        .any(|component| component.starts_with('.'))
+    {
+        return PathCategory::Dotfile;
+    }
+
+    match components[0].strip_prefix(stem) {
+        Some(".dist-info") => PathCategory::DistInfo,
+        Some(".data") => match components.get(1) {
+            Some(&"scripts") => PathCategory::DataScript,
+            Some(scheme) => PathCategory::Data((*scheme).to_owned()),
+            None => PathCategory::Data(String::new()),
+        },
+        _ => PathCategory::Root,
+    }
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Every file in the archive with its [`PathCategory`], in archive order.
+    pub fn classify_entries(&self) -> Vec<(&str, PathCategory)> {
+        self.file_names()
+            .filter(|path| !path.ends_with('/'))
+            .map(|path| (path, classify_path(path, self.dist_info_stem())))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::WheelNameParseError;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_classify_path() -> Result<(), WheelNameParseError> {
+        let wheel_name = WheelName::from_str("demo-1.0-py3-none-any.whl")?;
+        for (path, category) in [
+            ("demo/__init__.py", PathCategory::Root),
+            ("demo-1.0.dist-info/METADATA", PathCategory::DistInfo),
+            ("demo-1.0.data/scripts/demo", PathCategory::DataScript),
+            (
+                "demo-1.0.data/headers/demo.h",
+                PathCategory::Data("headers".to_string()),
+            ),
+            ("demo/../../etc/passwd", PathCategory::OutsideRoot),
+            ("/etc/passwd", PathCategory::OutsideRoot),
+            ("C:/Windows/demo.dll", PathCategory::OutsideRoot),
+            ("demo\\..\\evil.py", PathCategory::OutsideRoot),
+            ("demo/.env", PathCategory::Dotfile),
+            ("demo-2.0.dist-info/METADATA", PathCategory::Root),
+        ] {
+            assert_eq!(wheel_name.classify_path(path), category, "{path}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,328 @@
+use std::fmt;
+use std::io::Read;
+use std::io::Seek;
+
+use pep440_rs::Version;
+
+use crate::normalize_distribution_name;
+use crate::NameValidation;
+use crate::PathCategory;
+use crate::RecordEntryError;
+use crate::RecordFile;
+use crate::Wheel;
+use crate::WheelError;
+use crate::WheelName;
+
+/// How well a wheel satisfies one section of the spec, from best to worst.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum ComplianceLevel {
+    Pass,
+    /// The wheel deviates from the spec in a way installers tolerate.
+    Warn,
+    /// Installers may reject the wheel, or install it incorrectly.
+    Fail,
+}
+
+/// The parts of the [binary distribution format](https://packaging.python.org/en/latest/specifications/binary-distribution-format/)
+/// a [`ComplianceReport`] checks.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+pub enum ComplianceSection {
+    /// The file name, and whether it agrees with `METADATA`.
+    Naming,
+    /// The archive's structure: the `.dist-info` directory, paths, and data outside zip entries.
+    Layout,
+    /// `RECORD`'s rows, and the files they describe.
+    Record,
+    /// `RECORD.jws` and `RECORD.p7s`.
+    Signatures,
+    /// `Wheel-Version` in `WHEEL`.
+    WheelVersion,
+}
+
+impl ComplianceSection {
+    /// A stable, machine-readable name for the section, e.g. `wheel-version`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ComplianceSection::Naming => "naming",
+            ComplianceSection::Layout => "layout",
+            ComplianceSection::Record => "record",
+            ComplianceSection::Signatures => "signatures",
+            ComplianceSection::WheelVersion => "wheel-version",
+        }
+    }
+}
+
+impl fmt::Display for ComplianceSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The outcome of checking one [`ComplianceSection`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub struct SectionCompliance {
+    pub section: ComplianceSection,
+    pub level: ComplianceLevel,
+    /// Why the section didn't pass, in the order the problems were found.
+    pub messages: Vec<String>,
+}
+
+impl SectionCompliance {
+    fn new(section: ComplianceSection) -> Self {
+        Self {
+            section,
+            level: ComplianceLevel::Pass,
+            messages: Vec::new(),
+        }
+    }
+
+    fn warn(&mut self, message: impl Into<String>) {
+        self.add(ComplianceLevel::Warn, message.into());
+    }
+
+    fn fail(&mut self, message: impl Into<String>) {
+        self.add(ComplianceLevel::Fail, message.into());
+    }
+
+    fn add(&mut self, level: ComplianceLevel, message: String) {
+        self.level = self.level.max(level);
+        self.messages.push(message);
+    }
+}
+
+/// Which sections of the spec a wheel satisfies, see [`Wheel::compliance_report`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub struct ComplianceReport {
+    /// One entry per [`ComplianceSection`], in declaration order.
+    pub sections: Vec<SectionCompliance>,
+}
+
+impl ComplianceReport {
+    /// The worst level of any section, e.g. for an upload gate.
+    pub fn level(&self) -> ComplianceLevel {
+        self.sections
+            .iter()
+            .map(|section| section.level)
+            .max()
+            .unwrap_or(ComplianceLevel::Pass)
+    }
+
+    pub fn section(&self, section: ComplianceSection) -> Option<&SectionCompliance> {
+        self.sections.iter().find(|s| s.section == section)
+    }
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Checks the wheel against each [`ComplianceSection`] of the spec.
+    ///
+    /// Problems reading the wheel's files are reported as failures of the section which needs
+    /// them, rather than as errors, so that a report can always be produced. Only I/O errors
+    /// are returned.
+    pub fn compliance_report(&mut self) -> Result<ComplianceReport, WheelError> {
+        let record_file = match self.record_file() {
+            Ok(record_file) => Ok(record_file),
+            Err(e) => Err(check_io(e)?),
+        };
+        Ok(ComplianceReport {
+            sections: vec![
+                self.check_naming()?,
+                self.check_archive_layout()?,
+                self.check_record(&record_file)?,
+                check_signatures(&record_file, &self.dist_info),
+                self.check_wheel_version()?,
+            ],
+        })
+    }
+
+    fn check_naming(&mut self) -> Result<SectionCompliance, WheelError> {
+        let mut section = SectionCompliance::new(ComplianceSection::Naming);
+        if let Err(e) = WheelName::parse(&self.name.to_string(), NameValidation::Strict) {
+            section.warn(format!("file name: {e}"));
+        }
+        match self.metadata_headers() {
+            Ok(headers) => {
+                let name = headers.get("Name").map(normalize_distribution_name);
+                if name.as_deref() != Some(self.name.normalized_distribution().as_str()) {
+                    section.fail("METADATA Name doesn't match the file name");
+                }
+                let version = headers.get("Version").map(str::parse::<Version>);
+                if version.and_then(Result::ok).as_ref() != Some(&self.name.version) {
+                    section.fail("METADATA Version doesn't match the file name");
+                }
+            }
+            Err(e) => section.fail(check_io(e)?),
+        }
+        Ok(section)
+    }
+
+    fn check_archive_layout(&mut self) -> Result<SectionCompliance, WheelError> {
+        let mut section = SectionCompliance::new(ComplianceSection::Layout);
+        let prepended_data_len = self.prepended_data_len()?;
+        if prepended_data_len > 0 {
+            section.warn(format!(
+                "{prepended_data_len} bytes before the first zip entry"
+            ));
+        }
+        if !self.zip_comment().is_empty() {
+            section.warn("archive has a zip comment");
+        }
+        let expected = format!(
+            "{}-{}.dist-info",
+            self.name.escaped_distribution(),
+            self.name.version
+        );
+        if self.dist_info != expected {
+            section.warn(format!("expected {expected}, found {}", self.dist_info));
+        }
+        for filename in ["METADATA", "WHEEL", "RECORD"] {
+            let path = self.dist_info_path(filename);
+            if !self.file_names().any(|name| name == path) {
+                section.fail(format!("missing {path}"));
+            }
+        }
+        for (path, category) in self.classify_entries() {
+            if category == PathCategory::OutsideRoot {
+                section.fail(format!("{path} would be installed outside of its scheme"));
+            }
+        }
+        Ok(section)
+    }
+
+    fn check_record(
+        &mut self,
+        record_file: &Result<RecordFile, String>,
+    ) -> Result<SectionCompliance, WheelError> {
+        let mut section = SectionCompliance::new(ComplianceSection::Record);
+        let record_file = match record_file {
+            Ok(record_file) => record_file,
+            Err(message) => {
+                section.fail(message.clone());
+                return Ok(section);
+            }
+        };
+        for error in record_file.check_self_entries(&self.dist_info) {
+            match error {
+                RecordEntryError::SignatureListed(_) => {}
+                RecordEntryError::RecordNotListed(_) => section.fail(error.to_string()),
+                _ => section.warn(error.to_string()),
+            }
+        }
+        let coverage = self.record_coverage()?;
+        for path in coverage.unrecorded {
+            section.fail(format!("{path} is not listed in RECORD"));
+        }
+        for path in coverage.missing {
+            section.fail(format!("{path} is listed in RECORD but not in the archive"));
+        }
+        if let Err(e) = self.verify() {
+            section.fail(check_io(e)?);
+        }
+        Ok(section)
+    }
+
+    fn check_wheel_version(&mut self) -> Result<SectionCompliance, WheelError> {
+        let mut section = SectionCompliance::new(ComplianceSection::WheelVersion);
+        let wheel_file = match self.wheel_file() {
+            Ok(wheel_file) => wheel_file,
+            Err(e) => {
+                section.fail(check_io(e)?);
+                return Ok(section);
+            }
+        };
+        match wheel_file.wheel_version.split_once('.') {
+            Some(("1", "0")) => {}
+            Some(("1", minor)) if minor.parse::<u32>().is_ok() => section.warn(format!(
+                "Wheel-Version {} is newer than 1.0",
+                wheel_file.wheel_version
+            )),
+            _ => section.fail(format!(
+                "unsupported Wheel-Version {}",
+                wheel_file.wheel_version
+            )),
+        }
+        Ok(section)
+    }
+}
+
+fn check_signatures(
+    record_file: &Result<RecordFile, String>,
+    dist_info: &str,
+) -> SectionCompliance {
+    let mut section = SectionCompliance::new(ComplianceSection::Signatures);
+    // A RECORD which can't be read is reported as a failure of the RECORD section.
+    if let Ok(record_file) = record_file {
+        for error in record_file.check_self_entries(dist_info) {
+            if let RecordEntryError::SignatureListed(path) = error {
+                section.fail(format!("{path} is listed in RECORD"));
+            }
+        }
+    }
+    section
+}
+
+/// Returns I/O errors, and describes the rest as a failure message.
+fn check_io(error: WheelError) -> Result<String, WheelError> {
+    match error {
+        WheelError::IOError(_) => Err(error),
+        error => Ok(error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Corruption;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_compliance_report() -> Result<(), WheelError> {
+        let builder =
+            WheelBuilder::new("demo-1.0-py3-none-any.whl")?.file("demo/__init__.py", "hello world");
+        let report = builder.open()?.compliance_report()?;
+        assert_eq!(report.level(), ComplianceLevel::Pass);
+        assert_eq!(
+            report
+                .sections
+                .iter()
+                .map(|section| section.section.name())
+                .collect::<Vec<_>>(),
+            ["naming", "layout", "record", "signatures", "wheel-version"],
+        );
+
+        let report = builder
+            .corrupt(Corruption::DropRecordRow("demo/__init__.py".to_string()))
+            .open()?
+            .compliance_report()?;
+        assert_eq!(report.level(), ComplianceLevel::Fail);
+        assert_eq!(
+            report.section(ComplianceSection::Record),
+            Some(&SectionCompliance {
+                section: ComplianceSection::Record,
+                level: ComplianceLevel::Fail,
+                messages: vec!["demo/__init__.py is not listed in RECORD".to_string()],
+            }),
+        );
+        assert_eq!(
+            report.section(ComplianceSection::Naming).map(|s| s.level),
+            Some(ComplianceLevel::Pass),
+        );
+        Ok(())
+    }
+}
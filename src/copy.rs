@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::str::FromStr;
+
+use crate::hash::encode_hex;
+use crate::hash::Hasher;
+use crate::hash::HASH_BUFFER_SIZE;
+use crate::verify::check_record;
+use crate::verify::expected_digest;
+use crate::ArchiveLayoutError;
+use crate::HashAlgorithm;
+use crate::RecordFile;
+use crate::VerifyError;
+use crate::WheelError;
+
+/// Copies a wheel from `reader` to `writer` in a single pass, for mirrors moving wheels between
+/// storage backends which can't seek, e.g. object store downloads and uploads.
+///
+/// Every file is checked against the wheel's `RECORD` as it streams past, and the hex digest of
+/// the whole wheel under `algorithm` is returned. The copy is complete even when verification
+/// fails, so callers should discard the destination on error.
+///
+/// Entries are read from their local headers, so archives whose entries have data descriptors
+/// instead of sizes in their local headers aren't supported. Files are hashed before `RECORD`
+/// is read, so `RECORD` digests other than `sha256` fail with
+/// [`VerifyError::UnsupportedHashAlgorithm`].
+pub fn copy_verified<R: Read, W: Write>(
+    reader: R,
+    writer: W,
+    algorithm: HashAlgorithm,
+) -> Result<String, WheelError> {
+    let mut tee = Tee {
+        reader,
+        writer,
+        hasher: algorithm.hasher(),
+    };
+    let mut digests = BTreeMap::new();
+    let mut records = Vec::new();
+    let mut buffer = vec![0; HASH_BUFFER_SIZE];
+    while let Some(mut entry) = zip::read::read_zipfile_from_stream(&mut tee)? {
+        if entry.is_dir() {
+            continue;
+        }
+        let path = entry.name().to_owned();
+        if path
+            .strip_suffix(".dist-info/RECORD")
+            .is_some_and(|dist_info| !dist_info.contains('/'))
+        {
+            let mut contents = String::new();
+            entry.read_to_string(&mut contents)?;
+            records.push((path, contents));
+            continue;
+        }
+        let digest = HashAlgorithm::Sha256.hash_reader(&mut entry, &mut buffer)?;
+        digests.insert(path, digest);
+    }
+    // The central directory isn't needed for verification, but is part of the copy.
+    io::copy(&mut tee, &mut io::sink())?;
+
+    let contents = match records.len() {
+        0 => return Err(ArchiveLayoutError::MissingDistInfo.into()),
+        1 => records.remove(0).1,
+        _ => {
+            let mut dist_infos = records
+                .into_iter()
+                .map(|(path, _)| path.trim_end_matches("/RECORD").to_owned())
+                .collect::<Vec<_>>();
+            dist_infos.sort();
+            return Err(ArchiveLayoutError::MultipleDistInfo(dist_infos).into());
+        }
+    };
+    check_sha256_digests(&RecordFile::from_str(&contents)?, &digests)?;
+
+    tee.writer.flush()?;
+    Ok(encode_hex(&tee.hasher.finalize()))
+}
+
+/// Checks files which were hashed with `sha256` as they streamed past against `record_file`.
+/// `digests` maps each file's path to its digest and size.
+pub(crate) fn check_sha256_digests(
+    record_file: &RecordFile,
+    digests: &BTreeMap<String, (Vec<u8>, u64)>,
+) -> Result<(), WheelError> {
+    for record in &record_file.records {
+        let Some((record_algorithm, expected)) = expected_digest(record)? else {
+            continue;
+        };
+        if record_algorithm != HashAlgorithm::Sha256 {
+            return Err(
+                VerifyError::UnsupportedHashAlgorithm(record_algorithm.name().to_owned()).into(),
+            );
+        }
+        let Some((actual, size)) = digests.get(&record.filename) else {
+            return Err(VerifyError::MissingFile(record.filename.clone()).into());
+        };
+        check_record(record, &expected, actual, *size)?;
+    }
+    Ok(())
+}
+
+/// Writes and hashes everything read through it.
+pub(crate) struct Tee<R, W> {
+    pub(crate) reader: R,
+    pub(crate) writer: W,
+    pub(crate) hasher: Hasher,
+}
+
+impl<R: Read, W: Write> Read for Tee<R, W> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        self.writer.write_all(&buf[..read])?;
+        self.hasher.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Corruption;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_copy_verified() -> Result<(), WheelError> {
+        let builder =
+            WheelBuilder::new("demo-1.0-py3-none-any.whl")?.file("demo/__init__.py", "hello world");
+        let source = builder.build()?.into_inner();
+
+        let mut destination = Vec::new();
+        let hex_digest = copy_verified(source.as_slice(), &mut destination, HashAlgorithm::Sha256)?;
+        assert_eq!(destination, source);
+        assert_eq!(
+            hex_digest,
+            encode_hex(&HashAlgorithm::Sha256.digest(&source))
+        );
+
+        let corrupted = builder
+            .corrupt(Corruption::FlipByte {
+                path: "demo/__init__.py".to_string(),
+                offset: 0,
+            })
+            .build()?
+            .into_inner();
+        assert!(matches!(
+            copy_verified(corrupted.as_slice(), io::sink(), HashAlgorithm::Sha256),
+            Err(WheelError::VerifyError(VerifyError::DigestMismatch(path)))
+                if path == "demo/__init__.py",
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::BTreeSet;
+use std::io::Read;
+use std::io::Seek;
+
+use crate::Wheel;
+use crate::WheelError;
+
+/// How the files in a wheel's archive and its `RECORD` correspond, see [`Wheel::record_coverage`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RecordCoverage {
+    /// Files which are in both the archive and `RECORD`.
+    pub matched: BTreeSet<String>,
+    /// Files in the archive which `RECORD` doesn't list.
+    pub unrecorded: BTreeSet<String>,
+    /// Files `RECORD` lists which aren't in the archive.
+    pub missing: BTreeSet<String>,
+}
+
+impl RecordCoverage {
+    /// Whether `RECORD` lists exactly the files in the archive.
+    pub fn is_complete(&self) -> bool {
+        self.unrecorded.is_empty() && self.missing.is_empty()
+    }
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Compares the files in the archive against the files `RECORD` lists, without reading
+    /// or hashing any of them, for audits which don't need [`Wheel::verify`].
+    ///
+    /// Directory entries are ignored, as are `RECORD.jws` and `RECORD.p7s`, which sign
+    /// `RECORD` and so can't be listed in it.
+    pub fn record_coverage(&mut self) -> Result<RecordCoverage, WheelError> {
+        let signature_paths = [
+            self.dist_info_path("RECORD.jws"),
+            self.dist_info_path("RECORD.p7s"),
+        ];
+        let mut unrecorded = self
+            .file_names()
+            .filter(|path| !path.ends_with('/') && !signature_paths.iter().any(|s| s == path))
+            .map(str::to_owned)
+            .collect::<BTreeSet<_>>();
+
+        let mut coverage = RecordCoverage::default();
+        for record in self.record_file()?.records {
+            if signature_paths.contains(&record.filename) {
+                continue;
+            }
+            if unrecorded.remove(&record.filename) {
+                coverage.matched.insert(record.filename);
+            } else if !coverage.matched.contains(&record.filename) {
+                coverage.missing.insert(record.filename);
+            }
+        }
+        coverage.unrecorded = unrecorded;
+        Ok(coverage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Corruption;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_record_coverage() -> Result<(), WheelError> {
+        let builder = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .file("demo/__init__.py", "hello world")
+            .file("demo-1.0.dist-info/RECORD.jws", "{}");
+        assert_eq!(
+            builder.open()?.record_coverage()?.unrecorded,
+            BTreeSet::new()
+        );
+
+        let mut wheel = builder
+            .corrupt(Corruption::DropRecordRow("demo/__init__.py".to_string()))
+            .open()?;
+        let coverage = wheel.record_coverage()?;
+        assert_eq!(
+            coverage.unrecorded,
+            BTreeSet::from(["demo/__init__.py".to_string()])
+        );
+        assert_eq!(
+            coverage.matched,
+            BTreeSet::from([
+                "demo-1.0.dist-info/METADATA".to_string(),
+                "demo-1.0.dist-info/RECORD".to_string(),
+                "demo-1.0.dist-info/WHEEL".to_string(),
+            ]),
+        );
+        assert!(coverage.missing.is_empty());
+        assert!(!coverage.is_complete());
+        Ok(())
+    }
+}
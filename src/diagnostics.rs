@@ -0,0 +1,813 @@
+//! Stable codes for every [`Warning`] and [`WheelError`], and for the errors which are
+//! reported on their own, like [`RecordEntryError`], so that tools can suppress or escalate
+//! specific findings.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use zip::result::ZipError;
+
+use crate::ArchiveLayoutError;
+use crate::DistInfoFileError;
+use crate::EntryPointsParseError;
+use crate::InstalledDistributionError;
+use crate::LimitError;
+use crate::MetadataFileParseError;
+use crate::RecordEntryError;
+use crate::RecordFileParseError;
+use crate::RequirementParseError;
+use crate::TargetParseError;
+use crate::VerifyError;
+use crate::Warning;
+use crate::WheelError;
+use crate::WheelFileParseError;
+use crate::WheelNameParseError;
+use crate::ZipappError;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// A kind of finding, with a code like `W001` or `E014` which never changes meaning once
+/// released, and a name like `multi-line-summary`. [`DiagnosticCode::ALL`] lists them all.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DiagnosticCode {
+    code: &'static str,
+    name: &'static str,
+    severity: Severity,
+    summary: &'static str,
+}
+
+impl DiagnosticCode {
+    pub const MULTI_LINE_SUMMARY: Self =
+        Self::new("W001", "multi-line-summary", "Summary spans multiple lines");
+    pub const INVALID_URL: Self = Self::new("W002", "invalid-url", "a URL field is not a URL");
+    pub const LICENSE_WITH_LICENSE_EXPRESSION: Self = Self::new(
+        "W003",
+        "license-with-license-expression",
+        "both License and License-Expression are set",
+    );
+    pub const LICENSE_CLASSIFIERS_WITH_LICENSE_EXPRESSION: Self = Self::new(
+        "W004",
+        "license-classifiers-with-license-expression",
+        "License-Expression is set alongside license classifiers",
+    );
+    pub const LICENSE_CLASSIFIER_MISMATCH: Self = Self::new(
+        "W005",
+        "license-classifier-mismatch",
+        "License matches none of the license classifiers",
+    );
+    pub const MISSING_LICENSE_FILE: Self = Self::new(
+        "W006",
+        "missing-license-file",
+        "a License-File is not in the archive",
+    );
+    pub const IGNORED_RECORD_LINE: Self = Self::new(
+        "W007",
+        "ignored-record-line",
+        "RECORD has a blank or comment line",
+    );
+    pub const NAME_MISMATCH: Self = Self::new(
+        "W008",
+        "name-mismatch",
+        ".dist-info is spelled differently from the file name",
+    );
+    pub const MISSING_ENTRY_POINT_MODULE: Self = Self::new(
+        "W009",
+        "missing-entry-point-module",
+        "a script entry point's module is not in RECORD",
+    );
+    pub const METADATA_CHARSET: Self = Self::new(
+        "W010",
+        "metadata-charset",
+        "METADATA is not UTF-8, or declares another charset",
+    );
+    pub const FIELD_REQUIRES_METADATA_VERSION: Self = Self::new(
+        "W011",
+        "field-requires-metadata-version",
+        "a field is newer than the declared Metadata-Version",
+    );
+    pub const INVALID_DYNAMIC: Self = Self::new(
+        "W012",
+        "invalid-dynamic",
+        "Dynamic names a field which may not be dynamic",
+    );
+    pub const INVALID_LICENSE_EXPRESSION: Self = Self::new(
+        "W013",
+        "invalid-license-expression",
+        "License-Expression is not a valid SPDX expression",
+    );
+    pub const INVALID_REQUIREMENT: Self = Self::new(
+        "W014",
+        "invalid-requirement",
+        "a Requires-Dist is not a PEP 508 requirement",
+    );
+    pub const INVALID_REQUIRES_PYTHON: Self = Self::new(
+        "W015",
+        "invalid-requires-python",
+        "Requires-Python is not a PEP 440 version specifier",
+    );
+
+    pub const IO: Self = Self::new("E001", "io", "reading the wheel failed");
+    pub const INVALID_ARCHIVE: Self = Self::new(
+        "E002",
+        "invalid-archive",
+        "the archive is not a valid zip file",
+    );
+    pub const UNSUPPORTED_ARCHIVE: Self = Self::new(
+        "E003",
+        "unsupported-archive",
+        "the archive uses an unsupported zip feature",
+    );
+    pub const FILE_NOT_FOUND: Self = Self::new(
+        "E004",
+        "file-not-found",
+        "a required file is not in the archive",
+    );
+    pub const PREPENDED_DATA: Self = Self::new(
+        "E005",
+        "prepended-data",
+        "the archive has data before the first entry",
+    );
+    pub const ZIP_COMMENT: Self = Self::new("E006", "zip-comment", "the archive has a comment");
+    pub const UNSAFE_PATH: Self = Self::new(
+        "E007",
+        "unsafe-path",
+        "an entry would be unpacked outside of the destination",
+    );
+    pub const MISSING_DIST_INFO: Self =
+        Self::new("E008", "missing-dist-info", "the archive has no .dist-info");
+    pub const MULTIPLE_DIST_INFO: Self = Self::new(
+        "E009",
+        "multiple-dist-info",
+        "the archive has more than one .dist-info",
+    );
+    pub const NOT_A_WHEEL: Self =
+        Self::new("E010", "not-a-wheel", "the file name does not end in .whl");
+    pub const WHEEL_NAME_PART_MISMATCH: Self = Self::new(
+        "E011",
+        "wheel-name-part-mismatch",
+        "the file name has the wrong number of parts",
+    );
+    pub const INVALID_DISTRIBUTION_NAME: Self = Self::new(
+        "E012",
+        "invalid-distribution-name",
+        "the distribution name is invalid",
+    );
+    pub const INVALID_DISTRIBUTION_NAME_CHARACTER: Self = Self::new(
+        "E013",
+        "invalid-distribution-name-character",
+        "the distribution name has an invalid character",
+    );
+    pub const INVALID_VERSION: Self = Self::new(
+        "E014",
+        "invalid-version",
+        "the version is not a PEP 440 version",
+    );
+    pub const INVALID_BUILD_TAG: Self =
+        Self::new("E015", "invalid-build-tag", "the build tag is invalid");
+    pub const INVALID_TAG: Self =
+        Self::new("E016", "invalid-tag", "a compatibility tag is invalid");
+    pub const INVALID_TAG_CHARACTER: Self = Self::new(
+        "E017",
+        "invalid-tag-character",
+        "a compatibility tag has an invalid character",
+    );
+    pub const WHEEL_DUPLICATE_FIELD: Self =
+        Self::new("E018", "wheel-duplicate-field", "WHEEL repeats a field");
+    pub const WHEEL_INVALID_FIELD_VALUE: Self = Self::new(
+        "E019",
+        "wheel-invalid-field-value",
+        "WHEEL has an invalid field value",
+    );
+    pub const WHEEL_MISSING_FIELD: Self = Self::new(
+        "E020",
+        "wheel-missing-field",
+        "WHEEL is missing a required field",
+    );
+    pub const METADATA_MULTI_LINE_SUMMARY: Self = Self::new(
+        "E021",
+        "metadata-multi-line-summary",
+        "Summary spans multiple lines",
+    );
+    pub const METADATA_MALFORMED_HEADER: Self = Self::new(
+        "E022",
+        "metadata-malformed-header",
+        "METADATA has a malformed header line",
+    );
+    pub const METADATA_INVALID_UTF8: Self = Self::new(
+        "E023",
+        "metadata-invalid-utf8",
+        "METADATA is not valid UTF-8",
+    );
+    pub const METADATA_INVALID_REQUIREMENT: Self = Self::new(
+        "E024",
+        "metadata-invalid-requirement",
+        "a Requires-Dist or Obsoletes-Dist is not a PEP 508 requirement",
+    );
+    pub const METADATA_INVALID_JSON: Self =
+        Self::new("E025", "metadata-invalid-json", "metadata.json is invalid");
+    pub const RECORD_INVALID_CSV: Self =
+        Self::new("E026", "record-invalid-csv", "RECORD is not valid CSV");
+    pub const RECORD_MALFORMED_DIGEST: Self = Self::new(
+        "E027",
+        "record-malformed-digest",
+        "a RECORD digest is not of the form algorithm=digest",
+    );
+    pub const RECORD_MALFORMED_FILE_SIZE: Self = Self::new(
+        "E028",
+        "record-malformed-file-size",
+        "a RECORD file size is not a number",
+    );
+    pub const RECORD_TOO_MANY_ROWS: Self = Self::new(
+        "E029",
+        "record-too-many-rows",
+        "RECORD exceeds the configured row limit",
+    );
+    pub const RECORD_MALFORMED_LINE: Self = Self::new(
+        "E030",
+        "record-malformed-line",
+        "RECORD has a blank or comment line",
+    );
+    pub const RECORD_WRONG_FIELD_COUNT: Self = Self::new(
+        "E031",
+        "record-wrong-field-count",
+        "a RECORD row does not have three fields",
+    );
+    pub const ENTRY_POINTS_MALFORMED_LINE: Self = Self::new(
+        "E032",
+        "entry-points-malformed-line",
+        "entry_points.txt has a malformed line",
+    );
+    pub const ENTRY_POINTS_ENTRY_OUTSIDE_GROUP: Self = Self::new(
+        "E033",
+        "entry-points-entry-outside-group",
+        "entry_points.txt has an entry point before any group",
+    );
+    pub const ENTRY_POINTS_MALFORMED_ENTRY_POINT: Self = Self::new(
+        "E034",
+        "entry-points-malformed-entry-point",
+        "an entry point is not of the form module:attr [extras]",
+    );
+    pub const UNSUPPORTED_HASH_ALGORITHM: Self = Self::new(
+        "E035",
+        "unsupported-hash-algorithm",
+        "a digest uses an unsupported hash algorithm",
+    );
+    pub const MALFORMED_DIGEST: Self = Self::new(
+        "E036",
+        "malformed-digest",
+        "a RECORD digest is not valid base64",
+    );
+    pub const DIGEST_MISMATCH: Self = Self::new(
+        "E037",
+        "digest-mismatch",
+        "a file does not match its RECORD digest",
+    );
+    pub const SIZE_MISMATCH: Self = Self::new(
+        "E038",
+        "size-mismatch",
+        "a file does not match its RECORD size",
+    );
+    pub const HASH_MISMATCH: Self = Self::new(
+        "E039",
+        "hash-mismatch",
+        "the wheel does not match the expected hash",
+    );
+    pub const MISSING_RECORDED_FILE: Self = Self::new(
+        "E040",
+        "missing-recorded-file",
+        "a file listed in RECORD is not in the archive",
+    );
+    pub const ENTRY_TOO_LARGE: Self = Self::new(
+        "E041",
+        "entry-too-large",
+        "an entry exceeds the configured size limit",
+    );
+    pub const METADATA_TOO_LARGE: Self = Self::new(
+        "E042",
+        "metadata-too-large",
+        "METADATA exceeds the configured size limit",
+    );
+    pub const DIST_INFO_FILE_PARSE: Self = Self::new(
+        "E043",
+        "dist-info-file-parse",
+        "a .dist-info file could not be parsed",
+    );
+    pub const INSTALLED_DISTRIBUTION_MISMATCH: Self = Self::new(
+        "E044",
+        "installed-distribution-mismatch",
+        "the installed distribution does not match the wheel",
+    );
+    pub const METADATA_INVALID_REQUIRES_PYTHON: Self = Self::new(
+        "E045",
+        "metadata-invalid-requires-python",
+        "Requires-Python is not a PEP 440 version specifier",
+    );
+    pub const ZIPAPP_PLATLIB_WHEEL: Self = Self::new(
+        "E046",
+        "zipapp-platlib-wheel",
+        "a wheel for a zipapp is not pure Python",
+    );
+    pub const ZIPAPP_NO_SCRIPTS: Self = Self::new(
+        "E047",
+        "zipapp-no-scripts",
+        "no wheel for a zipapp has a console_scripts entry point",
+    );
+    pub const ZIPAPP_MISSING_SCRIPT: Self = Self::new(
+        "E048",
+        "zipapp-missing-script",
+        "no wheel for a zipapp has the requested script",
+    );
+    pub const ZIPAPP_AMBIGUOUS_SCRIPT: Self = Self::new(
+        "E049",
+        "zipapp-ambiguous-script",
+        "the wheels for a zipapp have more than one script",
+    );
+    pub const ZIPAPP_CONFLICTING_FILE: Self = Self::new(
+        "E050",
+        "zipapp-conflicting-file",
+        "the wheels for a zipapp have different files at the same path",
+    );
+    pub const UNRECORDED_FILE: Self = Self::new(
+        "E051",
+        "unrecorded-file",
+        "a file in the archive is not listed in RECORD",
+    );
+    pub const RECORD_NOT_LISTED: Self =
+        Self::new("E052", "record-not-listed", "RECORD does not list itself");
+    pub const RECORD_HAS_DIGEST: Self = Self::new(
+        "E053",
+        "record-has-digest",
+        "RECORD lists a digest or size for itself",
+    );
+    pub const RECORD_SIGNATURE_LISTED: Self = Self::new(
+        "E054",
+        "record-signature-listed",
+        "RECORD lists a signature file",
+    );
+    pub const UNSUPPORTED_TARGET: Self = Self::new(
+        "E055",
+        "unsupported-target",
+        "a target triple has no known Python platform",
+    );
+    pub const REQUIREMENT_INVALID_NAME: Self = Self::new(
+        "E056",
+        "requirement-invalid-name",
+        "a requirement does not start with a valid name",
+    );
+    pub const REQUIREMENT_UNCLOSED_EXTRAS: Self = Self::new(
+        "E057",
+        "requirement-unclosed-extras",
+        "a requirement has an unclosed list of extras",
+    );
+    pub const REQUIREMENT_INVALID_EXTRA: Self = Self::new(
+        "E058",
+        "requirement-invalid-extra",
+        "a requirement has an invalid extra name",
+    );
+    pub const REQUIREMENT_MISSING_URL: Self = Self::new(
+        "E059",
+        "requirement-missing-url",
+        "a requirement has `@` but no URL",
+    );
+    pub const REQUIREMENT_INVALID_SPECIFIER: Self = Self::new(
+        "E060",
+        "requirement-invalid-specifier",
+        "a requirement has an invalid version specifier",
+    );
+    pub const REQUIREMENT_EMPTY_MARKER: Self = Self::new(
+        "E061",
+        "requirement-empty-marker",
+        "a requirement has `;` but no marker",
+    );
+    pub const REQUIREMENT_UNEXPECTED_TEXT: Self = Self::new(
+        "E062",
+        "requirement-unexpected-text",
+        "a requirement has unexpected text",
+    );
+
+    /// Every code, warnings first, each in order of its code.
+    pub const ALL: &'static [DiagnosticCode] = &[
+        Self::MULTI_LINE_SUMMARY,
+        Self::INVALID_URL,
+        Self::LICENSE_WITH_LICENSE_EXPRESSION,
+        Self::LICENSE_CLASSIFIERS_WITH_LICENSE_EXPRESSION,
+        Self::LICENSE_CLASSIFIER_MISMATCH,
+        Self::MISSING_LICENSE_FILE,
+        Self::IGNORED_RECORD_LINE,
+        Self::NAME_MISMATCH,
+        Self::MISSING_ENTRY_POINT_MODULE,
+        Self::METADATA_CHARSET,
+        Self::FIELD_REQUIRES_METADATA_VERSION,
+        Self::INVALID_DYNAMIC,
+        Self::INVALID_LICENSE_EXPRESSION,
+        Self::INVALID_REQUIREMENT,
+        Self::INVALID_REQUIRES_PYTHON,
+        Self::IO,
+        Self::INVALID_ARCHIVE,
+        Self::UNSUPPORTED_ARCHIVE,
+        Self::FILE_NOT_FOUND,
+        Self::PREPENDED_DATA,
+        Self::ZIP_COMMENT,
+        Self::UNSAFE_PATH,
+        Self::MISSING_DIST_INFO,
+        Self::MULTIPLE_DIST_INFO,
+        Self::NOT_A_WHEEL,
+        Self::WHEEL_NAME_PART_MISMATCH,
+        Self::INVALID_DISTRIBUTION_NAME,
+        Self::INVALID_DISTRIBUTION_NAME_CHARACTER,
+        Self::INVALID_VERSION,
+        Self::INVALID_BUILD_TAG,
+        Self::INVALID_TAG,
+        Self::INVALID_TAG_CHARACTER,
+        Self::WHEEL_DUPLICATE_FIELD,
+        Self::WHEEL_INVALID_FIELD_VALUE,
+        Self::WHEEL_MISSING_FIELD,
+        Self::METADATA_MULTI_LINE_SUMMARY,
+        Self::METADATA_MALFORMED_HEADER,
+        Self::METADATA_INVALID_UTF8,
+        Self::METADATA_INVALID_REQUIREMENT,
+        Self::METADATA_INVALID_JSON,
+        Self::RECORD_INVALID_CSV,
+        Self::RECORD_MALFORMED_DIGEST,
+        Self::RECORD_MALFORMED_FILE_SIZE,
+        Self::RECORD_TOO_MANY_ROWS,
+        Self::RECORD_MALFORMED_LINE,
+        Self::RECORD_WRONG_FIELD_COUNT,
+        Self::ENTRY_POINTS_MALFORMED_LINE,
+        Self::ENTRY_POINTS_ENTRY_OUTSIDE_GROUP,
+        Self::ENTRY_POINTS_MALFORMED_ENTRY_POINT,
+        Self::UNSUPPORTED_HASH_ALGORITHM,
+        Self::MALFORMED_DIGEST,
+        Self::DIGEST_MISMATCH,
+        Self::SIZE_MISMATCH,
+        Self::HASH_MISMATCH,
+        Self::MISSING_RECORDED_FILE,
+        Self::ENTRY_TOO_LARGE,
+        Self::METADATA_TOO_LARGE,
+        Self::DIST_INFO_FILE_PARSE,
+        Self::INSTALLED_DISTRIBUTION_MISMATCH,
+        Self::METADATA_INVALID_REQUIRES_PYTHON,
+        Self::ZIPAPP_PLATLIB_WHEEL,
+        Self::ZIPAPP_NO_SCRIPTS,
+        Self::ZIPAPP_MISSING_SCRIPT,
+        Self::ZIPAPP_AMBIGUOUS_SCRIPT,
+        Self::ZIPAPP_CONFLICTING_FILE,
+        Self::UNRECORDED_FILE,
+        Self::RECORD_NOT_LISTED,
+        Self::RECORD_HAS_DIGEST,
+        Self::RECORD_SIGNATURE_LISTED,
+        Self::UNSUPPORTED_TARGET,
+        Self::REQUIREMENT_INVALID_NAME,
+        Self::REQUIREMENT_UNCLOSED_EXTRAS,
+        Self::REQUIREMENT_INVALID_EXTRA,
+        Self::REQUIREMENT_MISSING_URL,
+        Self::REQUIREMENT_INVALID_SPECIFIER,
+        Self::REQUIREMENT_EMPTY_MARKER,
+        Self::REQUIREMENT_UNEXPECTED_TEXT,
+    ];
+
+    const fn new(code: &'static str, name: &'static str, summary: &'static str) -> Self {
+        let severity = match code.as_bytes()[0] {
+            b'W' => Severity::Warning,
+            _ => Severity::Error,
+        };
+        Self {
+            code,
+            name,
+            severity,
+            summary,
+        }
+    }
+
+    /// The code, e.g. `W001`.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// The name, e.g. `multi-line-summary`, which is also [`Warning::code`] for warnings.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// A short description of the finding, e.g. `Summary spans multiple lines`.
+    pub fn summary(&self) -> &'static str {
+        self.summary
+    }
+}
+
+/// Finds a code by its code, e.g. `W001`, or its name, e.g. `multi-line-summary`.
+impl FromStr for DiagnosticCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DiagnosticCode::ALL
+            .iter()
+            .find(|code| code.code.eq_ignore_ascii_case(s) || code.name == s)
+            .copied()
+            .ok_or_else(|| s.to_owned())
+    }
+}
+
+impl fmt::Display for DiagnosticCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code)
+    }
+}
+
+impl Warning {
+    pub fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            Warning::MultiLineSummary(_) => DiagnosticCode::MULTI_LINE_SUMMARY,
+            Warning::InvalidUrl(_, _) => DiagnosticCode::INVALID_URL,
+            Warning::LicenseWithLicenseExpression => {
+                DiagnosticCode::LICENSE_WITH_LICENSE_EXPRESSION
+            }
+            Warning::LicenseClassifiersWithLicenseExpression(_) => {
+                DiagnosticCode::LICENSE_CLASSIFIERS_WITH_LICENSE_EXPRESSION
+            }
+            Warning::LicenseClassifierMismatch(_, _) => DiagnosticCode::LICENSE_CLASSIFIER_MISMATCH,
+            Warning::MissingLicenseFile(_) => DiagnosticCode::MISSING_LICENSE_FILE,
+            Warning::IgnoredRecordLine(_, _) => DiagnosticCode::IGNORED_RECORD_LINE,
+            Warning::NameMismatch(_, _) => DiagnosticCode::NAME_MISMATCH,
+            Warning::MissingEntryPointModule(_, _) => DiagnosticCode::MISSING_ENTRY_POINT_MODULE,
+            Warning::MetadataCharset(_, _) => DiagnosticCode::METADATA_CHARSET,
+            Warning::FieldRequiresMetadataVersion(_, _, _) => {
+                DiagnosticCode::FIELD_REQUIRES_METADATA_VERSION
+            }
+            Warning::InvalidDynamic(_) => DiagnosticCode::INVALID_DYNAMIC,
+            Warning::InvalidLicenseExpression(_) => DiagnosticCode::INVALID_LICENSE_EXPRESSION,
+            Warning::InvalidRequirement(_) => DiagnosticCode::INVALID_REQUIREMENT,
+            Warning::InvalidRequiresPython(_) => DiagnosticCode::INVALID_REQUIRES_PYTHON,
+        }
+    }
+}
+
+impl WheelError {
+    pub fn diagnostic_code(&self) -> DiagnosticCode {
+        use DiagnosticCode as C;
+
+        match self {
+            WheelError::ArchiveLayoutError(e) => match e {
+                ArchiveLayoutError::PrependedData(_) => C::PREPENDED_DATA,
+                ArchiveLayoutError::ZipComment(_) => C::ZIP_COMMENT,
+                ArchiveLayoutError::UnsafePath(_) => C::UNSAFE_PATH,
+                ArchiveLayoutError::MissingDistInfo => C::MISSING_DIST_INFO,
+                ArchiveLayoutError::MultipleDistInfo(_) => C::MULTIPLE_DIST_INFO,
+            },
+            WheelError::DistInfoFileError(DistInfoFileError::Parse(_, _)) => {
+                C::DIST_INFO_FILE_PARSE
+            }
+            WheelError::EntryPointsParseError(e) => match e {
+                EntryPointsParseError::MalformedLine(_) => C::ENTRY_POINTS_MALFORMED_LINE,
+                EntryPointsParseError::EntryOutsideGroup(_) => C::ENTRY_POINTS_ENTRY_OUTSIDE_GROUP,
+                EntryPointsParseError::MalformedEntryPoint(_, _) => {
+                    C::ENTRY_POINTS_MALFORMED_ENTRY_POINT
+                }
+            },
+            WheelError::InstalledDistributionError(
+                InstalledDistributionError::DistributionMismatch(_, _),
+            ) => C::INSTALLED_DISTRIBUTION_MISMATCH,
+            WheelError::LimitError(e) => match e {
+                LimitError::EntryTooLarge(_, _) => C::ENTRY_TOO_LARGE,
+                LimitError::MetadataTooLarge(_) => C::METADATA_TOO_LARGE,
+            },
+            WheelError::MetadataFileParseError(e) => match e {
+                MetadataFileParseError::MultiLineSummary => C::METADATA_MULTI_LINE_SUMMARY,
+                MetadataFileParseError::MalformedHeader(_) => C::METADATA_MALFORMED_HEADER,
+                MetadataFileParseError::InvalidUtf8 => C::METADATA_INVALID_UTF8,
+                MetadataFileParseError::InvalidRequirement(_, _) => C::METADATA_INVALID_REQUIREMENT,
+                MetadataFileParseError::InvalidRequiresPython(_) => {
+                    C::METADATA_INVALID_REQUIRES_PYTHON
+                }
+                MetadataFileParseError::InvalidJson(_) => C::METADATA_INVALID_JSON,
+            },
+            WheelError::RecordFileParseError(e) => match e {
+                RecordFileParseError::CSVError(_) => C::RECORD_INVALID_CSV,
+                RecordFileParseError::MalformedDigest => C::RECORD_MALFORMED_DIGEST,
+                RecordFileParseError::MalformedFileSize => C::RECORD_MALFORMED_FILE_SIZE,
+                RecordFileParseError::TooManyRows(_) => C::RECORD_TOO_MANY_ROWS,
+                RecordFileParseError::MalformedLine(_) => C::RECORD_MALFORMED_LINE,
+                RecordFileParseError::WrongFieldCount(_) => C::RECORD_WRONG_FIELD_COUNT,
+            },
+            WheelError::VerifyError(e) => match e {
+                VerifyError::UnsupportedHashAlgorithm(_) => C::UNSUPPORTED_HASH_ALGORITHM,
+                VerifyError::MalformedDigest(_) => C::MALFORMED_DIGEST,
+                VerifyError::DigestMismatch(_) => C::DIGEST_MISMATCH,
+                VerifyError::SizeMismatch(_, _, _) => C::SIZE_MISMATCH,
+                VerifyError::HashMismatch(_, _) => C::HASH_MISMATCH,
+                VerifyError::MissingFile(_) => C::MISSING_RECORDED_FILE,
+                VerifyError::UnrecordedFile(_) => C::UNRECORDED_FILE,
+            },
+            WheelError::WheelFileParseError(e) => match e {
+                WheelFileParseError::DuplicateField(_) => C::WHEEL_DUPLICATE_FIELD,
+                WheelFileParseError::InvalidFieldValue(_, _) => C::WHEEL_INVALID_FIELD_VALUE,
+                WheelFileParseError::MissingField(_) => C::WHEEL_MISSING_FIELD,
+            },
+            WheelError::WheelNameParseError(e) => match e {
+                WheelNameParseError::NotAWheel => C::NOT_A_WHEEL,
+                WheelNameParseError::PartMismatch => C::WHEEL_NAME_PART_MISMATCH,
+                WheelNameParseError::InvalidDistributionName(_) => C::INVALID_DISTRIBUTION_NAME,
+                WheelNameParseError::InvalidDistributionNameCharacter(_, _, _) => {
+                    C::INVALID_DISTRIBUTION_NAME_CHARACTER
+                }
+                WheelNameParseError::InvalidVersion(_) => C::INVALID_VERSION,
+                WheelNameParseError::InvalidBuildTag(_) => C::INVALID_BUILD_TAG,
+                WheelNameParseError::InvalidTag(_) => C::INVALID_TAG,
+                WheelNameParseError::InvalidTagCharacter(_, _, _) => C::INVALID_TAG_CHARACTER,
+            },
+            WheelError::ZipappError(e) => match e {
+                ZipappError::PlatlibWheel(_) => C::ZIPAPP_PLATLIB_WHEEL,
+                ZipappError::NoScripts => C::ZIPAPP_NO_SCRIPTS,
+                ZipappError::MissingScript(_) => C::ZIPAPP_MISSING_SCRIPT,
+                ZipappError::AmbiguousScript(_) => C::ZIPAPP_AMBIGUOUS_SCRIPT,
+                ZipappError::ConflictingFile(_) => C::ZIPAPP_CONFLICTING_FILE,
+            },
+            WheelError::ZipError(e) => match e {
+                ZipError::Io(_) => C::IO,
+                ZipError::InvalidArchive(_) => C::INVALID_ARCHIVE,
+                ZipError::UnsupportedArchive(_) => C::UNSUPPORTED_ARCHIVE,
+                ZipError::FileNotFound => C::FILE_NOT_FOUND,
+            },
+            WheelError::IOError(_) => C::IO,
+        }
+    }
+}
+
+impl RecordEntryError {
+    pub fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            RecordEntryError::RecordNotListed(_) => DiagnosticCode::RECORD_NOT_LISTED,
+            RecordEntryError::RecordHasDigest(_) => DiagnosticCode::RECORD_HAS_DIGEST,
+            RecordEntryError::SignatureListed(_) => DiagnosticCode::RECORD_SIGNATURE_LISTED,
+        }
+    }
+}
+
+impl RequirementParseError {
+    pub fn diagnostic_code(&self) -> DiagnosticCode {
+        use DiagnosticCode as C;
+
+        match self {
+            RequirementParseError::InvalidName(_) => C::REQUIREMENT_INVALID_NAME,
+            RequirementParseError::UnclosedExtras(_) => C::REQUIREMENT_UNCLOSED_EXTRAS,
+            RequirementParseError::InvalidExtra(_) => C::REQUIREMENT_INVALID_EXTRA,
+            RequirementParseError::MissingUrl(_) => C::REQUIREMENT_MISSING_URL,
+            RequirementParseError::InvalidSpecifier(_) => C::REQUIREMENT_INVALID_SPECIFIER,
+            RequirementParseError::EmptyMarker(_) => C::REQUIREMENT_EMPTY_MARKER,
+            RequirementParseError::UnexpectedText(_) => C::REQUIREMENT_UNEXPECTED_TEXT,
+        }
+    }
+}
+
+impl TargetParseError {
+    pub fn diagnostic_code(&self) -> DiagnosticCode {
+        match self {
+            TargetParseError::UnsupportedTarget(_) => DiagnosticCode::UNSUPPORTED_TARGET,
+        }
+    }
+}
+
+/// What to do about findings with a given code, see [`DiagnosticPolicy`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Level {
+    /// Suppress the finding.
+    Allow,
+    /// Report the finding.
+    Warn,
+    /// Treat the finding as an error.
+    Deny,
+}
+
+/// Per-code overrides of how findings are treated, for tools which let users suppress or
+/// escalate specific findings, e.g. from a configuration file.
+///
+/// ```
+/// # use pep_427::{DiagnosticCode, DiagnosticPolicy, Level};
+/// # use std::str::FromStr;
+/// let policy = DiagnosticPolicy::new()
+///     .with_level(DiagnosticCode::from_str("W001").unwrap(), Level::Allow)
+///     .with_level(DiagnosticCode::NAME_MISMATCH, Level::Deny);
+/// assert_eq!(policy.level(DiagnosticCode::MULTI_LINE_SUMMARY), Level::Allow);
+/// assert_eq!(policy.level(DiagnosticCode::INVALID_URL), Level::Warn);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DiagnosticPolicy {
+    levels: BTreeMap<&'static str, Level>,
+}
+
+impl DiagnosticPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_level(mut self, code: DiagnosticCode, level: Level) -> Self {
+        self.levels.insert(code.code, level);
+        self
+    }
+
+    /// The level for `code`: its override, or else [`Level::Warn`] for warnings and
+    /// [`Level::Deny`] for errors.
+    pub fn level(&self, code: DiagnosticCode) -> Level {
+        match (self.levels.get(code.code), code.severity) {
+            (Some(level), _) => *level,
+            (None, Severity::Warning) => Level::Warn,
+            (None, Severity::Error) => Level::Deny,
+        }
+    }
+
+    /// Splits `warnings` into those to report and those to treat as errors, dropping the
+    /// suppressed ones.
+    pub fn partition<'a>(&self, warnings: &'a [Warning]) -> (Vec<&'a Warning>, Vec<&'a Warning>) {
+        let mut reported = Vec::new();
+        let mut denied = Vec::new();
+        for warning in warnings {
+            match self.level(warning.diagnostic_code()) {
+                Level::Allow => {}
+                Level::Warn => reported.push(warning),
+                Level::Deny => denied.push(warning),
+            }
+        }
+        (reported, denied)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_codes_are_unique() {
+        let codes = DiagnosticCode::ALL
+            .iter()
+            .map(|code| code.code())
+            .collect::<BTreeSet<_>>();
+        let names = DiagnosticCode::ALL
+            .iter()
+            .map(|code| code.name())
+            .collect::<BTreeSet<_>>();
+        assert_eq!(codes.len(), DiagnosticCode::ALL.len());
+        assert_eq!(names.len(), DiagnosticCode::ALL.len());
+    }
+
+    #[test]
+    fn test_warning_codes() {
+        let warning = Warning::NameMismatch("a".to_string(), "b".to_string());
+        assert_eq!(warning.diagnostic_code().code(), "W008");
+        assert_eq!(warning.diagnostic_code().name(), warning.code());
+        assert_eq!(
+            DiagnosticCode::from_str("name-mismatch"),
+            Ok(DiagnosticCode::NAME_MISMATCH)
+        );
+        assert_eq!(
+            DiagnosticCode::from_str("w008"),
+            Ok(DiagnosticCode::NAME_MISMATCH)
+        );
+        assert_eq!(DiagnosticCode::from_str("W999"), Err("W999".to_string()));
+    }
+
+    #[test]
+    fn test_error_codes() {
+        let error = WheelError::from(VerifyError::DigestMismatch("a.py".to_string()));
+        assert_eq!(error.diagnostic_code(), DiagnosticCode::DIGEST_MISMATCH);
+        assert_eq!(error.diagnostic_code().severity(), Severity::Error);
+
+        let error = RecordEntryError::SignatureListed("demo-1.0.dist-info/RECORD.jws".to_string());
+        assert_eq!(
+            error.diagnostic_code(),
+            DiagnosticCode::RECORD_SIGNATURE_LISTED
+        );
+        let error = RequirementParseError::EmptyMarker("demo ;".to_string());
+        assert_eq!(error.diagnostic_code().code(), "E061");
+        let error = TargetParseError::UnsupportedTarget("wasm32-unknown-unknown".to_string());
+        assert_eq!(error.diagnostic_code(), DiagnosticCode::UNSUPPORTED_TARGET);
+    }
+
+    #[test]
+    fn test_policy_partition() {
+        let warnings = [
+            Warning::MultiLineSummary("a\nb".to_string()),
+            Warning::MissingLicenseFile("LICENSE".to_string()),
+            Warning::NameMismatch("a".to_string(), "b".to_string()),
+        ];
+        let policy = DiagnosticPolicy::new()
+            .with_level(DiagnosticCode::MULTI_LINE_SUMMARY, Level::Allow)
+            .with_level(DiagnosticCode::NAME_MISMATCH, Level::Deny);
+        assert_eq!(
+            policy.partition(&warnings),
+            (vec![&warnings[1]], vec![&warnings[2]]),
+        );
+    }
+}
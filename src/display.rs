@@ -0,0 +1,172 @@
+use std::fmt;
+use std::io::Read;
+use std::io::Seek;
+
+use crate::DosTimestamp;
+use crate::Wheel;
+use crate::WheelError;
+use crate::WheelName;
+
+/// `METADATA` fields shown in a [`WheelSummary`], in display order.
+const HIGHLIGHTED_FIELDS: &[&str] = &[
+    "Name",
+    "Version",
+    "Summary",
+    "License",
+    "License-Expression",
+    "Requires-Python",
+    "Author",
+    "Home-page",
+];
+
+const BOLD: &str = "\x1b[1m";
+const CYAN: &str = "\x1b[36m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// A human-readable summary of a wheel: highlights from `METADATA`, followed by
+/// a listing of the archive in the format of `unzip -l`.
+///
+/// Built with [`Wheel::summary`], and rendered with its `Display` implementation.
+/// The fields are public so that other front ends can lay them out themselves.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct WheelSummary {
+    pub name: WheelName,
+    /// The highlighted `METADATA` fields which are present, in display order.
+    pub metadata: Vec<(String, String)>,
+    /// Every entry's path, uncompressed size, and modification time, in archive order.
+    pub entries: Vec<(String, u64, DosTimestamp)>,
+    /// Whether to render with ANSI escape codes.
+    pub color: bool,
+}
+
+impl WheelSummary {
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    fn style(&self, style: &'static str) -> &'static str {
+        if self.color {
+            style
+        } else {
+            ""
+        }
+    }
+}
+
+impl fmt::Display for WheelSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (bold, cyan, dim, reset) = (
+            self.style(BOLD),
+            self.style(CYAN),
+            self.style(DIM),
+            self.style(RESET),
+        );
+
+        writeln!(f, "{bold}{}{reset}", self.name)?;
+        for (field, value) in &self.metadata {
+            // Continuation lines are re-indented to line up under the first line of the value.
+            let indent = format!("\n{:width$}", "", width = field.len() + 2);
+            let value = value.lines().map(str::trim_start).collect::<Vec<_>>();
+            writeln!(f, "{cyan}{field}:{reset} {}", value.join(&indent))?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "  Length      Date    Time    Name")?;
+        writeln!(f, "{dim}---------  ---------- -----   ----{reset}")?;
+        let mut total_size = 0;
+        for (path, size, timestamp) in &self.entries {
+            writeln!(
+                f,
+                "{size:>9}  {:04}-{:02}-{:02} {:02}:{:02}   {path}",
+                timestamp.year, timestamp.month, timestamp.day, timestamp.hour, timestamp.minute,
+            )?;
+            total_size += size;
+        }
+        writeln!(f, "{dim}---------                     -------{reset}")?;
+        let plural = if self.entries.len() == 1 { "" } else { "s" };
+        writeln!(
+            f,
+            "{total_size:>9}                     {} file{plural}",
+            self.entries.len(),
+        )
+    }
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Summarizes the wheel for display, see [`WheelSummary`]. Rendered without color;
+    /// use [`WheelSummary::color`] to enable it.
+    pub fn summary(&mut self) -> Result<WheelSummary, WheelError> {
+        let headers = self.metadata_headers()?;
+        let metadata = HIGHLIGHTED_FIELDS
+            .iter()
+            .filter_map(|field| Some(((*field).to_owned(), headers.get(field)?.to_owned())))
+            .collect();
+
+        let mut entries = Vec::with_capacity(self.archive.len());
+        for i in 0..self.archive.len() {
+            let zip_file = self.archive.by_index_raw(i)?;
+            entries.push((
+                zip_file.name().to_owned(),
+                zip_file.size(),
+                zip_file.last_modified().into(),
+            ));
+        }
+
+        Ok(WheelSummary {
+            name: self.name.clone(),
+            metadata,
+            entries,
+            color: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::io::Write;
+
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_summary() -> Result<(), WheelError> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = FileOptions::default().last_modified_time(zip::DateTime::default());
+        writer.start_file("demo/__init__.py", options)?;
+        writer.write_all(b"hello world")?;
+        writer.start_file("demo-1.0.dist-info/METADATA", options)?;
+        writer.write_all(b"Metadata-Version: 2.1\nName: demo\nVersion: 1.0\n")?;
+        let reader = writer.finish()?;
+
+        let mut wheel = Wheel::open("demo-1.0-py3-none-any.whl", reader)?;
+        let summary = wheel.summary()?;
+        assert_eq!(
+            summary.to_string(),
+            concat!(
+                "demo-1.0-py3-none-any.whl\n",
+                "Name: demo\n",
+                "Version: 1.0\n",
+                "\n",
+                "  Length      Date    Time    Name\n",
+                "---------  ---------- -----   ----\n",
+                "       11  1980-01-01 00:00   demo/__init__.py\n",
+                "       46  1980-01-01 00:00   demo-1.0.dist-info/METADATA\n",
+                "---------                     -------\n",
+                "       57                     2 files\n",
+            ),
+        );
+        assert!(summary
+            .color(true)
+            .to_string()
+            .starts_with("\x1b[1mdemo-1.0-py3-none-any.whl\x1b[0m\n"));
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use std::error::Error;
+use std::io::Read;
+use std::io::Seek;
+
+use crate::Wheel;
+use crate::WheelError;
+
+/// A file in the `.dist-info` directory which this crate doesn't know about, e.g. `AUTHORS`,
+/// `zip-safe`, or a tool's own build provenance, read with [`Wheel::dist_info`].
+pub trait DistInfoFile: Sized {
+    type Error: Error + Send + Sync + 'static;
+
+    /// The file's path within the `.dist-info` directory, e.g. `zip-safe`.
+    fn filename() -> &'static str;
+
+    fn parse(contents: &str) -> Result<Self, Self::Error>;
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Reads and parses a custom `.dist-info` file. Returns `None` if the wheel doesn't have one.
+    ///
+    /// The file is read with the same path resolution and [`Limits`](crate::Limits) as the
+    /// files this crate parses itself. Parse errors are returned as
+    /// [`DistInfoFileError::Parse`].
+    pub fn dist_info<T: DistInfoFile>(&mut self) -> Result<Option<T>, WheelError> {
+        let contents = match self.dist_info_contents(T::filename()) {
+            Ok(contents) => contents,
+            Err(WheelError::ZipError(zip::result::ZipError::FileNotFound)) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        T::parse(&contents)
+            .map(Some)
+            .map_err(|e| DistInfoFileError::Parse(T::filename().to_owned(), Box::new(e)).into())
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum DistInfoFileError {
+    /// The file's path within the `.dist-info` directory, and the parser's error.
+    #[error("failed to parse dist-info file")]
+    Parse(String, #[source] Box<dyn Error + Send + Sync>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct Authors(Vec<String>);
+
+    #[derive(thiserror::Error, Debug)]
+    #[error("empty AUTHORS file")]
+    struct EmptyAuthors;
+
+    impl DistInfoFile for Authors {
+        type Error = EmptyAuthors;
+
+        fn filename() -> &'static str {
+            "AUTHORS"
+        }
+
+        fn parse(contents: &str) -> Result<Self, Self::Error> {
+            let authors = contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_owned)
+                .collect::<Vec<_>>();
+            if authors.is_empty() {
+                return Err(EmptyAuthors);
+            }
+            Ok(Authors(authors))
+        }
+    }
+
+    #[test]
+    fn test_dist_info() -> Result<(), WheelError> {
+        let builder = WheelBuilder::new("demo-1.0-py3-none-any.whl")?;
+        assert_eq!(builder.open()?.dist_info::<Authors>()?, None);
+
+        let mut wheel = builder
+            .clone()
+            .file("demo-1.0.dist-info/AUTHORS", "Alice\nBob\n")
+            .open()?;
+        assert_eq!(
+            wheel.dist_info::<Authors>()?,
+            Some(Authors(vec!["Alice".to_string(), "Bob".to_string()])),
+        );
+
+        let mut wheel = builder.file("demo-1.0.dist-info/AUTHORS", "\n").open()?;
+        assert!(matches!(
+            wheel.dist_info::<Authors>(),
+            Err(WheelError::DistInfoFileError(DistInfoFileError::Parse(filename, _)))
+                if filename == "AUTHORS",
+        ));
+        Ok(())
+    }
+}
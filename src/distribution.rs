@@ -0,0 +1,67 @@
+use std::io::Read;
+use std::io::Seek;
+
+use crate::Egg;
+use crate::InstalledDistribution;
+use crate::MetadataFile;
+use crate::Requirement;
+use crate::SpecifierSet;
+use crate::Wheel;
+use crate::WheelError;
+
+/// An artifact which carries core metadata, e.g. a [`Wheel`], an [`Egg`], or an
+/// [`InstalledDistribution`].
+///
+/// Lets callers such as resolvers be generic over the kind of artifact they read.
+pub trait Distribution {
+    fn metadata_file(&mut self) -> Result<MetadataFile, WheelError>;
+}
+
+impl<R: Read + Seek> Distribution for Wheel<R> {
+    fn metadata_file(&mut self) -> Result<MetadataFile, WheelError> {
+        Wheel::metadata_file(self)
+    }
+}
+
+impl<R: Read + Seek> Distribution for Egg<R> {
+    fn metadata_file(&mut self) -> Result<MetadataFile, WheelError> {
+        Egg::metadata_file(self)
+    }
+}
+
+impl Distribution for InstalledDistribution {
+    fn metadata_file(&mut self) -> Result<MetadataFile, WheelError> {
+        InstalledDistribution::metadata_file(self)
+    }
+}
+
+/// The parts of core metadata which are needed to resolve dependencies.
+pub trait DistributionMetadata {
+    fn name(&self) -> &str;
+    fn version(&self) -> &str;
+    fn requires_dist(&self) -> &[Requirement];
+    fn requires_python(&self) -> Option<&SpecifierSet>;
+    fn extras(&self) -> &[String];
+}
+
+impl DistributionMetadata for MetadataFile {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> &str {
+        &self.version
+    }
+
+    fn requires_dist(&self) -> &[Requirement] {
+        &self.requires_dist
+    }
+
+    fn requires_python(&self) -> Option<&SpecifierSet> {
+        self.requires_python.as_ref()
+    }
+
+    fn extras(&self) -> &[String] {
+        &self.provides_extra
+    }
+}
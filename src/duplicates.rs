@@ -0,0 +1,211 @@
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::io::Seek;
+
+use crate::hash::encode_hex;
+use crate::hash::HASH_BUFFER_SIZE;
+use crate::HashAlgorithm;
+use crate::Wheel;
+use crate::WheelError;
+
+/// The files of many wheels by `sha256` digest, for finding content stored more than once
+/// across a wheelhouse, e.g. copies of the same vendored library, see
+/// [`DuplicateIndex::report`].
+#[derive(Clone, Debug, Default)]
+pub struct DuplicateIndex {
+    /// Each hex digest, the size of its content, and every wheel and path with it.
+    contents: BTreeMap<String, (u64, Vec<(String, String)>)>,
+    /// The name of each wheel, and the hex digest and size of each of its files.
+    wheels: Vec<(String, Vec<(String, u64)>)>,
+}
+
+impl DuplicateIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hashes every file in `wheel`, enforcing its [`Limits`](crate::Limits). Empty files
+    /// are skipped, since storing them again costs nothing, as are the files in `.dist-info`,
+    /// which describe their own wheel, so that identical ones, e.g. `WHEEL` files from the
+    /// same build backend, aren't counted as duplicated content.
+    pub fn insert<R: Read + Seek>(&mut self, wheel: &mut Wheel<R>) -> Result<(), WheelError> {
+        let name = wheel.name.to_string();
+        let dist_info = wheel.dist_info_path("");
+        let mut buffer = vec![0; HASH_BUFFER_SIZE];
+        let mut files = Vec::new();
+        for i in 0..wheel.archive.len() {
+            let zip_file = wheel.archive.by_index(i)?;
+            if zip_file.is_dir() || zip_file.name().starts_with(&dist_info) {
+                continue;
+            }
+            let path = zip_file.name().to_owned();
+            wheel.limits.check_size(&path, None, zip_file.size())?;
+            let mut zip_file = wheel.limits.take_entry(zip_file);
+            let (digest, size) = HashAlgorithm::Sha256.hash_reader(&mut zip_file, &mut buffer)?;
+            wheel.limits.check_size(&path, None, size)?;
+            if size == 0 {
+                continue;
+            }
+            let digest = encode_hex(&digest);
+            self.contents
+                .entry(digest.clone())
+                .or_insert_with(|| (size, Vec::new()))
+                .1
+                .push((name.clone(), path));
+            files.push((digest, size));
+        }
+        self.wheels.push((name, files));
+        Ok(())
+    }
+
+    /// Summarizes the content stored more than once across every wheel inserted so far.
+    pub fn report(&self) -> DuplicateReport {
+        let mut duplicates = self
+            .contents
+            .iter()
+            .filter(|(_, (_, locations))| locations.len() > 1)
+            .map(|(digest, (size, locations))| DuplicateContent {
+                digest: digest.clone(),
+                size: *size,
+                locations: locations.clone(),
+            })
+            .collect::<Vec<_>>();
+        duplicates.sort_by(|a, b| b.savings().cmp(&a.savings()).then(a.digest.cmp(&b.digest)));
+
+        let wheels = self
+            .wheels
+            .iter()
+            .map(|(wheel, files)| {
+                let mut duplication = WheelDuplication {
+                    wheel: wheel.clone(),
+                    ..WheelDuplication::default()
+                };
+                for (digest, size) in files {
+                    duplication.total_size += size;
+                    if self.contents[digest].1.len() > 1 {
+                        duplication.duplicate_files += 1;
+                        duplication.duplicate_size += size;
+                    }
+                }
+                duplication
+            })
+            .collect();
+
+        DuplicateReport {
+            potential_savings: duplicates.iter().map(DuplicateContent::savings).sum(),
+            duplicates,
+            wheels,
+        }
+    }
+}
+
+/// Content stored more than once across a wheelhouse, see [`DuplicateIndex::report`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct DuplicateReport {
+    /// Each content stored more than once, most potential savings first.
+    pub duplicates: Vec<DuplicateContent>,
+    /// Bytes which would be saved by storing each content once.
+    pub potential_savings: u64,
+    /// How much of each wheel is duplicated, in insertion order.
+    pub wheels: Vec<WheelDuplication>,
+}
+
+/// A file's content which is stored more than once, in the same wheel or different ones.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct DuplicateContent {
+    /// The lowercase hex `sha256` digest of the content.
+    pub digest: String,
+    /// The uncompressed size of the content.
+    pub size: u64,
+    /// Every wheel name and path with the content, in insertion order.
+    pub locations: Vec<(String, String)>,
+}
+
+impl DuplicateContent {
+    /// Bytes which would be saved by storing the content once.
+    pub fn savings(&self) -> u64 {
+        self.size * (self.locations.len() as u64 - 1)
+    }
+}
+
+/// How much of a single wheel's content is also stored elsewhere.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct WheelDuplication {
+    pub wheel: String,
+    /// The uncompressed size of every file outside `.dist-info`.
+    pub total_size: u64,
+    /// Number of files whose content is stored more than once.
+    pub duplicate_files: usize,
+    /// The uncompressed size of those files.
+    pub duplicate_size: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_duplicate_report() -> Result<(), WheelError> {
+        let vendored = "def vendored(): pass\n";
+        let mut index = DuplicateIndex::new();
+        index.insert(
+            &mut WheelBuilder::new("alpha-1.0-py3-none-any.whl")?
+                .file("alpha/__init__.py", "")
+                .file("alpha/_vendor/six.py", vendored)
+                .file("alpha/_vendor/six_copy.py", vendored)
+                .open()?,
+        )?;
+        index.insert(
+            &mut WheelBuilder::new("beta-1.0-py3-none-any.whl")?
+                .file("beta/__init__.py", "")
+                .file("beta/_vendor/six.py", vendored)
+                .open()?,
+        )?;
+
+        let report = index.report();
+        let vendored_size = vendored.len() as u64;
+        assert_eq!(report.duplicates.len(), 1);
+        let duplicate = &report.duplicates[0];
+        assert_eq!(duplicate.size, vendored_size);
+        assert_eq!(
+            duplicate.digest,
+            encode_hex(&HashAlgorithm::Sha256.digest(vendored.as_bytes()))
+        );
+        assert_eq!(
+            duplicate.locations,
+            [
+                ("alpha-1.0-py3-none-any.whl", "alpha/_vendor/six.py"),
+                ("alpha-1.0-py3-none-any.whl", "alpha/_vendor/six_copy.py"),
+                ("beta-1.0-py3-none-any.whl", "beta/_vendor/six.py"),
+            ]
+            .map(|(wheel, path)| (wheel.to_string(), path.to_string())),
+        );
+        assert_eq!(duplicate.savings(), 2 * vendored_size);
+        assert_eq!(report.potential_savings, 2 * vendored_size);
+
+        assert_eq!(
+            report.wheels,
+            [
+                WheelDuplication {
+                    wheel: "alpha-1.0-py3-none-any.whl".to_string(),
+                    total_size: 2 * vendored_size,
+                    duplicate_files: 2,
+                    duplicate_size: 2 * vendored_size,
+                },
+                WheelDuplication {
+                    wheel: "beta-1.0-py3-none-any.whl".to_string(),
+                    total_size: vendored_size,
+                    duplicate_files: 1,
+                    duplicate_size: vendored_size,
+                },
+            ],
+        );
+        Ok(())
+    }
+}
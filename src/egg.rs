@@ -0,0 +1,75 @@
+use std::io::Read;
+use std::io::Seek;
+use std::str::FromStr;
+
+use zip::ZipArchive;
+
+use crate::limits;
+use crate::Limits;
+use crate::MetadataFile;
+use crate::WheelError;
+
+/// A read-only view of a legacy `.egg` zip archive.
+///
+/// Eggs store their core metadata in `EGG-INFO/PKG-INFO`, which uses the same format
+/// as a wheel's `METADATA` file, so it's parsed into the same [`MetadataFile`] type.
+pub struct Egg<R> {
+    archive: ZipArchive<R>,
+    limits: Limits,
+}
+
+impl<R: Read + Seek> Egg<R> {
+    pub fn open(reader: R) -> Result<Egg<R>, WheelError> {
+        Ok(Self {
+            archive: ZipArchive::new(reader)?,
+            limits: Limits::default(),
+        })
+    }
+
+    /// Applies resource limits to all subsequent reads from this egg.
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    pub fn metadata_file(&mut self) -> Result<MetadataFile, WheelError> {
+        Ok(MetadataFile::from_str(&self.pkg_info_contents()?)?)
+    }
+
+    fn pkg_info_contents(&mut self) -> Result<String, WheelError> {
+        // PKG-INFO plays the role of METADATA, so it's subject to the same limit.
+        limits::read_entry_to_string(
+            &mut self.archive,
+            &self.limits,
+            "EGG-INFO/PKG-INFO",
+            Some("METADATA"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use zip::result::ZipError;
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    use super::*;
+
+    #[test]
+    fn test_missing_pkg_info() -> Result<(), WheelError> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .start_file("EGG-INFO/top_level.txt", FileOptions::default())
+            .unwrap();
+        let reader = writer.finish().unwrap();
+
+        let mut egg = Egg::open(reader)?;
+        assert!(matches!(
+            egg.metadata_file(),
+            Err(WheelError::ZipError(ZipError::FileNotFound)),
+        ));
+        Ok(())
+    }
+}
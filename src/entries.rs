@@ -0,0 +1,70 @@
+use std::io::Read;
+use std::io::Seek;
+
+use crate::Wheel;
+use crate::WheelError;
+
+/// An archive entry's name and sizes, as recorded in the zip central directory.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct EntryInfo {
+    pub path: String,
+    /// Uncompressed size in bytes.
+    pub size: u64,
+    pub compressed_size: u64,
+    pub crc32: u32,
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Every file under the `.dist-info` directory, in archive order.
+    ///
+    /// Only the central directory is read, so this is cheap even for large wheels.
+    pub fn dist_info_entries(&mut self) -> Result<Vec<EntryInfo>, WheelError> {
+        let prefix = self.dist_info_path("");
+        let mut entries = Vec::new();
+        for i in 0..self.archive.len() {
+            let zip_file = self.archive.by_index_raw(i)?;
+            if zip_file.is_dir() || !zip_file.name().starts_with(&prefix) {
+                continue;
+            }
+            entries.push(EntryInfo {
+                path: zip_file.name().to_owned(),
+                size: zip_file.size(),
+                compressed_size: zip_file.compressed_size(),
+                crc32: zip_file.crc32(),
+            });
+        }
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::io::Write;
+
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_dist_info_entries() -> Result<(), WheelError> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file("demo/__init__.py", FileOptions::default())?;
+        writer.add_directory("demo-1.0.dist-info/licenses", FileOptions::default())?;
+        writer.start_file("demo-1.0.dist-info/COPYING", FileOptions::default())?;
+        writer.write_all(b"hello world")?;
+        let reader = writer.finish()?;
+
+        let mut wheel = Wheel::open("demo-1.0-py3-none-any.whl", reader)?;
+        let entries = wheel.dist_info_entries()?;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].path, "demo-1.0.dist-info/COPYING");
+        assert_eq!(entries[0].size, 11);
+        assert_eq!(entries[0].crc32, 0x0d4a1185);
+        Ok(())
+    }
+}
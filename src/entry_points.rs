@@ -0,0 +1,395 @@
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::io::Seek;
+use std::str::FromStr;
+
+use crate::text::normalize_text;
+use crate::Warning;
+use crate::Wheel;
+use crate::WheelError;
+
+/// Used for parsing `... .dist-info/entry_points.txt` files.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct EntryPoints {
+    pub entry_points: Vec<EntryPoint>,
+}
+
+impl EntryPoints {
+    /// The entry points in `group`, e.g. `console_scripts`, in file order.
+    pub fn group<'a>(&'a self, group: &'a str) -> impl Iterator<Item = &'a EntryPoint> {
+        self.entry_points
+            .iter()
+            .filter(move |entry_point| entry_point.group == group)
+    }
+}
+
+impl FromStr for EntryPoints {
+    type Err = EntryPointsParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use EntryPointsParseError::*;
+
+        let mut entry_points = Vec::new();
+        let mut group = None;
+        for (i, line) in normalize_text(s).lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[') {
+                let Some(header) = header.strip_suffix(']') else {
+                    return Err(MalformedLine(i + 1));
+                };
+                group = Some(header.trim().to_owned());
+                continue;
+            }
+
+            // Like `configparser`, the name ends at the first delimiter of either kind.
+            let Some(delimiter) = line.find(['=', ':']) else {
+                return Err(MalformedLine(i + 1));
+            };
+            let Some(group) = &group else {
+                return Err(EntryOutsideGroup(i + 1));
+            };
+            entry_points.push(EntryPoint::new(
+                group,
+                line[..delimiter].trim(),
+                line[delimiter + 1..].trim(),
+            )?);
+        }
+        Ok(EntryPoints { entry_points })
+    }
+}
+
+/// Entry points from many distributions, indexed by group for plugin discovery.
+#[derive(Clone, Debug, Default)]
+pub struct EntryPointIndex {
+    groups: BTreeMap<String, Vec<(String, EntryPoint)>>,
+}
+
+impl EntryPointIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the entry points provided by `distribution`, e.g. from [`crate::Wheel::entry_points`].
+    pub fn insert(&mut self, distribution: &str, entry_points: EntryPoints) {
+        for entry_point in entry_points.entry_points {
+            self.groups
+                .entry(entry_point.group.clone())
+                .or_default()
+                .push((distribution.to_owned(), entry_point));
+        }
+    }
+
+    /// Every entry point in `group`, with the distribution which provides it, in insertion order.
+    pub fn group(&self, group: &str) -> &[(String, EntryPoint)] {
+        self.groups.get(group).map_or(&[], Vec::as_slice)
+    }
+
+    /// The distributions which provide at least one entry point in `group`, in insertion order.
+    pub fn providers(&self, group: &str) -> Vec<&str> {
+        let mut providers: Vec<&str> = Vec::new();
+        for (distribution, _) in self.group(group) {
+            if !providers.contains(&distribution.as_str()) {
+                providers.push(distribution);
+            }
+        }
+        providers
+    }
+
+    /// The names of every group with at least one entry point, sorted.
+    pub fn groups(&self) -> impl Iterator<Item = &str> {
+        self.groups.keys().map(String::as_str)
+    }
+}
+
+/// A single `name = module:attr [extras]` entry.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct EntryPoint {
+    pub group: String,
+    pub name: String,
+    pub value: String,
+    module: String,
+    attr: Option<String>,
+    extras: Vec<String>,
+}
+
+impl EntryPoint {
+    /// Validates `value` against the `module:attr [extras]` syntax.
+    pub fn new(group: &str, name: &str, value: &str) -> Result<Self, EntryPointsParseError> {
+        let malformed =
+            |offset| EntryPointsParseError::MalformedEntryPoint(name.to_owned(), offset);
+
+        let (module, mut rest) = split_dotted_name(value);
+        if module.is_empty() {
+            return Err(malformed(0));
+        }
+        let mut attr = None;
+        if let Some(after_colon) = rest.trim_start().strip_prefix(':') {
+            let after_colon = after_colon.trim_start();
+            let (name, after_attr) = split_dotted_name(after_colon);
+            if name.is_empty() {
+                return Err(malformed(value.len() - after_colon.len()));
+            }
+            attr = Some(name.to_owned());
+            rest = after_attr;
+        }
+        let mut extras = Vec::new();
+        let rest = rest.trim_start();
+        if let Some(after_bracket) = rest.strip_prefix('[') {
+            let Some(end) = after_bracket.find(']') else {
+                return Err(malformed(value.len() - rest.len()));
+            };
+            let trailing = after_bracket[end + 1..].trim_start();
+            if !trailing.is_empty() {
+                return Err(malformed(value.len() - trailing.len()));
+            }
+            // Matches importlib.metadata, which takes every run of word characters as an extra.
+            extras = after_bracket[..end]
+                .split(|c: char| !is_word_char(c))
+                .filter(|extra| !extra.is_empty())
+                .map(str::to_owned)
+                .collect();
+        } else if !rest.is_empty() {
+            return Err(malformed(value.len() - rest.len()));
+        }
+
+        Ok(EntryPoint {
+            group: group.to_owned(),
+            name: name.to_owned(),
+            value: value.to_owned(),
+            module: module.to_owned(),
+            attr,
+            extras,
+        })
+    }
+
+    /// The module to import, e.g. `demo.cli`.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// The object to look up in the module, e.g. `main`, if any.
+    pub fn attr(&self) -> Option<&str> {
+        self.attr.as_deref()
+    }
+
+    pub fn extras(&self) -> &[String] {
+        &self.extras
+    }
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Checks that the module of each `console_scripts` and `gui_scripts` entry point is
+    /// listed in `RECORD`, flagging scripts which would fail to import once installed.
+    ///
+    /// Modules are mapped to files heuristically: `demo.cli` is found as `demo/cli.py`,
+    /// `demo/cli/__init__.py`, a compiled `demo/cli.*.so` or `demo/cli.*.pyd` extension,
+    /// or any file under a `demo/cli/` namespace package, at the root of the archive or in
+    /// `.data/purelib/` or `.data/platlib/`.
+    pub fn check_entry_points(&mut self) -> Result<Vec<Warning>, WheelError> {
+        let entry_points = self.entry_points()?;
+        let record_file = self.record_file()?;
+        let data = self.data_prefix();
+        let paths = record_file
+            .records
+            .iter()
+            .map(|record| {
+                let path = record.filename.as_str();
+                path.strip_prefix(&data)
+                    .and_then(|path| {
+                        path.strip_prefix("purelib/")
+                            .or_else(|| path.strip_prefix("platlib/"))
+                    })
+                    .unwrap_or(path)
+            })
+            .collect::<Vec<_>>();
+
+        let mut warnings = Vec::new();
+        for entry_point in entry_points
+            .group("console_scripts")
+            .chain(entry_points.group("gui_scripts"))
+        {
+            if !has_module(&paths, entry_point.module()) {
+                warnings.push(Warning::MissingEntryPointModule(
+                    entry_point.name.clone(),
+                    entry_point.module().to_owned(),
+                ));
+            }
+        }
+        Ok(warnings)
+    }
+}
+
+/// Whether any of `paths` provides `module`, see [`Wheel::check_entry_points`].
+fn has_module(paths: &[&str], module: &str) -> bool {
+    let base = module.replace('.', "/");
+    let package = format!("{base}/");
+    let extension_prefix = format!("{base}.");
+    paths.iter().any(|path| {
+        *path == format!("{base}.py")
+            || path.starts_with(&package)
+            || (path.starts_with(&extension_prefix)
+                && (path.ends_with(".so") || path.ends_with(".pyd")))
+    })
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Splits the longest prefix of word characters and dots off of `s`.
+fn split_dotted_name(s: &str) -> (&str, &str) {
+    let end = s
+        .find(|c: char| !is_word_char(c) && c != '.')
+        .unwrap_or(s.len());
+    s.split_at(end)
+}
+
+#[derive(thiserror::Error, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum EntryPointsParseError {
+    #[error("line {0} is neither a group header nor an entry point")]
+    MalformedLine(usize),
+
+    #[error("line {0} declares an entry point before any group")]
+    EntryOutsideGroup(usize),
+
+    #[error("entry point is not of the form `module:attr [extras]`")]
+    MalformedEntryPoint(String, usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_str() -> Result<(), EntryPointsParseError> {
+        let entry_points = EntryPoints::from_str(concat!(
+            "[console_scripts]\n",
+            "demo = demo.cli:main\n",
+            "demo-extra = demo.cli : main [extra, other]\n",
+            "\n",
+            "# plugins\n",
+            "[demo.plugins]\n",
+            "builtin = demo.plugins\n",
+        ))?;
+        let console_scripts: Vec<_> = entry_points.group("console_scripts").collect();
+        assert_eq!(console_scripts.len(), 2);
+        assert_eq!(console_scripts[0].name, "demo");
+        assert_eq!(console_scripts[0].module(), "demo.cli");
+        assert_eq!(console_scripts[0].attr(), Some("main"));
+        assert_eq!(console_scripts[1].value, "demo.cli : main [extra, other]");
+        assert_eq!(console_scripts[1].attr(), Some("main"));
+        assert_eq!(console_scripts[1].extras(), ["extra", "other"]);
+
+        let plugin = entry_points.group("demo.plugins").next().unwrap();
+        assert_eq!(plugin.module(), "demo.plugins");
+        assert_eq!(plugin.attr(), None);
+        assert!(plugin.extras().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_bom_crlf() -> Result<(), EntryPointsParseError> {
+        let entry_points = EntryPoints::from_str(
+            "\u{feff}[console_scripts]\r\ndemo = demo.cli:main\r\n[gui_scripts]\rdemo-gui = demo.gui:main\r",
+        )?;
+        assert_eq!(entry_points.entry_points.len(), 2);
+        assert_eq!(entry_points.entry_points[0].group, "console_scripts");
+        assert_eq!(entry_points.entry_points[1].group, "gui_scripts");
+        assert_eq!(entry_points.entry_points[1].value, "demo.gui:main");
+        Ok(())
+    }
+
+    #[test]
+    fn test_index() -> Result<(), EntryPointsParseError> {
+        let mut index = EntryPointIndex::new();
+        index.insert(
+            "demo",
+            EntryPoints::from_str("[demo.plugins]\na = demo.a\nb = demo.b\n")?,
+        );
+        index.insert(
+            "demo-extras",
+            EntryPoints::from_str("[demo.plugins]\nc = extras.c\n[console_scripts]\nx = x:main\n")?,
+        );
+        assert_eq!(index.providers("demo.plugins"), ["demo", "demo-extras"]);
+        assert_eq!(index.group("demo.plugins").len(), 3);
+        assert_eq!(index.providers("console_scripts"), ["demo-extras"]);
+        assert!(index.group("missing").is_empty());
+        assert_eq!(
+            index.groups().collect::<Vec<_>>(),
+            ["console_scripts", "demo.plugins"],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed() {
+        use EntryPointsParseError::*;
+
+        assert_eq!(
+            EntryPoints::from_str("demo = demo:main\n"),
+            Err(EntryOutsideGroup(1)),
+        );
+        assert_eq!(
+            EntryPoints::from_str("[console_scripts]\ndemo\n"),
+            Err(MalformedLine(2)),
+        );
+        assert_eq!(
+            EntryPoint::new("console_scripts", "demo", "demo:"),
+            Err(MalformedEntryPoint("demo".to_owned(), 5)),
+        );
+        assert_eq!(
+            EntryPoint::new("console_scripts", "demo", "demo:main [extra"),
+            Err(MalformedEntryPoint("demo".to_owned(), 10)),
+        );
+        assert_eq!(
+            EntryPoint::new("console_scripts", "demo", "demo-cli:main"),
+            Err(MalformedEntryPoint("demo".to_owned(), 4)),
+        );
+    }
+
+    #[test]
+    fn test_check_entry_points() -> Result<(), WheelError> {
+        let mut wheel = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .file("demo/__init__.py", "")
+            .file("demo/cli.py", "")
+            .file(
+                "demo-1.0.data/platlib/_speedups.cpython-311-x86_64-linux-gnu.so",
+                "",
+            )
+            .file(
+                "demo-1.0.dist-info/entry_points.txt",
+                concat!(
+                    "[console_scripts]\n",
+                    "demo = demo.cli:main\n",
+                    "demo-pkg = demo:main\n",
+                    "demo-fast = _speedups:main\n",
+                    "demo-broken = demo.missing:main\n",
+                    "[gui_scripts]\n",
+                    "demo-gui = demo_gui:main\n",
+                    "[demo.plugins]\n",
+                    "other = other:plugin\n",
+                ),
+            )
+            .open()?;
+        assert_eq!(
+            wheel.check_entry_points()?,
+            vec![
+                Warning::MissingEntryPointModule(
+                    "demo-broken".to_string(),
+                    "demo.missing".to_string()
+                ),
+                Warning::MissingEntryPointModule("demo-gui".to_string(), "demo_gui".to_string()),
+            ],
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt::Write;
+use std::io::Read;
+use std::io::Seek;
+
+use crate::MetadataHeaders;
+use crate::Requirement;
+use crate::Wheel;
+use crate::WheelError;
+
+/// Single-valued `METADATA` fields exported under `[metadata]`, and their TOML keys.
+const METADATA_FIELDS: &[(&str, &str)] = &[
+    ("Metadata-Version", "metadata-version"),
+    ("Summary", "summary"),
+    ("License", "license"),
+    ("License-Expression", "license-expression"),
+    ("Requires-Python", "requires-python"),
+    ("Home-page", "home-page"),
+    ("Author", "author"),
+    ("Author-email", "author-email"),
+    ("Maintainer", "maintainer"),
+    ("Maintainer-email", "maintainer-email"),
+];
+
+/// Multiple-use `METADATA` fields exported under `[metadata]` as arrays, and their TOML keys.
+const METADATA_LIST_FIELDS: &[(&str, &str)] = &[
+    ("Classifier", "classifiers"),
+    ("Provides-Extra", "provides-extra"),
+];
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Exports the wheel's key metadata as a TOML document, for people and for tools
+    /// configured in TOML.
+    ///
+    /// The document has a `[wheel]` table from the file name and `WHEEL`, a `[metadata]` table
+    /// from `METADATA` with a `[[metadata.project-urls]]` table per `Project-URL`, and a
+    /// `[[requirements]]` table per `Requires-Dist` which parses as a [`Requirement`]. Keys are stable;
+    /// fields which are absent from the wheel are omitted rather than written empty.
+    pub fn to_toml(&mut self) -> Result<String, WheelError> {
+        let wheel_file = self.wheel_file()?;
+        let headers = self.metadata_headers()?;
+        let mut toml = String::new();
+
+        toml.push_str("[wheel]\n");
+        write_string(&mut toml, "name", &self.name.distribution);
+        write_string(&mut toml, "version", &self.name.version.to_string());
+        if let Some(build_tag) = &self.name.build_tag {
+            write_string(&mut toml, "build-tag", &build_tag.to_string());
+        }
+        let tags = self
+            .name
+            .to_packaging_tags()
+            .into_iter()
+            .collect::<Vec<_>>();
+        write_array(&mut toml, "tags", &tags);
+        write_string(&mut toml, "generator", &wheel_file.generator);
+        writeln!(toml, "root-is-purelib = {}", wheel_file.root_is_purelib).unwrap();
+
+        toml.push_str("\n[metadata]\n");
+        for (field, key) in METADATA_FIELDS {
+            if let Some(value) = headers.get(field) {
+                write_string(&mut toml, key, value);
+            }
+        }
+        for (field, key) in METADATA_LIST_FIELDS {
+            let values = headers
+                .get_all(field)
+                .map(str::to_owned)
+                .collect::<Vec<_>>();
+            if !values.is_empty() {
+                write_array(&mut toml, key, &values);
+            }
+        }
+        write_project_urls(&mut toml, &headers);
+
+        for requirement in &self.metadata_file()?.requires_dist {
+            toml.push_str("\n[[requirements]]\n");
+            write_requirement(&mut toml, requirement);
+        }
+        Ok(toml)
+    }
+}
+
+/// Writes a `[[metadata.project-urls]]` table per `Project-URL`, since labels may repeat or be
+/// missing and so can't be keys.
+fn write_project_urls(toml: &mut String, headers: &MetadataHeaders) {
+    for project_url in headers.get_all("Project-URL") {
+        let (label, url) = project_url.split_once(',').unwrap_or(("", project_url));
+        toml.push_str("\n[[metadata.project-urls]]\n");
+        if !label.trim().is_empty() {
+            write_string(toml, "label", label.trim());
+        }
+        write_string(toml, "url", url.trim());
+    }
+}
+
+/// Writes a requirement's name, extras, version specifier or URL, and environment marker.
+fn write_requirement(toml: &mut String, requirement: &Requirement) {
+    write_string(toml, "name", &requirement.name);
+    if !requirement.extras.is_empty() {
+        write_array(toml, "extras", &requirement.extras);
+    }
+    match &requirement.url {
+        Some(url) => write_string(toml, "url", url),
+        None => {
+            let specifier = requirement.specifiers.to_string();
+            if !specifier.is_empty() {
+                write_string(toml, "specifier", &specifier);
+            }
+        }
+    }
+    if let Some(marker) = &requirement.marker {
+        write_string(toml, "marker", marker);
+    }
+}
+
+fn write_string(toml: &mut String, key: &str, value: &str) {
+    writeln!(toml, "{key} = {}", quote(value)).unwrap();
+}
+
+fn write_array(toml: &mut String, key: &str, values: &[String]) {
+    let values = values.iter().map(|value| quote(value)).collect::<Vec<_>>();
+    writeln!(toml, "{key} = [{}]", values.join(", ")).unwrap();
+}
+
+/// Formats `s` as a TOML basic string.
+fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => write!(quoted, "\\u{:04X}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Warning;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_to_toml() -> Result<(), WheelError> {
+        let mut wheel = WheelBuilder::new("demo-1.0-py2.py3-none-any.whl")?
+            .metadata("Summary", "A \"demo\" package")
+            .metadata("Classifier", "License :: OSI Approved :: MIT License")
+            .metadata("Project-URL", "Source Code, https://example.com/demo")
+            .metadata(
+                "Project-URL",
+                "Source Code, https://mirror.example.com/demo",
+            )
+            .metadata("Project-URL", "https://example.com")
+            .metadata(
+                "Requires-Dist",
+                "requests[socks] (>=2.0) ; python_version < \"3.8\"",
+            )
+            .metadata(
+                "Requires-Dist",
+                "demo-core @ https://example.com/demo_core.whl ; extra == \"core\"",
+            )
+            .metadata("Requires-Dist", "-bad")
+            .open()?;
+
+        assert_eq!(
+            wheel.to_toml()?,
+            concat!(
+                "[wheel]\n",
+                "name = \"demo\"\n",
+                "version = \"1.0\"\n",
+                "tags = [\"py2-none-any\", \"py3-none-any\"]\n",
+                "generator = \"pep-427\"\n",
+                "root-is-purelib = true\n",
+                "\n",
+                "[metadata]\n",
+                "metadata-version = \"2.1\"\n",
+                "summary = \"A \\\"demo\\\" package\"\n",
+                "classifiers = [\"License :: OSI Approved :: MIT License\"]\n",
+                "\n",
+                "[[metadata.project-urls]]\n",
+                "label = \"Source Code\"\n",
+                "url = \"https://example.com/demo\"\n",
+                "\n",
+                "[[metadata.project-urls]]\n",
+                "label = \"Source Code\"\n",
+                "url = \"https://mirror.example.com/demo\"\n",
+                "\n",
+                "[[metadata.project-urls]]\n",
+                "url = \"https://example.com\"\n",
+                "\n",
+                "[[requirements]]\n",
+                "name = \"requests\"\n",
+                "extras = [\"socks\"]\n",
+                "specifier = \">=2.0\"\n",
+                "marker = \"python_version < \\\"3.8\\\"\"\n",
+                "\n",
+                "[[requirements]]\n",
+                "name = \"demo-core\"\n",
+                "url = \"https://example.com/demo_core.whl\"\n",
+                "marker = \"extra == \\\"core\\\"\"\n",
+            ),
+        );
+        assert_eq!(
+            wheel.warnings(),
+            [Warning::InvalidRequirement("-bad".to_string())],
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use std::io::Read;
+use std::io::Seek;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::Wheel;
+
+lazy_static! {
+    /// Matches the interpreter-specific part of an extension module suffix,
+    /// e.g. `.cpython-311-x86_64-linux-gnu.so` or `.cp311-win_amd64.pyd`.
+    static ref VERSION_SPECIFIC_SUFFIX_RE: Regex =
+        Regex::new(r#"\.(cpython-\d+|cp\d+|pypy\d*)[-.]"#).unwrap();
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Paths of the compiled extension modules (`.so` and `.pyd` files) in the archive.
+    pub fn compiled_extensions(&self) -> Vec<&str> {
+        let mut extensions: Vec<&str> = self
+            .file_names()
+            .filter(|path| is_compiled_extension(path))
+            .collect();
+        extensions.sort_unstable();
+        extensions
+    }
+
+    pub fn has_compiled_extensions(&self) -> bool {
+        self.file_names().any(is_compiled_extension)
+    }
+
+    /// Whether the wheel is tagged for the stable ABI, e.g. `cp38-abi3-manylinux_2_17_x86_64`.
+    pub fn claims_abi3(&self) -> bool {
+        self.name.abi_tag.split('.').any(|tag| tag == "abi3")
+    }
+
+    /// Compiled extensions whose file names tie them to one interpreter version,
+    /// in a wheel which claims to target the stable ABI. Empty unless [`Wheel::claims_abi3`].
+    ///
+    /// Such extensions usually mean the build didn't actually use the limited API,
+    /// and the wheel won't import on other Python versions.
+    pub fn version_specific_extensions(&self) -> Vec<&str> {
+        if !self.claims_abi3() {
+            return Vec::new();
+        }
+        self.compiled_extensions()
+            .into_iter()
+            .filter(|path| is_version_specific(path))
+            .collect()
+    }
+}
+
+fn is_compiled_extension(path: &str) -> bool {
+    path.ends_with(".so") || path.ends_with(".pyd")
+}
+
+fn is_version_specific(path: &str) -> bool {
+    let basename = path.rsplit('/').next().unwrap_or_default();
+    VERSION_SPECIFIC_SUFFIX_RE.is_match(basename)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::WheelBuilder;
+    use crate::WheelError;
+
+    use pretty_assertions::assert_eq;
+
+    fn open(filename: &str, files: &[&str]) -> Result<Wheel<Cursor<Vec<u8>>>, WheelError> {
+        files
+            .iter()
+            .fold(WheelBuilder::new(filename)?, |builder, path| {
+                builder.file(path, "")
+            })
+            .open()
+    }
+
+    #[test]
+    fn test_pure() -> Result<(), WheelError> {
+        let wheel = open("demo-1.0-py3-none-any.whl", &["demo/__init__.py"])?;
+        assert!(!wheel.has_compiled_extensions());
+        assert!(wheel.compiled_extensions().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_abi3() -> Result<(), WheelError> {
+        let files = [
+            "demo/__init__.py",
+            "demo/_fast.abi3.so",
+            "demo/_slow.cpython-311-x86_64-linux-gnu.so",
+            "demo/_win.cp311-win_amd64.pyd",
+        ];
+        let wheel = open("demo-1.0-cp38-abi3-manylinux_2_17_x86_64.whl", &files)?;
+        assert!(wheel.has_compiled_extensions());
+        assert!(wheel.claims_abi3());
+        assert_eq!(wheel.compiled_extensions(), &files[1..]);
+        assert_eq!(wheel.version_specific_extensions(), &files[2..]);
+
+        let wheel = open("demo-1.0-cp311-cp311-manylinux_2_17_x86_64.whl", &files)?;
+        assert!(!wheel.claims_abi3());
+        assert!(wheel.version_specific_extensions().is_empty());
+        Ok(())
+    }
+}
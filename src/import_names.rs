@@ -0,0 +1,171 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io::Read;
+use std::io::Seek;
+
+use crate::normalize_distribution_name;
+use crate::Wheel;
+use crate::WheelError;
+
+/// File extensions which make a file importable as a module.
+const MODULE_EXTENSIONS: &[&str] = &[".py", ".pyc", ".so", ".pyd"];
+
+impl<R: Read + Seek> Wheel<R> {
+    /// The top-level names this wheel makes importable, sorted, e.g. `yaml` for PyYAML.
+    ///
+    /// This is a heuristic over the paths in `RECORD`, at the root of the archive or in
+    /// `.data/purelib/` or `.data/platlib/`: a top-level module is a `.py`, `.pyc`, `.so`,
+    /// or `.pyd` file, and a top-level package is a directory with such a file anywhere
+    /// under it, including namespace packages. Names which aren't Python identifiers,
+    /// e.g. `demo.libs` directories vendored by auditwheel, are skipped.
+    pub fn import_names(&mut self) -> Result<BTreeSet<String>, WheelError> {
+        let record_file = self.record_file()?;
+        let data = self.data_prefix();
+        let dist_info = format!("{}/", self.dist_info);
+        let mut import_names = BTreeSet::new();
+        for record in &record_file.records {
+            let path = record.filename.as_str();
+            let path = match path.strip_prefix(&data) {
+                Some(path) => match path
+                    .strip_prefix("purelib/")
+                    .or_else(|| path.strip_prefix("platlib/"))
+                {
+                    Some(path) => path,
+                    None => continue,
+                },
+                None if path.starts_with(&dist_info) => continue,
+                None => path,
+            };
+            if !MODULE_EXTENSIONS
+                .iter()
+                .any(|extension| path.ends_with(extension))
+            {
+                continue;
+            }
+            let import_name = match path.split_once('/') {
+                Some((package, _)) => package,
+                // Extension modules are named e.g. `demo.cpython-311-x86_64-linux-gnu.so`.
+                None => path.split('.').next().unwrap_or(path),
+            };
+            if is_identifier(import_name) {
+                import_names.insert(import_name.to_owned());
+            }
+        }
+        Ok(import_names)
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Import names from many distributions, for answering which distribution provides a module.
+///
+/// Serializable with the `serde` feature, as a map from each import name to the sorted,
+/// normalized names of the distributions which provide it.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+pub struct ImportNameIndex {
+    import_names: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl ImportNameIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the import names provided by `distribution`, e.g. from [`Wheel::import_names`].
+    pub fn insert<I>(&mut self, distribution: &str, import_names: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let distribution = normalize_distribution_name(distribution);
+        for import_name in import_names {
+            self.import_names
+                .entry(import_name.into())
+                .or_default()
+                .insert(distribution.clone());
+        }
+    }
+
+    /// The distributions which provide `module`, sorted. Submodules such as `yaml.loader`
+    /// are looked up by their top-level name.
+    pub fn providers(&self, module: &str) -> Vec<&str> {
+        let import_name = module.split('.').next().unwrap_or(module);
+        self.import_names
+            .get(import_name)
+            .map(|distributions| distributions.iter().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Every import name in the index, sorted.
+    pub fn import_names(&self) -> impl Iterator<Item = &str> {
+        self.import_names.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_import_names() -> Result<(), WheelError> {
+        let mut wheel = WheelBuilder::new("PyYAML-6.0-cp311-cp311-linux_x86_64.whl")?
+            .file("yaml/__init__.py", "")
+            .file("yaml/loader.py", "")
+            .file("_yaml.cpython-311-x86_64-linux-gnu.so", "")
+            .file("pyyaml.libs/libyaml.so", "")
+            .file("google/protobuf/__init__.py", "")
+            .file("pyyaml-6.0.data/platlib/_extra.py", "")
+            .file("pyyaml-6.0.data/scripts/tool.py", "")
+            .file("yaml.pth", "")
+            .open()?;
+        assert_eq!(
+            wheel.import_names()?.into_iter().collect::<Vec<_>>(),
+            ["_extra", "_yaml", "google", "yaml"],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_name_index() {
+        let mut index = ImportNameIndex::new();
+        index.insert("PyYAML", ["_yaml", "yaml"]);
+        index.insert("protobuf", ["google"]);
+        index.insert("googleapis-common-protos", ["google"]);
+
+        assert_eq!(index.providers("yaml.loader"), ["pyyaml"]);
+        assert_eq!(
+            index.providers("google.protobuf"),
+            ["googleapis-common-protos", "protobuf"],
+        );
+        assert!(index.providers("requests").is_empty());
+        assert_eq!(
+            index.import_names().collect::<Vec<_>>(),
+            ["_yaml", "google", "yaml"],
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_import_name_index_json() {
+        let mut index = ImportNameIndex::new();
+        index.insert("PyYAML", ["yaml"]);
+        let json = serde_json::to_string(&index).unwrap();
+        assert_eq!(json, r#"{"yaml":["pyyaml"]}"#);
+        assert_eq!(
+            serde_json::from_str::<ImportNameIndex>(&json).unwrap(),
+            index
+        );
+    }
+}
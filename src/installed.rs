@@ -0,0 +1,383 @@
+use std::collections::HashMap;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::hash::HASH_BUFFER_SIZE;
+use crate::verify::check_record;
+use crate::verify::expected_digest;
+use crate::wheel_name::normalize_distribution_name;
+use crate::MetadataFile;
+use crate::PathCategory;
+use crate::Record;
+use crate::RecordFile;
+use crate::VerifyError;
+use crate::Wheel;
+use crate::WheelError;
+
+/// A distribution installed into a directory such as `site-packages`,
+/// identified by its `.dist-info` directory.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstalledDistribution {
+    root: PathBuf,
+    dist_info: String,
+}
+
+impl InstalledDistribution {
+    /// `dist_info` is the name of the `.dist-info` directory within `root`,
+    /// e.g. `demo-1.0.dist-info`.
+    pub fn new(root: impl Into<PathBuf>, dist_info: &str) -> Self {
+        Self {
+            root: root.into(),
+            dist_info: dist_info.to_owned(),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The normalized name of the distribution, from the `.dist-info` directory name.
+    pub fn distribution(&self) -> String {
+        let name = self.dist_info.split('-').next().unwrap_or_default();
+        normalize_distribution_name(name)
+    }
+
+    pub fn dist_info_path(&self) -> PathBuf {
+        self.root.join(&self.dist_info)
+    }
+
+    /// Reads the installed `METADATA` file.
+    pub fn metadata_file(&self) -> Result<MetadataFile, WheelError> {
+        let contents = fs::read_to_string(self.dist_info_path().join("METADATA"))?;
+        Ok(MetadataFile::from_str(&contents)?)
+    }
+
+    /// Reads the `RECORD` file the installer wrote.
+    pub fn record_file(&self) -> Result<RecordFile, WheelError> {
+        let contents = fs::read_to_string(self.dist_info_path().join("RECORD"))?;
+        Ok(RecordFile::from_str(&contents)?)
+    }
+
+    /// Checks every file listed in the installed `RECORD` against its recorded digest and size.
+    ///
+    /// Unlike [`Wheel::verify`](crate::Wheel::verify), problems with individual files are
+    /// collected into the report rather than returned as errors. Entries without a digest,
+    /// such as `RECORD` itself, generated scripts, and `.pyc` files, are only checked to exist.
+    pub fn verify(&self) -> Result<InstalledReport, WheelError> {
+        let record_file = self.record_file()?;
+        let mut report = InstalledReport::default();
+        let mut buffer = vec![0; HASH_BUFFER_SIZE];
+        for record in &record_file.records {
+            let path = self.root.join(&record.filename);
+            let (algorithm, expected) = match expected_digest(record) {
+                Ok(Some(digest)) => digest,
+                Ok(None) => {
+                    if path.is_file() {
+                        report.unhashed.push(record.filename.clone());
+                    } else {
+                        report.missing.push(record.filename.clone());
+                    }
+                    continue;
+                }
+                Err(WheelError::VerifyError(e)) => {
+                    report.mismatches.push(e);
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+
+            let mut file = match File::open(&path) {
+                Ok(file) => file,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                    report.missing.push(record.filename.clone());
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+            };
+            let (actual, size) = algorithm.hash_reader(&mut file, &mut buffer)?;
+            match check_record(record, &expected, &actual, size) {
+                Ok(()) => report.verified += 1,
+                Err(WheelError::VerifyError(e)) => report.mismatches.push(e),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(report)
+    }
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Compares this wheel's `RECORD` against the `RECORD` of an installed version of the
+    /// same distribution, to find the files an upgrade would add, replace, or remove.
+    ///
+    /// Only files installed into the root (`purelib` or `platlib`) are compared. Scripts,
+    /// headers, and data files are installed relative to other scheme directories, so they
+    /// never match an installed path; files an installer generated, like scripts and `.pyc`
+    /// files, are reported as removed.
+    pub fn diff_installed(
+        &mut self,
+        installed: &InstalledDistribution,
+    ) -> Result<InstallDiff, WheelError> {
+        if installed.distribution() != self.name.normalized_distribution() {
+            return Err(InstalledDistributionError::DistributionMismatch(
+                self.name.normalized_distribution(),
+                installed.distribution(),
+            )
+            .into());
+        }
+        let installed_record_file = installed.record_file()?;
+        let mut installed_records = installed_record_file
+            .records
+            .iter()
+            .map(|record| (record.filename.as_str(), record))
+            .collect::<HashMap<_, _>>();
+
+        let mut diff = InstallDiff::default();
+        for record in self.record_file()?.records {
+            let Some(path) = self.installed_path(&record.filename) else {
+                continue;
+            };
+            match installed_records.remove(path.as_str()) {
+                Some(installed_record) if same_contents(&record, installed_record) => {
+                    diff.unchanged.push(path)
+                }
+                Some(_) => diff.replaced.push(path),
+                None => diff.added.push(path),
+            }
+        }
+        diff.removed = installed_record_file
+            .records
+            .iter()
+            .filter(|record| installed_records.contains_key(record.filename.as_str()))
+            .map(|record| record.filename.clone())
+            .collect();
+        Ok(diff)
+    }
+
+    /// Where an archive path is installed relative to the root, if it's installed there.
+    fn installed_path(&self, path: &str) -> Option<String> {
+        match self.name.classify_path(path) {
+            PathCategory::Root | PathCategory::DistInfo => Some(path.to_owned()),
+            PathCategory::Data(scheme) if scheme == "purelib" || scheme == "platlib" => {
+                let (_, rest) = path.split_once(&format!(".data/{scheme}/"))?;
+                Some(rest.to_owned())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Whether two `RECORD` entries describe the same contents. Entries without comparable
+/// digests are assumed to differ.
+fn same_contents(a: &Record, b: &Record) -> bool {
+    match (&a.digest, &b.digest) {
+        (Some(a_digest), Some(b_digest)) => {
+            a_digest.method == b_digest.method && a_digest.b64_digest == b_digest.b64_digest
+        }
+        _ => false,
+    }
+}
+
+/// The files an upgrade would change, see [`Wheel::diff_installed`].
+/// Paths are relative to the install root, in `RECORD` order.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct InstallDiff {
+    /// Files in the wheel which aren't installed.
+    pub added: Vec<String>,
+    /// Files which are installed with different contents.
+    pub replaced: Vec<String>,
+    /// Installed files which aren't in the wheel.
+    pub removed: Vec<String>,
+    /// Files which are installed with the same contents.
+    pub unchanged: Vec<String>,
+}
+
+#[derive(thiserror::Error, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum InstalledDistributionError {
+    /// The wheel's distribution, and the installed distribution, both normalized.
+    #[error("installed distribution does not match the wheel")]
+    DistributionMismatch(String, String),
+}
+
+/// The result of [`InstalledDistribution::verify`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct InstalledReport {
+    /// Number of files whose digest and size matched `RECORD`.
+    pub verified: usize,
+    /// Files which exist, but have no digest in `RECORD` to check against.
+    pub unhashed: Vec<String>,
+    /// Files listed in `RECORD` which don't exist.
+    pub missing: Vec<String>,
+    /// Files which don't match `RECORD`, or whose `RECORD` entry can't be checked.
+    pub mismatches: Vec<VerifyError>,
+}
+
+impl InstalledReport {
+    /// Whether every file listed in `RECORD` exists, and every recorded digest matched.
+    pub fn is_ok(&self) -> bool {
+        self.missing.is_empty() && self.mismatches.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_verify_installed() -> Result<(), WheelError> {
+        let root = TempDir::new("verify-installed")?;
+        fs::create_dir_all(root.join("demo"))?;
+        fs::create_dir_all(root.join("demo-1.0.dist-info"))?;
+        fs::write(root.join("demo/__init__.py"), "hello world")?;
+        fs::write(root.join("demo/core.py"), "tampered")?;
+        fs::write(
+            root.join("demo-1.0.dist-info/RECORD"),
+            concat!(
+                "demo/__init__.py,sha256=uU0nuZNNPgilLlLX2n2r-sSE7-N6U4DukIj3rOLvzek,11\n",
+                "demo/core.py,sha256=uU0nuZNNPgilLlLX2n2r-sSE7-N6U4DukIj3rOLvzek,11\n",
+                "demo/__pycache__/__init__.cpython-311.pyc,,\n",
+                "demo-1.0.dist-info/RECORD,,\n",
+            ),
+        )?;
+
+        let report = InstalledDistribution::new(&*root, "demo-1.0.dist-info").verify()?;
+        assert_eq!(
+            report,
+            InstalledReport {
+                verified: 1,
+                unhashed: vec!["demo-1.0.dist-info/RECORD".to_string()],
+                missing: vec!["demo/__pycache__/__init__.cpython-311.pyc".to_string()],
+                mismatches: vec![VerifyError::DigestMismatch("demo/core.py".to_string())],
+            },
+        );
+        assert!(!report.is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_installed_metadata() -> Result<(), WheelError> {
+        use crate::Distribution;
+        use crate::DistributionMetadata;
+
+        let root = TempDir::new("installed-metadata")?;
+        fs::create_dir_all(root.join("demo-1.0.dist-info"))?;
+        fs::write(
+            root.join("demo-1.0.dist-info/METADATA"),
+            concat!(
+                "Metadata-Version: 2.1\n",
+                "Name: demo\n",
+                "Version: 1.0\n",
+                "Requires-Python: >=3.8\n",
+                "Requires-Dist: requests>=2.0\n",
+                "Requires-Dist: pytest; extra == \"test\"\n",
+                "Provides-Extra: test\n",
+            ),
+        )?;
+
+        let mut installed = InstalledDistribution::new(&*root, "demo-1.0.dist-info");
+        let metadata_file = Distribution::metadata_file(&mut installed)?;
+        assert_eq!(metadata_file.name(), "demo");
+        assert_eq!(metadata_file.version(), "1.0");
+        assert_eq!(
+            metadata_file
+                .requires_dist()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["requests>=2.0", "pytest; extra == \"test\""],
+        );
+        assert_eq!(
+            metadata_file.requires_python().map(ToString::to_string),
+            Some(">=3.8".to_string()),
+        );
+        assert_eq!(metadata_file.extras(), ["test"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_installed() -> Result<(), WheelError> {
+        use std::io::Cursor;
+        use std::io::Write;
+
+        use zip::write::FileOptions;
+        use zip::ZipWriter;
+
+        let root = TempDir::new("diff-installed")?;
+        fs::create_dir_all(root.join("demo-1.0.dist-info"))?;
+        fs::write(
+            root.join("demo-1.0.dist-info/RECORD"),
+            concat!(
+                "demo/__init__.py,sha256=AAAA,11\n",
+                "demo/core.py,sha256=BBBB,11\n",
+                "demo/old.py,sha256=CCCC,11\n",
+                "../../../bin/demo,,\n",
+                "demo-1.0.dist-info/RECORD,,\n",
+            ),
+        )?;
+
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file("demo-2.0.dist-info/RECORD", FileOptions::default())?;
+        writer.write_all(
+            concat!(
+                "demo/__init__.py,sha256=AAAA,11\n",
+                "demo/core.py,sha256=DDDD,11\n",
+                "demo-2.0.data/purelib/demo/new.py,sha256=EEEE,11\n",
+                "demo-2.0.data/scripts/demo,sha256=FFFF,11\n",
+                "demo-2.0.dist-info/RECORD,,\n",
+            )
+            .as_bytes(),
+        )?;
+        let mut wheel = Wheel::open("demo-2.0-py3-none-any.whl", writer.finish()?)?;
+
+        let installed = InstalledDistribution::new(&*root, "demo-1.0.dist-info");
+        assert_eq!(
+            wheel.diff_installed(&installed)?,
+            InstallDiff {
+                added: vec![
+                    "demo/new.py".to_string(),
+                    "demo-2.0.dist-info/RECORD".to_string(),
+                ],
+                replaced: vec!["demo/core.py".to_string()],
+                removed: vec![
+                    "demo/old.py".to_string(),
+                    "../../../bin/demo".to_string(),
+                    "demo-1.0.dist-info/RECORD".to_string(),
+                ],
+                unchanged: vec!["demo/__init__.py".to_string()],
+            },
+        );
+
+        let other = InstalledDistribution::new(&*root, "other-1.0.dist-info");
+        assert!(matches!(
+            wheel.diff_installed(&other),
+            Err(WheelError::InstalledDistributionError(
+                InstalledDistributionError::DistributionMismatch(_, _)
+            )),
+        ));
+
+        // Names are compared normalized, as installers may spell them differently.
+        fs::create_dir_all(root.join("demo_package-1.0.dist-info"))?;
+        fs::write(
+            root.join("demo_package-1.0.dist-info/RECORD"),
+            "demo_package-1.0.dist-info/RECORD,,\n",
+        )?;
+        let mut wheel = WheelBuilder::new("demo_package-2.0-py3-none-any.whl")?.open()?;
+        wheel.diff_installed(&InstalledDistribution::new(
+            &*root,
+            "demo_package-1.0.dist-info",
+        ))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::BTreeSet;
+use std::io::Read;
+use std::io::Seek;
+use std::str::FromStr;
+
+use pep440_rs::Version;
+
+use crate::normalize_distribution_name;
+use crate::Warning;
+use crate::Wheel;
+use crate::WheelError;
+use crate::WheelName;
+
+impl<R: Read + Seek> Wheel<R> {
+    /// The number of bytes before the first local file header.
+    ///
+    /// This is non-zero for self-extracting archives and polyglot files,
+    /// where the wheel is appended to some other content.
+    pub fn prepended_data_len(&mut self) -> Result<u64, WheelError> {
+        let mut first_header_start = None;
+        for i in 0..self.archive.len() {
+            let header_start = self.archive.by_index_raw(i)?.header_start();
+            first_header_start =
+                Some(first_header_start.map_or(header_start, |start: u64| start.min(header_start)));
+        }
+        Ok(first_header_start.unwrap_or(self.archive.offset()))
+    }
+
+    /// The archive comment from the zip end of central directory record.
+    pub fn zip_comment(&self) -> &[u8] {
+        self.archive.comment()
+    }
+
+    /// Fails if the archive contains data outside of its zip entries.
+    pub(crate) fn check_layout(&mut self) -> Result<(), WheelError> {
+        let prepended_data_len = self.prepended_data_len()?;
+        if prepended_data_len > 0 {
+            return Err(ArchiveLayoutError::PrependedData(prepended_data_len).into());
+        }
+        if !self.zip_comment().is_empty() {
+            return Err(ArchiveLayoutError::ZipComment(self.zip_comment().len()).into());
+        }
+        Ok(())
+    }
+
+    /// Finds the `.dist-info` directory by scanning the archive, for wheels whose directory
+    /// is spelled differently from the file name, e.g. `Demo-1.0.dist-info` or
+    /// `demo-1.00.dist-info` in `demo-1.0-py3-none-any.whl`.
+    ///
+    /// The directory the file name implies is used if it exists. Otherwise, if exactly one
+    /// other directory has the same normalized name and version, it's used instead and a
+    /// [`Warning::NameMismatch`] is recorded.
+    pub(crate) fn resolve_dist_info(&mut self) {
+        let expected = format!("{}/", self.dist_info);
+        if self.file_names().any(|path| path.starts_with(&expected)) {
+            return;
+        }
+        if let Some(actual) = matching_dist_info(&self.name, self.file_names()) {
+            self.warnings.push(Warning::NameMismatch(
+                self.dist_info.clone(),
+                actual.clone(),
+            ));
+            self.dist_info = actual;
+        }
+    }
+}
+
+/// The only top-level `.dist-info` directory among `paths` with the same normalized name and
+/// version as `name`, see [`Wheel::resolve_dist_info`].
+pub(crate) fn matching_dist_info<'a>(
+    name: &WheelName,
+    paths: impl Iterator<Item = &'a str>,
+) -> Option<String> {
+    let distribution = normalize_distribution_name(&name.distribution);
+    let candidates = paths
+        .filter_map(|path| path.split_once('/'))
+        .map(|(dir, _)| dir)
+        .filter(|dir| {
+            let Some((dir_name, version)) = dir
+                .strip_suffix(".dist-info")
+                .and_then(|stem| stem.rsplit_once('-'))
+            else {
+                return false;
+            };
+            normalize_distribution_name(dir_name) == distribution
+                && Version::from_str(version).is_ok_and(|v| v == name.version)
+        })
+        .collect::<BTreeSet<_>>();
+    if candidates.len() != 1 {
+        return None;
+    }
+    candidates.into_iter().next().map(str::to_owned)
+}
+
+#[derive(thiserror::Error, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ArchiveLayoutError {
+    #[error("archive has data before the first zip entry")]
+    PrependedData(u64),
+
+    #[error("archive has a zip comment")]
+    ZipComment(usize),
+
+    #[error("archive entry would be unpacked outside of the destination")]
+    UnsafePath(String),
+
+    #[error("archive has no .dist-info directory")]
+    MissingDistInfo,
+
+    /// The name of each `.dist-info` directory, sorted.
+    #[error("archive has more than one .dist-info directory")]
+    MultipleDistInfo(Vec<String>),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::io::Write;
+
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    use super::*;
+    use crate::PathCategory;
+    use crate::WheelBuilder;
+
+    fn builder() -> Result<WheelBuilder, WheelError> {
+        WheelBuilder::new("demo-1.0-py3-none-any.whl")
+    }
+
+    #[test]
+    fn test_clean_layout() -> Result<(), WheelError> {
+        let mut wheel = Wheel::options()
+            .strict(true)
+            .open("demo-1.0-py3-none-any.whl", builder()?.build()?)?;
+        assert_eq!(wheel.prepended_data_len()?, 0);
+        assert_eq!(wheel.zip_comment(), b"");
+        Ok(())
+    }
+
+    #[test]
+    fn test_dist_info_name_mismatch() -> Result<(), WheelError> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .start_file("Demo_Package-1.00.dist-info/WHEEL", FileOptions::default())
+            .unwrap();
+        writer
+            .write_all(b"Wheel-Version: 1.0\nGenerator: demo\nRoot-Is-Purelib: true\n")
+            .unwrap();
+        writer
+            .start_file(
+                "Demo_Package-1.00.data/scripts/demo",
+                FileOptions::default(),
+            )
+            .unwrap();
+        let reader = writer.finish().unwrap();
+
+        let mut wheel = Wheel::open("demo_package-1.0-py3-none-any.whl", reader.clone())?;
+        assert_eq!(wheel.dist_info_dir(), "Demo_Package-1.00.dist-info");
+        assert_eq!(wheel.data_prefix(), "Demo_Package-1.00.data/");
+        assert_eq!(
+            wheel.classify_entries()[1],
+            (
+                "Demo_Package-1.00.data/scripts/demo",
+                PathCategory::DataScript
+            ),
+        );
+        assert_eq!(
+            wheel.warnings(),
+            [Warning::NameMismatch(
+                "demo_package-1.0.dist-info".to_string(),
+                "Demo_Package-1.00.dist-info".to_string(),
+            )],
+        );
+        assert_eq!(wheel.wheel_file()?.generator, "demo");
+
+        let wheel = Wheel::open("other-1.0-py3-none-any.whl", reader.clone())?;
+        assert_eq!(wheel.dist_info_dir(), "other-1.0.dist-info");
+        assert!(wheel.warnings().is_empty());
+
+        let wheel = Wheel::options()
+            .strict(true)
+            .open("demo_package-1.0-py3-none-any.whl", reader)?;
+        assert_eq!(wheel.dist_info_dir(), "demo_package-1.0.dist-info");
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepended_data() -> Result<(), WheelError> {
+        let reader = builder()?.prefix("#!/bin/sh\n").build()?;
+        let mut wheel = Wheel::open("demo-1.0-py3-none-any.whl", reader.clone())?;
+        assert_eq!(wheel.prepended_data_len()?, 10);
+
+        assert!(matches!(
+            Wheel::options()
+                .strict(true)
+                .open("demo-1.0-py3-none-any.whl", reader),
+            Err(WheelError::ArchiveLayoutError(
+                ArchiveLayoutError::PrependedData(10)
+            )),
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_comment() -> Result<(), WheelError> {
+        let reader = builder()?.zip_comment("hello").build()?;
+        let wheel = Wheel::open("demo-1.0-py3-none-any.whl", reader.clone())?;
+        assert_eq!(wheel.zip_comment(), b"hello");
+
+        assert!(matches!(
+            Wheel::options()
+                .strict(true)
+                .open("demo-1.0-py3-none-any.whl", reader),
+            Err(WheelError::ArchiveLayoutError(
+                ArchiveLayoutError::ZipComment(5)
+            )),
+        ));
+        Ok(())
+    }
+}
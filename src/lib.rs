@@ -1,45 +1,382 @@
 //! This crate provides a structured interface to reading Python wheel metadata.
 //! See [PyPA docs on wheels](https://packaging.python.org/en/latest/specifications/binary-distribution-format/)
 //! for more information.
+//!
+//! # Stability
+//!
+//! Everything this crate exposes is re-exported from the crate root.
+//! Error enums, [`ErrorKind`], [`HashAlgorithm`], and the structs produced by parsing
+//! are `#[non_exhaustive]`: new variants and fields may be added in minor releases,
+//! so downstream `match`es and destructuring patterns need a wildcard arm or `..`.
+//!
+//! # Ordering
+//!
+//! Every collection this crate returns has a deterministic order, so output can be diffed
+//! across runs:
+//!
+//! - Contents parsed from a file keep the file's order: `RECORD` rows, `METADATA` headers,
+//!   and `entry_points.txt` entries.
+//! - Listings of archive entries, e.g. [`Wheel::dist_info_entries`] and [`Wheel::grep`],
+//!   are in archive (central directory) order, unless documented as sorted.
+//! - Maps are [`BTreeMap`](std::collections::BTreeMap)s, and iterate in key order.
+//!
+//! # Panics
+//!
+//! Wheels are untrusted input, so nothing this crate does with a file name, an archive, or
+//! the contents of one panics on malformed data: every parser and every [`Wheel`] method
+//! returns an error instead. A panic on any input is a bug. Inputs which have broken this
+//! in the past, or nearly did, are kept in `fixtures/fuzz/` and parsed by the test suite.
 
+mod abi_tag;
+mod checksums;
+mod classify;
+mod compliance;
+mod copy;
+mod coverage;
+mod diagnostics;
+mod display;
+mod dist_info;
+mod distribution;
+mod duplicates;
+mod egg;
+mod entries;
+mod entry_points;
+mod export;
+mod extensions;
+mod grep;
+mod hash;
+mod import_names;
+mod installed;
+mod layout;
+mod license;
+mod limits;
 mod metadata_file;
+#[cfg(feature = "metadata-json")]
+mod metadata_json;
+mod options;
+mod probe;
+mod provides;
+mod range_reader;
 mod record_file;
+mod repack;
+mod requirement;
+#[cfg(feature = "serde")]
+mod report;
+mod retry;
+mod specifier_set;
+mod split;
+mod stream;
+mod tags;
+mod target;
+#[cfg(test)]
+mod temp_dir;
+#[cfg(any(test, feature = "test-util"))]
+mod test_util;
+mod text;
+mod timestamps;
+mod typing;
+mod unpack;
+mod variant;
+mod verify;
+mod warning;
 mod wheel_file;
+mod wheel_metadata;
 mod wheel_name;
+mod zipapp;
 
+use std::borrow::Cow;
 use std::io;
+use std::io::Cursor;
 use std::io::Read;
 use std::io::Seek;
 use std::str::FromStr;
 
 use zip::ZipArchive;
 
+pub use abi_tag::AbiTag;
+pub use checksums::Checksums;
+pub use classify::PathCategory;
+pub use compliance::ComplianceLevel;
+pub use compliance::ComplianceReport;
+pub use compliance::ComplianceSection;
+pub use compliance::SectionCompliance;
+pub use copy::copy_verified;
+pub use coverage::RecordCoverage;
+pub use diagnostics::DiagnosticCode;
+pub use diagnostics::DiagnosticPolicy;
+pub use diagnostics::Level;
+pub use diagnostics::Severity;
+pub use display::WheelSummary;
+pub use dist_info::DistInfoFile;
+pub use dist_info::DistInfoFileError;
+pub use distribution::Distribution;
+pub use distribution::DistributionMetadata;
+pub use duplicates::DuplicateContent;
+pub use duplicates::DuplicateIndex;
+pub use duplicates::DuplicateReport;
+pub use duplicates::WheelDuplication;
+pub use egg::Egg;
+pub use entries::EntryInfo;
+pub use entry_points::EntryPoint;
+pub use entry_points::EntryPointIndex;
+pub use entry_points::EntryPoints;
+pub use entry_points::EntryPointsParseError;
+pub use grep::GrepMatch;
+pub use grep::GrepOptions;
+pub use hash::HashAlgorithm;
+pub use import_names::ImportNameIndex;
+pub use installed::InstallDiff;
+pub use installed::InstalledDistribution;
+pub use installed::InstalledDistributionError;
+pub use installed::InstalledReport;
+pub use layout::ArchiveLayoutError;
+pub use license::LicenseFile;
+pub use limits::LimitError;
+pub use limits::Limits;
+pub use metadata_file::Description;
+pub use metadata_file::EmailAddress;
 pub use metadata_file::MetadataFile;
+pub use metadata_file::MetadataFileParseError;
+pub use metadata_file::MetadataHeaders;
+pub use metadata_file::ProjectURL;
+pub use options::OpenOptions;
+pub use probe::probe;
+pub use probe::WheelIdentity;
+pub use provides::ProvidesIndex;
+pub use range_reader::RangeReader;
+pub use range_reader::RangeSource;
+pub use record_file::Digest;
+pub use record_file::Record;
+pub use record_file::RecordEntryError;
 pub use record_file::RecordFile;
+pub use record_file::RecordFileParseError;
+pub use record_file::RecordStats;
+pub use requirement::Requirement;
+pub use requirement::RequirementParseError;
+#[cfg(feature = "serde")]
+pub use report::LintReport;
+#[cfg(feature = "serde")]
+pub use report::MetadataReport;
+#[cfg(feature = "serde")]
+pub use report::RecordReport;
+#[cfg(feature = "serde")]
+pub use report::WheelReport;
+pub use retry::RetryHandle;
+pub use retry::RetryPolicy;
+pub use retry::RetryReader;
+pub use specifier_set::SpecifierSet;
+pub use split::SplitOptions;
+pub use stream::StreamedWheel;
+pub use tags::compatible_tags;
+pub use tags::Environment;
+pub use tags::Implementation;
+pub use tags::Libc;
+pub use tags::Platform;
+pub use tags::Tag;
+pub use target::TargetParseError;
+#[cfg(any(test, feature = "test-util"))]
+pub use test_util::Corruption;
+#[cfg(any(test, feature = "test-util"))]
+pub use test_util::WheelBuilder;
+pub use timestamps::DosTimestamp;
+pub use variant::Variant;
+pub use verify::VerifyCheckpoint;
+pub use verify::VerifyError;
+pub use warning::Warning;
 pub use wheel_file::WheelFile;
+pub use wheel_file::WheelFileParseError;
+pub use wheel_metadata::WheelMetadata;
+pub use wheel_name::escape_distribution_name;
+pub use wheel_name::normalize_distribution_name;
+pub use wheel_name::parse_wheel_names;
+pub use wheel_name::BuildTag;
+pub use wheel_name::NameCanonicalization;
+pub use wheel_name::NameRepair;
+pub use wheel_name::NameValidation;
 pub use wheel_name::WheelName;
+pub use wheel_name::WheelNameFailure;
+pub use wheel_name::WheelNameParseError;
+pub use zipapp::ZipappBuilder;
+pub use zipapp::ZipappError;
 
 pub struct Wheel<R> {
     name: WheelName,
     archive: ZipArchive<R>,
+    strict: bool,
+    limits: Limits,
+    normalize_unicode: bool,
+    warnings: Vec<Warning>,
+    /// The `.dist-info` directory, e.g. `demo-1.0.dist-info`, see [`Wheel::dist_info_dir`].
+    dist_info: String,
+    /// The name of every entry in archive order, see [`Wheel::file_names`].
+    file_names: Vec<String>,
+}
+
+impl Wheel<()> {
+    pub fn options() -> OpenOptions {
+        OpenOptions::new()
+    }
+}
+
+impl<T: AsRef<[u8]>> Wheel<Cursor<T>> {
+    /// Opens a wheel which is already in memory, e.g. a `&[u8]`, `Vec<u8>`, or `bytes::Bytes`.
+    ///
+    /// Entries are read directly out of `bytes`; nothing is copied up front.
+    pub fn from_bytes(name: &str, bytes: T) -> Result<Wheel<Cursor<T>>, WheelError> {
+        Self::open(name, Cursor::new(bytes))
+    }
 }
 
 impl<R: Read + Seek> Wheel<R> {
     pub fn open(name: &str, reader: R) -> Result<Wheel<R>, WheelError> {
-        Ok(Self {
-            name: WheelName::from_str(name)?,
-            archive: ZipArchive::new(reader)?,
-        })
+        Self::open_with_options(name, reader, &OpenOptions::default())
+    }
+
+    fn open_with_options(
+        name: &str,
+        mut reader: R,
+        options: &OpenOptions,
+    ) -> Result<Wheel<R>, WheelError> {
+        let name_validation = if options.strict {
+            NameValidation::Strict
+        } else {
+            NameValidation::Legacy
+        };
+        let name = WheelName::parse(name, name_validation)?;
+        let dist_info = format!("{}-{}.dist-info", name.escaped_distribution(), name.version);
+        if let Some((algorithm, expected_hex)) = &options.expected_hash {
+            Self::verify_artifact_hash(&mut reader, *algorithm, expected_hex)?;
+        }
+        let mut archive = ZipArchive::new(reader)?;
+        let file_names = (0..archive.len())
+            .map(|i| Ok(archive.by_index_raw(i)?.name().to_owned()))
+            .collect::<Result<_, WheelError>>()?;
+        let mut wheel = Self {
+            name,
+            archive,
+            strict: options.strict,
+            limits: options.limits,
+            normalize_unicode: options.normalize_unicode,
+            warnings: Vec::new(),
+            dist_info,
+            file_names,
+        };
+        if wheel.strict {
+            wheel.check_layout()?;
+        } else {
+            wheel.resolve_dist_info();
+        }
+        Ok(wheel)
+    }
+
+    /// Applies resource limits to all subsequent reads from this wheel.
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    pub fn limits(&self) -> &Limits {
+        &self.limits
     }
 
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Deviations from the spec which were tolerated so far while reading this wheel,
+    /// in the order they were encountered. Each is recorded once, however often the file
+    /// it's about is read.
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    /// Records the `warnings` which haven't been recorded already, so that reading a file
+    /// more than once doesn't repeat them.
+    fn record_warnings(&mut self, warnings: impl IntoIterator<Item = Warning>) {
+        for warning in warnings {
+            if !self.warnings.contains(&warning) {
+                self.warnings.push(warning);
+            }
+        }
+    }
+
+    /// The name of every entry, including directories, in archive order. Unlike
+    /// [`ZipArchive::file_names`], which iterates in hash order.
+    pub(crate) fn file_names(&self) -> impl Iterator<Item = &str> {
+        self.file_names.iter().map(String::as_str)
+    }
+
+    /// Reads `entry_points.txt`, which is optional: a wheel without one has no entry points.
+    pub fn entry_points(&mut self) -> Result<EntryPoints, WheelError> {
+        match self.dist_info_contents("entry_points.txt") {
+            Ok(contents) => Ok(EntryPoints::from_str(&contents)?),
+            Err(WheelError::ZipError(zip::result::ZipError::FileNotFound)) => {
+                Ok(EntryPoints::default())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reads `METADATA`. Unless the wheel was opened in strict mode, a file which isn't
+    /// valid UTF-8 is decoded as the charset it declares, see [`Warning::MetadataCharset`].
     pub fn metadata_file(&mut self) -> Result<MetadataFile, WheelError> {
-        Ok(MetadataFile::from_str(
-            &self.dist_info_contents("METADATA")?,
-        )?)
+        let raw = self.metadata_bytes()?;
+        let contents = self.decode_metadata(&raw)?;
+        Ok(self.parse_metadata_file(&contents)?)
+    }
+
+    fn metadata_bytes(&mut self) -> Result<Vec<u8>, WheelError> {
+        let path = self.dist_info_path("METADATA");
+        limits::read_entry_to_end(&mut self.archive, &self.limits, &path, Some("METADATA"))
+    }
+
+    /// Decodes `METADATA`, recording a warning if it isn't plain UTF-8, see
+    /// [`Warning::MetadataCharset`].
+    fn decode_metadata<'a>(
+        &mut self,
+        raw: &'a [u8],
+    ) -> Result<Cow<'a, str>, MetadataFileParseError> {
+        let (contents, warning) = metadata_file::decode_metadata(raw, self.strict)?;
+        self.record_warnings(warning);
+        Ok(contents)
+    }
+
+    /// Parses `METADATA` with the options the wheel was opened with, recording any warnings.
+    fn parse_metadata_file(
+        &mut self,
+        contents: &str,
+    ) -> Result<MetadataFile, MetadataFileParseError> {
+        let (mut metadata_file, warnings) =
+            MetadataFile::from_str_with_warnings(contents, self.strict)?;
+        self.record_warnings(warnings);
+        if self.normalize_unicode {
+            metadata_file.normalize_unicode();
+        }
+        let warning = metadata_file.check_summary(self.strict)?;
+        self.record_warnings(warning);
+        self.record_warnings(metadata_file.check_metadata_version());
+        #[cfg(feature = "url")]
+        self.record_warnings(metadata_file.check_urls());
+        Ok(metadata_file)
     }
 
+    /// Reads `METADATA` as ordered raw headers, without interpreting any fields.
+    pub fn metadata_headers(&mut self) -> Result<MetadataHeaders, WheelError> {
+        let raw = self.metadata_bytes()?;
+        let contents = self.decode_metadata(&raw)?;
+        Ok(MetadataHeaders::from_str(&contents)?)
+    }
+
+    /// Reads `RECORD`. Unless the wheel was opened in strict mode, blank and comment lines
+    /// are skipped and recorded as warnings, see [`RecordFile::from_str_with_warnings`].
     pub fn record_file(&mut self) -> Result<RecordFile, WheelError> {
-        Ok(RecordFile::from_str(&self.dist_info_contents("RECORD")?)?)
+        let (record_file, warnings) = RecordFile::from_str_with_warnings(
+            &self.dist_info_contents("RECORD")?,
+            self.limits.max_record_rows,
+            self.strict,
+        )?;
+        self.record_warnings(warnings);
+        Ok(record_file)
     }
 
     pub fn wheel_file(&mut self) -> Result<WheelFile, WheelError> {
@@ -51,35 +388,311 @@ impl<R: Read + Seek> Wheel<R> {
     }
 
     fn dist_info_contents(&mut self, filename: &str) -> Result<String, WheelError> {
+        let path = self.dist_info_path(filename);
+        limits::read_entry_to_string(&mut self.archive, &self.limits, &path, Some(filename))
+    }
+
+    /// The `.dist-info` directory which files such as `METADATA` are read from,
+    /// e.g. `demo-1.0.dist-info`. Unless the wheel was opened in strict mode, this may be
+    /// spelled differently from the file name, see [`Warning::NameMismatch`].
+    pub fn dist_info_dir(&self) -> &str {
+        &self.dist_info
+    }
+
+    fn dist_info_path(&self, filename: &str) -> String {
         // TODO: maybe don't do this, use Path/PathBuf, and make sure this works on windows
-        let filename = format!(
-            "{}-{}.dist.info/{}",
-            self.name.distribution, self.name.version, filename
-        );
-        let mut zip_file = self.archive.by_name(&filename)?;
-        let mut contents = String::new();
-        zip_file.read_to_string(&mut contents)?;
-        Ok(contents)
+        format!("{}/{}", self.dist_info, filename)
+    }
+
+    /// The name and version which [`Wheel::dist_info_dir`] starts with, e.g. `demo-1.0`,
+    /// spelled as in the archive rather than as in the file name.
+    pub(crate) fn dist_info_stem(&self) -> &str {
+        self.dist_info
+            .strip_suffix(".dist-info")
+            .unwrap_or(&self.dist_info)
+    }
+
+    /// The `.data` directory with a trailing `/`, e.g. `demo-1.0.data/`, named after
+    /// [`Wheel::dist_info_stem`].
+    pub(crate) fn data_prefix(&self) -> String {
+        format!("{}.data/", self.dist_info_stem())
     }
 }
 
 #[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
 pub enum WheelError {
+    #[error(transparent)]
+    ArchiveLayoutError(#[from] layout::ArchiveLayoutError),
+
+    #[error(transparent)]
+    DistInfoFileError(#[from] dist_info::DistInfoFileError),
+
+    #[error(transparent)]
+    EntryPointsParseError(#[from] entry_points::EntryPointsParseError),
+
+    #[error(transparent)]
+    InstalledDistributionError(#[from] installed::InstalledDistributionError),
+
+    #[error(transparent)]
+    LimitError(#[from] limits::LimitError),
+
     #[error(transparent)]
     MetadataFileParseError(#[from] metadata_file::MetadataFileParseError),
 
     #[error(transparent)]
     RecordFileParseError(#[from] record_file::RecordFileParseError),
 
+    #[error(transparent)]
+    VerifyError(#[from] verify::VerifyError),
+
     #[error(transparent)]
     WheelFileParseError(#[from] wheel_file::WheelFileParseError),
 
     #[error(transparent)]
     WheelNameParseError(#[from] wheel_name::WheelNameParseError),
 
+    #[error(transparent)]
+    ZipappError(#[from] zipapp::ZipappError),
+
     #[error(transparent)]
     ZipError(#[from] zip::result::ZipError),
 
     #[error(transparent)]
     IOError(#[from] io::Error),
 }
+
+impl WheelError {
+    /// Categorizes the error, so that callers can decide how to report or retry it
+    /// without matching on every variant.
+    pub fn kind(&self) -> ErrorKind {
+        use zip::result::ZipError;
+
+        match self {
+            WheelError::ArchiveLayoutError(_)
+            | WheelError::DistInfoFileError(_)
+            | WheelError::EntryPointsParseError(_)
+            | WheelError::InstalledDistributionError(_)
+            | WheelError::LimitError(_)
+            | WheelError::MetadataFileParseError(_)
+            | WheelError::RecordFileParseError(_)
+            | WheelError::WheelFileParseError(_)
+            | WheelError::WheelNameParseError(_) => ErrorKind::InvalidWheel,
+            WheelError::VerifyError(verify::VerifyError::UnsupportedHashAlgorithm(_)) => {
+                ErrorKind::UnsupportedFeature
+            }
+            WheelError::VerifyError(_) => ErrorKind::InvalidWheel,
+            WheelError::ZipappError(zipapp::ZipappError::PlatlibWheel(_)) => {
+                ErrorKind::UnsupportedFeature
+            }
+            WheelError::ZipappError(_) => ErrorKind::InvalidWheel,
+            WheelError::ZipError(ZipError::Io(e)) | WheelError::IOError(e) => io_error_kind(e),
+            WheelError::ZipError(ZipError::InvalidArchive(_))
+            | WheelError::ZipError(ZipError::FileNotFound) => ErrorKind::InvalidWheel,
+            WheelError::ZipError(ZipError::UnsupportedArchive(_)) => ErrorKind::UnsupportedFeature,
+        }
+    }
+}
+
+fn io_error_kind(error: &io::Error) -> ErrorKind {
+    match error.kind() {
+        // Produced when reading a file that isn't valid UTF-8, or a corrupt compressed stream.
+        io::ErrorKind::InvalidData | io::ErrorKind::UnexpectedEof => ErrorKind::InvalidWheel,
+        _ => ErrorKind::Io,
+    }
+}
+
+/// Broad categories of [`WheelError`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The wheel is malformed or violates the spec. Retrying won't help.
+    InvalidWheel,
+    /// The wheel is valid, but uses something this crate doesn't support.
+    UnsupportedFeature,
+    /// Reading the underlying data failed. Retrying may help.
+    Io,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_bytes() -> Result<(), WheelError> {
+        use std::io::Write;
+
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file("demo-1.0.dist-info/WHEEL", Default::default())?;
+        writer.write_all(b"Wheel-Version: 1.0\nGenerator: demo\nRoot-Is-Purelib: true\n")?;
+        let bytes = writer.finish()?.into_inner();
+
+        let mut wheel = Wheel::from_bytes("demo-1.0-py3-none-any.whl", bytes.as_slice())?;
+        assert_eq!(wheel.wheel_file()?.generator, "demo");
+        Ok(())
+    }
+
+    #[test]
+    fn test_warnings_once() -> Result<(), WheelError> {
+        use std::io::Write;
+
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file("demo-1.0.dist-info/RECORD", Default::default())?;
+        writer.write_all(b"# generated by demo\ndemo-1.0.dist-info/RECORD,,\n")?;
+        writer.start_file("demo-1.0.dist-info/METADATA", Default::default())?;
+        writer.write_all(b"Metadata-Version: 2.1\nName: demo\nVersion: 1.0\nDynamic: Summary\n")?;
+        let bytes = writer.finish()?.into_inner();
+
+        let mut wheel = Wheel::from_bytes("demo-1.0-py3-none-any.whl", bytes.as_slice())?;
+        for _ in 0..2 {
+            wheel.record_file()?;
+            wheel.metadata_file()?;
+        }
+        assert_eq!(
+            wheel.warnings(),
+            [
+                Warning::IgnoredRecordLine(1, "# generated by demo".to_string()),
+                Warning::FieldRequiresMetadataVersion(
+                    "Dynamic",
+                    "2.2".to_string(),
+                    "2.1".to_string()
+                ),
+            ],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_iteration_order() -> Result<(), WheelError> {
+        use std::io::Write;
+
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for name in [
+            "demo-1.0.dist-info/WHEEL",
+            "demo-1.0.dist-info/RECORD",
+            "demo-1.0.dist-info/METADATA",
+        ] {
+            writer.start_file(name, Default::default())?;
+        }
+        writer.write_all(b"Name: demo\nVersion: 1.0\nMetadata-Version: 2.1\n")?;
+        let bytes = writer.finish()?.into_inner();
+
+        let mut wheel = Wheel::from_bytes("demo-1.0-py3-none-any.whl", bytes.as_slice())?;
+        let paths = wheel
+            .dist_info_entries()?
+            .into_iter()
+            .map(|entry| entry.path)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "demo-1.0.dist-info/WHEEL",
+                "demo-1.0.dist-info/RECORD",
+                "demo-1.0.dist-info/METADATA",
+            ],
+        );
+        assert_eq!(
+            wheel
+                .classify_entries()
+                .into_iter()
+                .map(|(path, _)| path)
+                .collect::<Vec<_>>(),
+            paths,
+        );
+        let headers = wheel.metadata_headers()?;
+        let names = headers.headers.iter().map(|(name, _)| name.as_str());
+        assert_eq!(
+            names.collect::<Vec<_>>(),
+            ["Name", "Version", "Metadata-Version"],
+        );
+        Ok(())
+    }
+
+    /// Every file in `fixtures/fuzz/<dir>`, in name order.
+    fn fuzz_corpus(dir: &str) -> Vec<Vec<u8>> {
+        let mut paths = std::fs::read_dir(format!("fixtures/fuzz/{dir}"))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths
+            .into_iter()
+            .map(|path| std::fs::read(path).unwrap())
+            .collect()
+    }
+
+    /// Malformed inputs which have made a parser panic, or could have. Each must produce
+    /// an `Ok` or an `Err`; the results themselves aren't checked.
+    #[test]
+    fn test_fuzz_corpus() {
+        for contents in fuzz_corpus("record") {
+            let contents = String::from_utf8_lossy(&contents);
+            let _ = RecordFile::from_str(&contents);
+            let _ = RecordFile::from_str_with_warnings(&contents, Some(100), false);
+        }
+        for contents in fuzz_corpus("metadata") {
+            let contents = String::from_utf8_lossy(&contents);
+            let _ = MetadataHeaders::from_str(&contents);
+            if let Ok(mut metadata_file) = MetadataFile::from_str(&contents) {
+                let _ = metadata_file.check_summary(false);
+            }
+        }
+        for contents in fuzz_corpus("wheel") {
+            let _ = WheelFile::from_str(&String::from_utf8_lossy(&contents));
+        }
+        for contents in fuzz_corpus("entry_points") {
+            let _ = EntryPoints::from_str(&String::from_utf8_lossy(&contents));
+        }
+        for contents in fuzz_corpus("wheel_name") {
+            for line in String::from_utf8_lossy(&contents).lines() {
+                let _ = WheelName::from_str(line);
+                let _ = WheelName::parse_lenient(line);
+                let _ = BuildTag::from_str(line);
+            }
+        }
+        for contents in fuzz_corpus("archive") {
+            for strict in [true, false] {
+                let Ok(mut wheel) = Wheel::options()
+                    .strict(strict)
+                    .open("demo-1.0-py3-none-any.whl", Cursor::new(&contents))
+                else {
+                    continue;
+                };
+                let _ = wheel.wheel_file();
+                let _ = wheel.metadata_file();
+                let _ = wheel.record_file();
+                let _ = wheel.entry_points();
+                let _ = wheel.verify();
+                let _ = wheel.record_coverage();
+                let _ = wheel.compliance_report();
+                let _ = wheel.check_entry_points();
+                let _ = wheel.import_names();
+                let _ = wheel.entry_unix_timestamps();
+                let _ = wheel.classify_entries();
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_kind() {
+        assert_eq!(
+            WheelError::from(wheel_name::WheelNameParseError::NotAWheel).kind(),
+            ErrorKind::InvalidWheel,
+        );
+        assert_eq!(
+            WheelError::from(verify::VerifyError::UnsupportedHashAlgorithm(
+                "md5".to_owned()
+            ))
+            .kind(),
+            ErrorKind::UnsupportedFeature,
+        );
+        assert_eq!(
+            WheelError::from(io::Error::from(io::ErrorKind::ConnectionReset)).kind(),
+            ErrorKind::Io,
+        );
+        assert_eq!(
+            WheelError::from(io::Error::from(io::ErrorKind::InvalidData)).kind(),
+            ErrorKind::InvalidWheel,
+        );
+    }
+}
@@ -0,0 +1,386 @@
+use std::io::Read;
+use std::io::Seek;
+
+use crate::MetadataFile;
+use crate::Warning;
+use crate::Wheel;
+use crate::WheelError;
+
+/// Legacy license file names which tools placed at the root of the `.dist-info` directory
+/// before [PEP 639](https://peps.python.org/pep-0639/) introduced `licenses/`.
+const LEGACY_LICENSE_PREFIXES: &[&str] = &["LICENSE", "LICENCE", "COPYING", "NOTICE", "AUTHORS"];
+
+/// `License ::` classifiers which identify a single license, and the SPDX identifier they imply.
+const CLASSIFIER_SPDX_IDS: &[(&str, &str)] = &[
+    (
+        "License :: OSI Approved :: Apache Software License",
+        "Apache-2.0",
+    ),
+    ("License :: OSI Approved :: ISC License (ISCL)", "ISC"),
+    ("License :: OSI Approved :: MIT License", "MIT"),
+    (
+        "License :: OSI Approved :: Mozilla Public License 2.0 (MPL 2.0)",
+        "MPL-2.0",
+    ),
+    (
+        "License :: OSI Approved :: GNU General Public License v2 (GPLv2)",
+        "GPL-2.0",
+    ),
+    (
+        "License :: OSI Approved :: GNU General Public License v3 (GPLv3)",
+        "GPL-3.0",
+    ),
+    (
+        "License :: OSI Approved :: The Unlicense (Unlicense)",
+        "Unlicense",
+    ),
+    ("License :: OSI Approved :: BSD License", "BSD"),
+];
+
+impl MetadataFile {
+    /// Checks that the `License` field, `License-Expression`, and `License ::` classifiers agree,
+    /// reporting the same ambiguities PyPI warns about on upload, and that `License-Expression`
+    /// is a syntactically valid SPDX expression.
+    pub fn check_license(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        let classifiers = self
+            .classifier
+            .iter()
+            .filter(|classifier| classifier.starts_with("License ::"))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if !self.license_expression.is_empty() {
+            if !is_valid_license_expression(&self.license_expression) {
+                warnings.push(Warning::InvalidLicenseExpression(
+                    self.license_expression.clone(),
+                ));
+            }
+            if !self.license.is_empty() {
+                warnings.push(Warning::LicenseWithLicenseExpression);
+            }
+            if !classifiers.is_empty() {
+                warnings.push(Warning::LicenseClassifiersWithLicenseExpression(
+                    classifiers,
+                ));
+            }
+            return warnings;
+        }
+
+        let Some(license_id) = spdx_id_for_license(&self.license) else {
+            return warnings;
+        };
+        let classifier_ids = classifiers
+            .iter()
+            .filter_map(|classifier| spdx_id_for_classifier(classifier))
+            .collect::<Vec<_>>();
+        if !classifier_ids.is_empty() && !classifier_ids.contains(&license_id) {
+            warnings.push(Warning::LicenseClassifierMismatch(
+                self.license.clone(),
+                classifiers,
+            ));
+        }
+        warnings
+    }
+}
+
+/// Whether `expression` is a syntactically valid SPDX license expression, e.g.
+/// `MIT OR (Apache-2.0 WITH LLVM-exception)`. Identifiers aren't checked against the SPDX
+/// license list, and the `AND`, `OR`, and `WITH` operators must be uppercase.
+fn is_valid_license_expression(expression: &str) -> bool {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in expression.char_indices() {
+        if c.is_whitespace() || c == '(' || c == ')' {
+            if let Some(start) = start.take() {
+                tokens.push(&expression[start..i]);
+            }
+            if !c.is_whitespace() {
+                tokens.push(&expression[i..i + 1]);
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if let Some(start) = start {
+        tokens.push(&expression[start..]);
+    }
+    let mut position = 0;
+    parse_license_expression(&tokens, &mut position) && position == tokens.len()
+}
+
+/// Parses terms joined by `AND` or `OR`.
+fn parse_license_expression(tokens: &[&str], position: &mut usize) -> bool {
+    loop {
+        if !parse_license_term(tokens, position) {
+            return false;
+        }
+        match tokens.get(*position) {
+            Some(&"AND") | Some(&"OR") => *position += 1,
+            _ => return true,
+        }
+    }
+}
+
+/// Parses a parenthesized expression, or a license with an optional `WITH` exception.
+fn parse_license_term(tokens: &[&str], position: &mut usize) -> bool {
+    match tokens.get(*position) {
+        Some(&"(") => {
+            *position += 1;
+            if !parse_license_expression(tokens, position) || tokens.get(*position) != Some(&")") {
+                return false;
+            }
+            *position += 1;
+            true
+        }
+        Some(token) if is_license_id(token) => {
+            *position += 1;
+            if tokens.get(*position) == Some(&"WITH") {
+                *position += 1;
+                match tokens.get(*position) {
+                    Some(token) if is_license_id(token) => *position += 1,
+                    _ => return false,
+                }
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Whether `token` is a license or exception identifier, e.g. `GPL-2.0+` or
+/// `LicenseRef-Proprietary`.
+fn is_license_id(token: &str) -> bool {
+    let id = token.strip_suffix('+').unwrap_or(token);
+    !id.is_empty()
+        && !matches!(id, "AND" | "OR" | "WITH")
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':'))
+}
+
+/// A license file bundled in a wheel's `.dist-info` directory, see [`Wheel::license_files`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct LicenseFile {
+    /// Path of the file within the archive.
+    pub path: String,
+    /// The `License-File` metadata entry which refers to this file, if any: its path relative
+    /// to `.dist-info/licenses/` (`Metadata-Version` 2.4), or for a legacy file at the root of
+    /// `.dist-info`, its file name (as setuptools wrote before 2.4).
+    pub license_file: Option<String>,
+    pub contents: Vec<u8>,
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Reads the license files under `.dist-info/licenses/`, and legacy license files
+    /// (e.g. `LICENSE`, `COPYING`) at the root of `.dist-info`, in archive order.
+    ///
+    /// Each file is paired with the `License-File` entry in `METADATA` which names it.
+    /// Entries which name no file in the archive are recorded as
+    /// [`Warning::MissingLicenseFile`].
+    pub fn license_files(&mut self) -> Result<Vec<LicenseFile>, WheelError> {
+        let headers = self.metadata_headers()?;
+        let mut declared = headers
+            .get_all("License-File")
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+
+        let dist_info = self.dist_info_path("");
+        let licenses = self.dist_info_path("licenses/");
+        let mut license_files = Vec::new();
+        for i in 0..self.archive.len() {
+            let zip_file = self.archive.by_index(i)?;
+            if zip_file.is_dir() {
+                continue;
+            }
+            let path = zip_file.name().to_owned();
+            let relative_path = if let Some(relative_path) = path.strip_prefix(&licenses) {
+                relative_path
+            } else {
+                match path.strip_prefix(&dist_info) {
+                    Some(filename) if is_legacy_license_file(filename) => filename,
+                    _ => continue,
+                }
+            };
+            let license_file = declared
+                .iter()
+                .position(|entry| entry == relative_path)
+                .map(|index| declared.remove(index));
+
+            self.limits.check_size(&path, None, zip_file.size())?;
+            let mut contents = Vec::new();
+            self.limits
+                .take_entry(zip_file)
+                .read_to_end(&mut contents)?;
+            self.limits.check_size(&path, None, contents.len() as u64)?;
+            license_files.push(LicenseFile {
+                path,
+                license_file,
+                contents,
+            });
+        }
+        self.warnings
+            .extend(declared.into_iter().map(Warning::MissingLicenseFile));
+        Ok(license_files)
+    }
+}
+
+fn is_legacy_license_file(filename: &str) -> bool {
+    !filename.contains('/')
+        && LEGACY_LICENSE_PREFIXES
+            .iter()
+            .any(|prefix| filename.to_ascii_uppercase().starts_with(prefix))
+}
+
+fn spdx_id_for_classifier(classifier: &str) -> Option<&'static str> {
+    CLASSIFIER_SPDX_IDS
+        .iter()
+        .find(|(known, _)| *known == classifier)
+        .map(|(_, id)| *id)
+}
+
+/// Recognizes `License` values which name a single well-known license.
+/// Free text which doesn't match exactly is never flagged.
+fn spdx_id_for_license(license: &str) -> Option<&'static str> {
+    let license = license.trim().to_ascii_lowercase();
+    let id = match license.as_str() {
+        "apache-2.0" | "apache 2.0" | "apache license 2.0" | "apache software license" => {
+            "Apache-2.0"
+        }
+        "isc" => "ISC",
+        "mit" | "mit license" => "MIT",
+        "mpl-2.0" | "mpl 2.0" => "MPL-2.0",
+        "gpl-2.0" | "gplv2" => "GPL-2.0",
+        "gpl-3.0" | "gplv3" => "GPL-3.0",
+        "unlicense" => "Unlicense",
+        "bsd" | "bsd license" | "bsd-2-clause" | "bsd-3-clause" => "BSD",
+        _ => return None,
+    };
+    Some(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::io::Write;
+
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_check_license() {
+        let consistent = MetadataFile {
+            license: "MIT".to_string(),
+            classifier: vec!["License :: OSI Approved :: MIT License".to_string()],
+            ..MetadataFile::default()
+        };
+        assert_eq!(consistent.check_license(), vec![]);
+
+        let mismatch = MetadataFile {
+            license: "Apache 2.0".to_string(),
+            ..consistent.clone()
+        };
+        assert_eq!(
+            mismatch.check_license(),
+            vec![Warning::LicenseClassifierMismatch(
+                "Apache 2.0".to_string(),
+                vec!["License :: OSI Approved :: MIT License".to_string()],
+            )],
+        );
+
+        let expression = MetadataFile {
+            license_expression: "MIT".to_string(),
+            ..consistent
+        };
+        assert_eq!(
+            expression.check_license(),
+            vec![
+                Warning::LicenseWithLicenseExpression,
+                Warning::LicenseClassifiersWithLicenseExpression(vec![
+                    "License :: OSI Approved :: MIT License".to_string()
+                ]),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_license_expression() {
+        for expression in [
+            "MIT",
+            "GPL-2.0+",
+            "MIT OR Apache-2.0",
+            "(MIT OR Apache-2.0) AND LicenseRef-Proprietary",
+            "Apache-2.0 WITH LLVM-exception OR (BSD-3-Clause AND (ISC))",
+        ] {
+            assert!(is_valid_license_expression(expression), "{expression}");
+        }
+        for expression in [
+            "",
+            "MIT or Apache-2.0",
+            "MIT OR",
+            "(MIT",
+            "MIT)",
+            "MIT WITH",
+            "BSD 3-Clause",
+            "MIT/Apache-2.0",
+        ] {
+            assert!(!is_valid_license_expression(expression), "{expression}");
+        }
+
+        let metadata_file = MetadataFile {
+            license_expression: "MIT and Apache-2.0".to_string(),
+            ..MetadataFile::default()
+        };
+        assert_eq!(
+            metadata_file.check_license(),
+            vec![Warning::InvalidLicenseExpression(
+                "MIT and Apache-2.0".to_string()
+            )],
+        );
+    }
+
+    #[test]
+    fn test_license_files() -> Result<(), WheelError> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, contents) in [
+            ("demo/LICENSE", "not a license file"),
+            ("demo-1.0.dist-info/LICENSE.txt", "legacy"),
+            ("demo-1.0.dist-info/licenses/vendor/NOTICE", "vendored"),
+            (
+                "demo-1.0.dist-info/METADATA",
+                "Name: demo\nLicense-File: vendor/NOTICE\nLicense-File: COPYING\n",
+            ),
+        ] {
+            writer.start_file(name, FileOptions::default())?;
+            writer.write_all(contents.as_bytes())?;
+        }
+        let reader = writer.finish()?;
+
+        let mut wheel = Wheel::open("demo-1.0-py3-none-any.whl", reader)?;
+        assert_eq!(
+            wheel.license_files()?,
+            vec![
+                LicenseFile {
+                    path: "demo-1.0.dist-info/LICENSE.txt".to_string(),
+                    license_file: None,
+                    contents: b"legacy".to_vec(),
+                },
+                LicenseFile {
+                    path: "demo-1.0.dist-info/licenses/vendor/NOTICE".to_string(),
+                    license_file: Some("vendor/NOTICE".to_string()),
+                    contents: b"vendored".to_vec(),
+                },
+            ],
+        );
+        assert_eq!(
+            wheel.warnings(),
+            &[Warning::MissingLicenseFile("COPYING".to_string())],
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+
+use zip::ZipArchive;
+
+use crate::WheelError;
+
+/// Bounds on the resources spent reading a single wheel.
+///
+/// Every limit is `None` (unbounded) by default. Services which process
+/// untrusted wheels should set them to values appropriate for their workload.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Limits {
+    /// Maximum size in bytes of the `METADATA` file.
+    pub max_metadata_size: Option<u64>,
+    /// Maximum number of rows in the `RECORD` file.
+    pub max_record_rows: Option<usize>,
+    /// Maximum uncompressed size in bytes of any single archive entry.
+    pub max_entry_size: Option<u64>,
+}
+
+impl Limits {
+    pub fn max_metadata_size(mut self, max_metadata_size: u64) -> Self {
+        self.max_metadata_size = Some(max_metadata_size);
+        self
+    }
+
+    pub fn max_record_rows(mut self, max_record_rows: usize) -> Self {
+        self.max_record_rows = Some(max_record_rows);
+        self
+    }
+
+    pub fn max_entry_size(mut self, max_entry_size: u64) -> Self {
+        self.max_entry_size = Some(max_entry_size);
+        self
+    }
+
+    /// Checks an archive entry's size against the configured limits.
+    /// `dist_info_filename` is set when the entry is a file in the dist-info directory.
+    pub(crate) fn check_size(
+        &self,
+        path: &str,
+        dist_info_filename: Option<&str>,
+        size: u64,
+    ) -> Result<(), LimitError> {
+        if dist_info_filename == Some("METADATA") {
+            if let Some(limit) = self.max_metadata_size {
+                if size > limit {
+                    return Err(LimitError::MetadataTooLarge(limit));
+                }
+            }
+        }
+        if let Some(limit) = self.max_entry_size {
+            if size > limit {
+                return Err(LimitError::EntryTooLarge(path.to_owned(), limit));
+            }
+        }
+        Ok(())
+    }
+
+    /// Limits `reader` to one byte more than an archive entry may contain, so that
+    /// oversized entries can be detected without reading them in full.
+    pub(crate) fn take_entry<T: Read>(&self, reader: T) -> io::Take<T> {
+        reader.take(self.max_size(None).map_or(u64::MAX, |limit| limit + 1))
+    }
+
+    /// The most bytes any read of the given entry needs to consume to enforce the limits.
+    pub(crate) fn max_size(&self, dist_info_filename: Option<&str>) -> Option<u64> {
+        let metadata_limit = match dist_info_filename {
+            Some("METADATA") => self.max_metadata_size,
+            _ => None,
+        };
+        match (metadata_limit, self.max_entry_size) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+/// Reads the entry at `path` to a string, enforcing `limits` on both its declared and actual size.
+/// `dist_info_filename` is the name of the file whose limits apply, e.g. `METADATA`.
+pub(crate) fn read_entry_to_string<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    limits: &Limits,
+    path: &str,
+    dist_info_filename: Option<&str>,
+) -> Result<String, WheelError> {
+    let zip_file = archive.by_name(path)?;
+    limits.check_size(path, dist_info_filename, zip_file.size())?;
+    let contents = read_to_string_limited(zip_file, limits.max_size(dist_info_filename))?;
+    limits.check_size(path, dist_info_filename, contents.len() as u64)?;
+    Ok(contents)
+}
+
+/// Like [`read_entry_to_string`], for entries which may not be valid UTF-8.
+pub(crate) fn read_entry_to_end<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    limits: &Limits,
+    path: &str,
+    dist_info_filename: Option<&str>,
+) -> Result<Vec<u8>, WheelError> {
+    let zip_file = archive.by_name(path)?;
+    limits.check_size(path, dist_info_filename, zip_file.size())?;
+    let mut contents = Vec::new();
+    zip_file
+        .take(
+            limits
+                .max_size(dist_info_filename)
+                .map_or(u64::MAX, |limit| limit + 1),
+        )
+        .read_to_end(&mut contents)?;
+    limits.check_size(path, dist_info_filename, contents.len() as u64)?;
+    Ok(contents)
+}
+
+/// Reads at most `limit + 1` bytes from `reader`, so that callers can detect entries whose
+/// contents are larger than the size they declare without reading them in full.
+fn read_to_string_limited(reader: impl Read, limit: Option<u64>) -> io::Result<String> {
+    let mut contents = String::new();
+    reader
+        .take(limit.map_or(u64::MAX, |limit| limit + 1))
+        .read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+#[derive(thiserror::Error, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum LimitError {
+    #[error("archive entry exceeds the configured size limit")]
+    EntryTooLarge(String, u64),
+
+    #[error("METADATA exceeds the configured size limit")]
+    MetadataTooLarge(u64),
+}
+
+impl LimitError {
+    /// The configured limit which was exceeded.
+    pub fn limit(&self) -> u64 {
+        match self {
+            LimitError::EntryTooLarge(_, limit) | LimitError::MetadataTooLarge(limit) => *limit,
+        }
+    }
+}
@@ -1,50 +1,1117 @@
+use std::borrow::Cow;
+use std::fmt;
 use std::str::FromStr;
 
+use pep440_rs::Version;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::text::normalize_text;
+use crate::text::Charset;
+use crate::Requirement;
+use crate::RequirementParseError;
+use crate::SpecifierSet;
+use crate::Warning;
+
 /// Used for parsing `... .dist-info/METADATA` files.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct MetadataFile {
     pub metadata_version: String,
     pub name: String,
     pub version: String,
-    // TODO: dynamic https://packaging.python.org/en/latest/specifications/core-metadata/#dynamic-multiple-use
-    pub dynamic: (),
+    /// Fields which the build backend will fill in when building from the sdist, as written.
+    pub dynamic: Vec<String>,
     pub platform: String,
     pub supported_platform: String,
     pub summary: String,
-    // TODO: this one is going to need some special treatment
-    // https://packaging.python.org/en/latest/specifications/core-metadata/#description
-    pub description: String,
-    pub description_content_type: String,
+    pub description: Description,
     pub keywords: Vec<String>,
     pub home_page: String,
     pub author: String,
-    pub author_email: Vec<String>,
+    pub author_email: Vec<EmailAddress>,
     pub maintainer: String,
-    pub maintainer_email: Vec<String>,
+    pub maintainer_email: Vec<EmailAddress>,
     pub license: String,
+    /// An SPDX license expression, see [`MetadataFile::check_license`].
+    pub license_expression: String,
+    /// Paths of license files, see [`Wheel::license_files`](crate::Wheel::license_files).
+    /// From `Metadata-Version` 2.4 they're relative to `.dist-info/licenses/`. Earlier,
+    /// setuptools wrote them relative to the project, and put the files themselves at the
+    /// root of `.dist-info`, e.g. `License-File: LICENSE` for `.dist-info/LICENSE`.
+    pub license_file: Vec<String>,
     pub classifier: Vec<String>,
-    // TODO: https://packaging.python.org/en/latest/specifications/core-metadata/#requires-dist-multiple-use
-    pub requires_dist: (),
-    pub requires_python: String,
+    pub requires_dist: Vec<Requirement>,
+    /// `None` when `Requires-Python` is missing or empty, i.e. any version is supported.
+    pub requires_python: Option<SpecifierSet>,
     pub requires_external: Vec<String>,
     pub project_url: ProjectURL,
     // This is probably going to need some smarts https://packaging.python.org/en/latest/specifications/core-metadata/#provides-extra-multiple-use
     pub provides_extra: Vec<String>,
-    // Intentionally omitting fields which are marked as rarely used.
-    // https://packaging.python.org/en/latest/specifications/core-metadata/#rarely-used-fields
+    /// Every header which isn't parsed into a field above, in its original order, e.g.
+    /// `Provides-Dist`, `Obsoletes-Dist`, `Download-URL`, unrecognized fields, and repeats
+    /// of single-use fields after the first.
+    pub extra_fields: Vec<(String, String)>,
+    pub(crate) headers: MetadataHeaders,
 }
 
+/// An address from `Author-email` or `Maintainer-email`, e.g.
+/// `"Doe, Jane" <jane@example.com>` or `jane@example.com`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EmailAddress {
+    /// The display name, without quotes, e.g. `Doe, Jane`.
+    pub name: Option<String>,
+    pub email: String,
+}
+
+impl EmailAddress {
+    /// Parses a single address, as written by e.g. `pyproject.toml` build backends. Anything
+    /// which isn't of the form `name <email>` is taken to be a bare email address.
+    pub fn parse(s: &str) -> EmailAddress {
+        let s = s.trim();
+        let Some((name, email)) = s.strip_suffix('>').and_then(|s| s.rsplit_once('<')) else {
+            return EmailAddress {
+                name: None,
+                email: s.to_owned(),
+            };
+        };
+        let name = name.trim();
+        let name = match name
+            .strip_prefix('"')
+            .and_then(|name| name.strip_suffix('"'))
+        {
+            Some(quoted) => quoted.replace("\\\"", "\"").replace("\\\\", "\\"),
+            None => name.to_owned(),
+        };
+        EmailAddress {
+            name: (!name.is_empty()).then_some(name),
+            email: email.trim().to_owned(),
+        }
+    }
+}
+
+/// Formats the address as RFC 5322 does, quoting names with special characters.
+impl fmt::Display for EmailAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) if name.contains(|c: char| "()<>[]:;@\\,.\"".contains(c)) => {
+                let escaped = name.replace('\\', "\\\\").replace('"', "\\\"");
+                write!(f, "\"{escaped}\" <{}>", self.email)
+            }
+            Some(name) => write!(f, "{name} <{}>", self.email),
+            None => write!(f, "{}", self.email),
+        }
+    }
+}
+
+/// The long description, from either the `Description` header or the message body, and the
+/// `Description-Content-Type` it's written in.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Description {
+    /// `Description-Content-Type` as written, e.g. `text/markdown; variant=GFM`, or empty.
+    pub content_type: String,
+    pub text: String,
+}
+
+impl Description {
+    /// The media type, e.g. `text/markdown`, lowercased. This is `text/x-rst` when
+    /// `Description-Content-Type` is missing, as the spec says to assume.
+    pub fn media_type(&self) -> String {
+        match self.content_type.split(';').next().map(str::trim) {
+            Some(media_type) if !media_type.is_empty() => media_type.to_ascii_lowercase(),
+            _ => "text/x-rst".to_owned(),
+        }
+    }
+
+    /// The value of a `Description-Content-Type` parameter, e.g. `GFM` for `variant`.
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        content_type_parameter(&self.content_type, name)
+    }
+
+    pub fn is_markdown(&self) -> bool {
+        self.media_type() == "text/markdown"
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct ProjectURL {
     pub label: String,
     pub url: String,
 }
 
+impl MetadataFile {
+    /// Every header in the file in their original order, including repeated and
+    /// unrecognized fields.
+    pub fn headers(&self) -> &MetadataHeaders {
+        &self.headers
+    }
+
+    /// Applies Unicode NFC normalization to the human-readable identity fields
+    /// (name, summary, author, maintainer), so that metadata from producers which
+    /// encode the same text differently compares equal.
+    pub fn normalize_unicode(&mut self) {
+        for field in [
+            &mut self.name,
+            &mut self.summary,
+            &mut self.author,
+            &mut self.maintainer,
+        ] {
+            *field = field.nfc().collect();
+        }
+    }
+
+    /// Whether `Requires-Python` allows `version`, which it does if it's missing.
+    pub fn supports_python(&self, version: &Version) -> bool {
+        self.requires_python
+            .as_ref()
+            .is_none_or(|requires_python| requires_python.contains(version))
+    }
+}
+
+impl MetadataFile {
+    /// Enforces that `Summary` is a single line, as the spec requires.
+    ///
+    /// In strict mode a multi-line summary is an error. Otherwise the summary is
+    /// truncated to its first line and the original value is returned as a warning.
+    pub fn check_summary(
+        &mut self,
+        strict: bool,
+    ) -> Result<Option<Warning>, MetadataFileParseError> {
+        let Some((first_line, _)) = self.summary.split_once(['\r', '\n']) else {
+            return Ok(None);
+        };
+        if strict {
+            return Err(MetadataFileParseError::MultiLineSummary);
+        }
+        let first_line = first_line.trim_end().to_owned();
+        let original = std::mem::replace(&mut self.summary, first_line);
+        Ok(Some(Warning::MultiLineSummary(original)))
+    }
+}
+
+/// Fields introduced after `Metadata-Version` 2.1, and the version which introduced them.
+///
+/// `License-File` is left out: it was standardized in 2.4, but setuptools and wheel wrote it
+/// for years before that, so it's expected in older metadata.
+const FIELD_VERSIONS: &[(&str, (u32, u32))] =
+    &[("Dynamic", (2, 2)), ("License-Expression", (2, 4))];
+
+impl MetadataFile {
+    /// Checks the fields introduced by `Metadata-Version` 2.2 to 2.4 against the declared
+    /// `Metadata-Version`, and that `Dynamic` doesn't name `Name`, `Version`, or
+    /// `Metadata-Version`, which the spec forbids. Nothing is checked against a
+    /// `Metadata-Version` which isn't of the form `major.minor`.
+    pub fn check_metadata_version(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        if let Some(declared) = parse_metadata_version(&self.metadata_version) {
+            for (field, required) in FIELD_VERSIONS {
+                if declared < *required && self.headers.get(field).is_some() {
+                    warnings.push(Warning::FieldRequiresMetadataVersion(
+                        field,
+                        format!("{}.{}", required.0, required.1),
+                        self.metadata_version.clone(),
+                    ));
+                }
+            }
+        }
+        for field in &self.dynamic {
+            if ["Name", "Version", "Metadata-Version"]
+                .iter()
+                .any(|name| field.trim().eq_ignore_ascii_case(name))
+            {
+                warnings.push(Warning::InvalidDynamic(field.clone()));
+            }
+        }
+        warnings
+    }
+}
+
+fn parse_metadata_version(metadata_version: &str) -> Option<(u32, u32)> {
+    let (major, minor) = metadata_version.trim().split_once('.')?;
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+#[cfg(feature = "url")]
+impl MetadataFile {
+    /// `Home-page` parsed as a URL. `None` if the field is empty.
+    pub fn home_page_url(&self) -> Option<Result<url::Url, url::ParseError>> {
+        if self.home_page.is_empty() {
+            None
+        } else {
+            Some(url::Url::parse(&self.home_page))
+        }
+    }
+
+    /// Returns a warning for every URL-valued field which isn't a valid URL.
+    /// The raw strings are left untouched.
+    pub fn check_urls(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        if let Some(Err(_)) = self.home_page_url() {
+            warnings.push(Warning::InvalidUrl("home_page", self.home_page.clone()));
+        }
+        if let Some(Err(_)) = self.project_url.url() {
+            warnings.push(Warning::InvalidUrl(
+                "project_url",
+                self.project_url.url.clone(),
+            ));
+        }
+        warnings
+    }
+}
+
+#[cfg(feature = "url")]
+impl ProjectURL {
+    /// The URL parsed as a URL. `None` if it's empty.
+    pub fn url(&self) -> Option<Result<url::Url, url::ParseError>> {
+        if self.url.is_empty() {
+            None
+        } else {
+            Some(url::Url::parse(&self.url))
+        }
+    }
+}
+
+/// The raw headers and body of a `METADATA` file, in their original order.
+///
+/// Unlike [`MetadataFile`], this keeps repeated and unrecognized fields as-is.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MetadataHeaders {
+    /// Header names and values. Folded values keep their line breaks.
+    pub headers: Vec<(String, String)>,
+    /// Everything after the first empty line.
+    pub body: String,
+}
+
+impl MetadataHeaders {
+    /// The value of the first header named `name`, compared case-insensitively.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The values of every header named `name`, compared case-insensitively.
+    pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.headers
+            .iter()
+            .filter(move |(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The `charset` parameter of `Content-Type`, which some generators write for the whole
+    /// file, or else of `Description-Content-Type`, e.g. `UTF-8` for
+    /// `text/markdown; charset=UTF-8; variant=GFM`.
+    pub fn charset(&self) -> Option<&str> {
+        ["Content-Type", "Description-Content-Type"]
+            .into_iter()
+            .filter_map(|name| self.get(name))
+            .find_map(|value| content_type_parameter(value, "charset"))
+    }
+}
+
+/// The value of the parameter `name` in a content type like `text/plain; charset=UTF-8`,
+/// with the name compared case-insensitively.
+fn content_type_parameter<'a>(content_type: &'a str, name: &str) -> Option<&'a str> {
+    content_type.split(';').skip(1).find_map(|parameter| {
+        let (key, value) = parameter.split_once('=')?;
+        key.trim()
+            .eq_ignore_ascii_case(name)
+            .then(|| value.trim().trim_matches('"'))
+    })
+}
+
+/// Parses an RFC 822 style message into its headers and body.
+///
+/// Continuation lines (those starting with whitespace) are appended to the previous
+/// header's value, separated by a newline. Headers end at the first empty line.
+impl FromStr for MetadataHeaders {
+    type Err = MetadataFileParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = normalize_text(s);
+        let mut headers: Vec<(String, String)> = Vec::new();
+        let mut rest = s.as_ref();
+        while !rest.is_empty() {
+            let (line, remainder) = match rest.find('\n') {
+                Some(index) => (&rest[..index], &rest[index + 1..]),
+                None => (rest, ""),
+            };
+            rest = remainder;
+
+            if line.is_empty() {
+                break;
+            }
+            if line.starts_with([' ', '\t']) {
+                let Some((_, value)) = headers.last_mut() else {
+                    return Err(MetadataFileParseError::MalformedHeader(line.to_owned()));
+                };
+                value.push('\n');
+                value.push_str(line);
+                continue;
+            }
+            let Some((key, value)) = line.split_once(':') else {
+                return Err(MetadataFileParseError::MalformedHeader(line.to_owned()));
+            };
+            headers.push((key.trim().to_owned(), value.trim_start().to_owned()));
+        }
+        Ok(MetadataHeaders {
+            headers,
+            body: rest.to_owned(),
+        })
+    }
+}
+
+/// The headers parsed into [`MetadataFile`] fields, and whether every value is kept rather
+/// than only the first.
+const PARSED_FIELDS: &[(&str, bool)] = &[
+    ("Metadata-Version", false),
+    ("Name", false),
+    ("Version", false),
+    ("Dynamic", true),
+    ("Platform", false),
+    ("Supported-Platform", false),
+    ("Summary", false),
+    ("Description", false),
+    ("Description-Content-Type", false),
+    ("Keywords", false),
+    ("Home-page", false),
+    ("Author", false),
+    ("Author-email", false),
+    ("Maintainer", false),
+    ("Maintainer-email", false),
+    ("License", false),
+    ("License-Expression", false),
+    ("License-File", true),
+    ("Classifier", true),
+    ("Requires-Dist", true),
+    ("Requires-Python", false),
+    ("Requires-External", true),
+    ("Project-URL", false),
+    ("Provides-Extra", true),
+];
+
+/// The headers which [`PARSED_FIELDS`] doesn't account for, and the `unparsed` ones whose
+/// values couldn't be parsed, in their original order.
+fn extra_fields(headers: &MetadataHeaders, unparsed: &[(&str, &str)]) -> Vec<(String, String)> {
+    let mut seen = Vec::new();
+    headers
+        .headers
+        .iter()
+        .filter(|(key, value)| {
+            if unparsed
+                .iter()
+                .any(|(name, v)| key.eq_ignore_ascii_case(name) && value == v)
+            {
+                return true;
+            }
+            let Some((name, multiple_use)) = PARSED_FIELDS
+                .iter()
+                .find(|(name, _)| key.eq_ignore_ascii_case(name))
+            else {
+                return true;
+            };
+            if *multiple_use || !seen.contains(name) {
+                seen.push(*name);
+                return false;
+            }
+            true
+        })
+        .cloned()
+        .collect()
+}
+
+/// Parses the fields of a `METADATA` file out of its [`MetadataHeaders`].
+///
+/// Single-use fields take their first value, and missing fields are left empty. Folded
+/// `Description` and `License` values are unfolded, with the `        ` or `       |`
+/// indentation of each continuation line removed, and `Description` falls back to the
+/// message body. Each `Requires-Dist` is parsed as a [`Requirement`]. `Keywords` is split
+/// on commas, or on whitespace if it has none, as older generators wrote it. The email
+/// fields are split on commas outside of quoted names and angle brackets into
+/// [`EmailAddress`]es, and `project_url` is the first `Project-URL`. Every other header is
+/// kept in `extra_fields`.
+///
+/// A `Requires-Dist` or `Requires-Python` which can't be parsed is an error, see
+/// [`MetadataFile::from_str_with_warnings`] to tolerate them.
 impl FromStr for MetadataFile {
     type Err = MetadataFileParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        todo!()
+        Self::from_str_with_warnings(s, true).map(|(metadata_file, _)| metadata_file)
+    }
+}
+
+impl MetadataFile {
+    /// Like [`MetadataFile::from_str`], but unless `strict`, values which can't be parsed
+    /// are kept in `extra_fields` instead of failing: a `Requires-Dist` which isn't a valid
+    /// requirement is left out of `requires_dist`, with a [`Warning::InvalidRequirement`],
+    /// and a `Requires-Python` which isn't a valid specifier set, e.g. `>=3.6.*`, leaves
+    /// `requires_python` as `None`, with a [`Warning::InvalidRequiresPython`].
+    pub fn from_str_with_warnings(
+        s: &str,
+        strict: bool,
+    ) -> Result<(Self, Vec<Warning>), MetadataFileParseError> {
+        let headers = MetadataHeaders::from_str(s)?;
+        let field = |name: &str| headers.get(name).unwrap_or_default().to_owned();
+        let fields = |name: &str| headers.get_all(name).map(str::to_owned).collect();
+        let addresses = |name: &str| split_addresses(headers.get(name).unwrap_or_default());
+
+        let project_url = headers
+            .get("Project-URL")
+            .map(|value| match value.split_once(',') {
+                Some((label, url)) => ProjectURL {
+                    label: label.trim().to_owned(),
+                    url: url.trim().to_owned(),
+                },
+                None => ProjectURL {
+                    label: String::new(),
+                    url: value.trim().to_owned(),
+                },
+            })
+            .unwrap_or_default();
+
+        let mut warnings = Vec::new();
+        let mut unparsed = Vec::new();
+        let mut requires_dist = Vec::new();
+        for value in headers.get_all("Requires-Dist") {
+            match Requirement::from_str(value) {
+                Ok(requirement) => requires_dist.push(requirement),
+                Err(e) if strict => {
+                    return Err(MetadataFileParseError::InvalidRequirement(
+                        value.to_owned(),
+                        e,
+                    ))
+                }
+                Err(_) => {
+                    warnings.push(Warning::InvalidRequirement(value.to_owned()));
+                    unparsed.push(("Requires-Dist", value));
+                }
+            }
+        }
+        let requires_python = match parse_requires_python(headers.get("Requires-Python")) {
+            Ok(requires_python) => requires_python,
+            Err(e) if strict => return Err(e),
+            Err(_) => {
+                let value = headers.get("Requires-Python").unwrap_or_default();
+                warnings.push(Warning::InvalidRequiresPython(value.to_owned()));
+                unparsed.push(("Requires-Python", value));
+                None
+            }
+        };
+
+        let metadata_file = MetadataFile {
+            metadata_version: field("Metadata-Version"),
+            name: field("Name"),
+            version: field("Version"),
+            dynamic: fields("Dynamic"),
+            platform: field("Platform"),
+            supported_platform: field("Supported-Platform"),
+            summary: field("Summary"),
+            description: Description {
+                content_type: field("Description-Content-Type"),
+                text: match headers.get("Description") {
+                    Some(description) => unfold(description),
+                    None => headers.body.clone(),
+                },
+            },
+            keywords: split_keywords(headers.get("Keywords").unwrap_or_default()),
+            home_page: field("Home-page"),
+            author: field("Author"),
+            author_email: addresses("Author-email"),
+            maintainer: field("Maintainer"),
+            maintainer_email: addresses("Maintainer-email"),
+            license: unfold(headers.get("License").unwrap_or_default()),
+            license_expression: field("License-Expression"),
+            license_file: fields("License-File"),
+            classifier: fields("Classifier"),
+            requires_dist,
+            requires_python,
+            requires_external: fields("Requires-External"),
+            project_url,
+            provides_extra: fields("Provides-Extra"),
+            extra_fields: extra_fields(&headers, &unparsed),
+            headers,
+        };
+        Ok((metadata_file, warnings))
     }
 }
 
+/// Decodes the contents of `METADATA`, which the spec requires to be UTF-8.
+///
+/// Contents which are valid UTF-8 are always decoded as such, with a warning if they declare
+/// another [`MetadataHeaders::charset`] and aren't ASCII. Otherwise, unless `strict`, they're
+/// decoded as the declared charset if it's latin-1 or windows-1252, or else as windows-1252,
+/// with a warning recording the charset used.
+pub(crate) fn decode_metadata(
+    raw: &[u8],
+    strict: bool,
+) -> Result<(Cow<'_, str>, Option<Warning>), MetadataFileParseError> {
+    let declared = if raw.is_ascii() {
+        None
+    } else {
+        MetadataHeaders::from_str(&String::from_utf8_lossy(raw))
+            .ok()
+            .and_then(|headers| headers.charset().map(str::to_owned))
+    };
+    match std::str::from_utf8(raw) {
+        Ok(contents) => {
+            let warning = declared
+                .filter(|charset| {
+                    !matches!(charset.to_ascii_lowercase().as_str(), "utf-8" | "utf8")
+                })
+                .map(|charset| Warning::MetadataCharset(Some(charset), "utf-8"));
+            Ok((Cow::Borrowed(contents), warning))
+        }
+        Err(_) if strict => Err(MetadataFileParseError::InvalidUtf8),
+        Err(_) => {
+            let charset = declared
+                .as_deref()
+                .and_then(Charset::from_label)
+                .unwrap_or(Charset::Windows1252);
+            Ok((
+                Cow::Owned(charset.decode(raw)),
+                Some(Warning::MetadataCharset(declared, charset.name())),
+            ))
+        }
+    }
+}
+
+/// Parses `Requires-Python`, treating an empty value the same as a missing one.
+pub(crate) fn parse_requires_python(
+    value: Option<&str>,
+) -> Result<Option<SpecifierSet>, MetadataFileParseError> {
+    match value.map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(value) => SpecifierSet::from_str(value)
+            .map(Some)
+            .map_err(|_| MetadataFileParseError::InvalidRequiresPython(value.to_owned())),
+    }
+}
+
+/// Removes the indentation which setuptools and others add to the continuation lines of
+/// folded values: `       |` for `Description`, and eight spaces or a single space or tab
+/// otherwise.
+fn unfold(value: &str) -> String {
+    let mut lines = value.split('\n');
+    let mut unfolded = lines.next().unwrap_or_default().to_owned();
+    for line in lines {
+        let line = line
+            .strip_prefix("       |")
+            .or_else(|| line.strip_prefix("        "))
+            .or_else(|| line.strip_prefix([' ', '\t']))
+            .unwrap_or(line);
+        unfolded.push('\n');
+        unfolded.push_str(line);
+    }
+    unfolded
+}
+
+/// Splits `Keywords` on commas, or on whitespace if there are none.
+fn split_keywords(value: &str) -> Vec<String> {
+    let keywords: Vec<&str> = if value.contains(',') {
+        value.split(',').collect()
+    } else {
+        value.split_whitespace().collect()
+    };
+    keywords
+        .into_iter()
+        .map(str::trim)
+        .filter(|keyword| !keyword.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Splits a list of email addresses like `"Doe, Jane" <jane@example.com>, bob@example.com`
+/// on the commas which aren't inside quotes or angle brackets, and parses each.
+fn split_addresses(value: &str) -> Vec<EmailAddress> {
+    let mut addresses = Vec::new();
+    let mut start = 0;
+    let (mut quoted, mut bracketed) = (false, false);
+    for (i, c) in value.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '<' if !quoted => bracketed = true,
+            '>' if !quoted => bracketed = false,
+            ',' if !quoted && !bracketed => {
+                addresses.push(&value[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    addresses.push(&value[start..]);
+    addresses
+        .into_iter()
+        .map(str::trim)
+        .filter(|address| !address.is_empty())
+        .map(EmailAddress::parse)
+        .collect()
+}
+
 #[derive(thiserror::Error, Debug)]
-pub enum MetadataFileParseError {}
+#[non_exhaustive]
+pub enum MetadataFileParseError {
+    #[error("Summary must be a single line")]
+    MultiLineSummary,
+
+    #[error("malformed header line")]
+    MalformedHeader(String),
+
+    #[error("METADATA is not valid UTF-8")]
+    InvalidUtf8,
+
+    /// The `Requires-Dist` or `Obsoletes-Dist` value which couldn't be parsed.
+    #[error("invalid requirement")]
+    InvalidRequirement(String, RequirementParseError),
+
+    /// The `Requires-Python` value which couldn't be parsed.
+    #[error("invalid Requires-Python")]
+    InvalidRequiresPython(String),
+
+    /// `metadata.json` isn't valid JSON, or doesn't have the expected shape.
+    #[error("invalid metadata.json")]
+    InvalidJson(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_normalize_unicode() {
+        let mut metadata_file = MetadataFile {
+            name: "cafe\u{301}".to_string(),
+            author: "Ame\u{301}lie".to_string(),
+            ..MetadataFile::default()
+        };
+        metadata_file.normalize_unicode();
+        assert_eq!(metadata_file.name, "caf\u{e9}");
+        assert_eq!(metadata_file.author, "Am\u{e9}lie");
+    }
+
+    #[test]
+    fn test_check_summary() {
+        let mut metadata_file = MetadataFile {
+            summary: "A library.\nWith a second line.".to_string(),
+            ..MetadataFile::default()
+        };
+        assert!(matches!(
+            metadata_file.clone().check_summary(true),
+            Err(MetadataFileParseError::MultiLineSummary),
+        ));
+        assert_eq!(
+            metadata_file.check_summary(false).unwrap(),
+            Some(Warning::MultiLineSummary(
+                "A library.\nWith a second line.".to_string()
+            )),
+        );
+        assert_eq!(metadata_file.summary, "A library.");
+        assert_eq!(metadata_file.check_summary(true).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_headers() -> Result<(), MetadataFileParseError> {
+        let metadata_headers = MetadataHeaders::from_str(concat!(
+            "Metadata-Version: 2.1\r\n",
+            "Name: demo\n",
+            "Classifier: A\n",
+            "Classifier: B\n",
+            "License: line one\n",
+            "        line two\n",
+            "\n",
+            "The description.\n",
+        ))?;
+        assert_eq!(
+            metadata_headers.headers,
+            vec![
+                ("Metadata-Version".to_string(), "2.1".to_string()),
+                ("Name".to_string(), "demo".to_string()),
+                ("Classifier".to_string(), "A".to_string()),
+                ("Classifier".to_string(), "B".to_string()),
+                (
+                    "License".to_string(),
+                    "line one\n        line two".to_string()
+                ),
+            ],
+        );
+        assert_eq!(metadata_headers.body, "The description.\n");
+        assert_eq!(metadata_headers.get("name"), Some("demo"));
+        assert_eq!(
+            metadata_headers.get_all("Classifier").collect::<Vec<_>>(),
+            vec!["A", "B"],
+        );
+
+        assert!(matches!(
+            MetadataHeaders::from_str("Name demo\n"),
+            Err(MetadataFileParseError::MalformedHeader(line)) if line == "Name demo",
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_headers_bom_crlf() -> Result<(), MetadataFileParseError> {
+        let metadata_headers = MetadataHeaders::from_str(concat!(
+            "\u{feff}Metadata-Version: 2.1\r\n",
+            "Name: demo\r",
+            "License: line one\r\n",
+            "        line two\r\n",
+            "\r\n",
+            "The description.\r\n",
+        ))?;
+        assert_eq!(
+            metadata_headers.headers,
+            vec![
+                ("Metadata-Version".to_string(), "2.1".to_string()),
+                ("Name".to_string(), "demo".to_string()),
+                (
+                    "License".to_string(),
+                    "line one\n        line two".to_string()
+                ),
+            ],
+        );
+        assert_eq!(metadata_headers.body, "The description.\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_metadata() -> Result<(), MetadataFileParseError> {
+        let headers = MetadataHeaders::from_str(concat!(
+            "Content-Type: text/plain\n",
+            "Description-Content-Type: text/x-rst; Charset=\"ISO-8859-1\"\n",
+        ))?;
+        assert_eq!(headers.charset(), Some("ISO-8859-1"));
+
+        let raw = b"Name: demo\nDescription-Content-Type: text/plain; charset=latin-1\n\nCaf\xe9\n";
+        let (contents, warning) = decode_metadata(raw, false)?;
+        assert_eq!(
+            MetadataFile::from_str(&contents)?.description.text,
+            "Caf\u{e9}\n"
+        );
+        assert_eq!(
+            warning,
+            Some(Warning::MetadataCharset(
+                Some("latin-1".to_string()),
+                "iso-8859-1"
+            ))
+        );
+        assert!(matches!(
+            decode_metadata(raw, true),
+            Err(MetadataFileParseError::InvalidUtf8)
+        ));
+
+        let (contents, warning) = decode_metadata(b"Name: demo\n\n\x93Hi\x94\n", false)?;
+        assert_eq!(contents, "Name: demo\n\n\u{201c}Hi\u{201d}\n");
+        assert_eq!(
+            warning,
+            Some(Warning::MetadataCharset(None, "windows-1252"))
+        );
+
+        let raw = "Name: demo\nDescription-Content-Type: text/plain; charset=cp1252\n\nCaf\u{e9}\n";
+        let (contents, warning) = decode_metadata(raw.as_bytes(), true)?;
+        assert_eq!(contents, raw);
+        assert_eq!(
+            warning,
+            Some(Warning::MetadataCharset(
+                Some("cp1252".to_string()),
+                "utf-8"
+            ))
+        );
+
+        let raw = "Name: d\u{e9}mo\nDescription-Content-Type: text/plain; charset=UTF-8\n";
+        assert_eq!(
+            decode_metadata(raw.as_bytes(), true)?,
+            (Cow::Borrowed(raw), None)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_metadata_version() -> Result<(), MetadataFileParseError> {
+        let metadata_file = MetadataFile::from_str(concat!(
+            "Metadata-Version: 2.4\n",
+            "Name: demo\n",
+            "Dynamic: Requires-Dist\n",
+            "License-Expression: MIT\n",
+            "License-File: LICENSE\n",
+            "License-File: licenses/NOTICE\n",
+        ))?;
+        assert_eq!(metadata_file.dynamic, ["Requires-Dist"]);
+        assert_eq!(metadata_file.license_file, ["LICENSE", "licenses/NOTICE"]);
+        assert_eq!(metadata_file.check_metadata_version(), vec![]);
+
+        let metadata_file = MetadataFile::from_str(concat!(
+            "Metadata-Version: 2.2\n",
+            "Name: demo\n",
+            "Dynamic: version\n",
+            "License-Expression: MIT\n",
+            "License-File: LICENSE\n",
+        ))?;
+        assert_eq!(
+            metadata_file.check_metadata_version(),
+            vec![
+                Warning::FieldRequiresMetadataVersion(
+                    "License-Expression",
+                    "2.4".to_string(),
+                    "2.2".to_string()
+                ),
+                Warning::InvalidDynamic("version".to_string()),
+            ],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_metadata_file() -> Result<(), MetadataFileParseError> {
+        let metadata_file = MetadataFile::from_str(concat!(
+            "Metadata-Version: 2.1\n",
+            "Name: demo\n",
+            "Version: 1.0\n",
+            "Keywords: one, two\n",
+            "Author-email: a@example.com, b@example.com\n",
+            "Classifier: A\n",
+            "Classifier: B\n",
+            "Project-URL: Source, https://example.com/demo\n",
+            "Provides-Extra: test\n",
+            "Provides-Dist: demo_compat\n",
+            "Project-URL: Docs, https://example.com/docs\n",
+            "Download-URL: https://example.com/demo-1.0.tar.gz\n",
+            "X-Custom: kept\n",
+            "\n",
+            "The description.\n",
+        ))?;
+        assert_eq!(metadata_file.name, "demo");
+        assert_eq!(metadata_file.version, "1.0");
+        assert_eq!(metadata_file.keywords, ["one", "two"]);
+        assert_eq!(
+            metadata_file
+                .author_email
+                .iter()
+                .map(|address| address.email.as_str())
+                .collect::<Vec<_>>(),
+            ["a@example.com", "b@example.com"]
+        );
+        assert_eq!(metadata_file.classifier, ["A", "B"]);
+        assert_eq!(
+            metadata_file.project_url,
+            ProjectURL {
+                label: "Source".to_string(),
+                url: "https://example.com/demo".to_string(),
+            },
+        );
+        assert_eq!(metadata_file.provides_extra, ["test"]);
+        assert_eq!(
+            metadata_file.extra_fields,
+            [
+                ("Provides-Dist", "demo_compat"),
+                ("Project-URL", "Docs, https://example.com/docs"),
+                ("Download-URL", "https://example.com/demo-1.0.tar.gz"),
+                ("X-Custom", "kept"),
+            ]
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+        );
+        assert_eq!(metadata_file.description.text, "The description.\n");
+        assert_eq!(metadata_file.description.media_type(), "text/x-rst");
+        assert_eq!(metadata_file.summary, "");
+        assert_eq!(metadata_file.requires_python, None);
+        assert!(metadata_file.supports_python(&Version::from_str("2.7").unwrap()));
+        assert!(matches!(
+            MetadataFile::from_str("Name: demo\nRequires-Python: >=three\n"),
+            Err(MetadataFileParseError::InvalidRequiresPython(value)) if value == ">=three",
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_requires_dist() -> Result<(), MetadataFileParseError> {
+        let contents = concat!(
+            "Name: demo
+",
+            "Requires-Dist: requests
+",
+            "Requires-Dist: -bad
+",
+            "X-Custom: kept
+",
+        );
+        assert!(matches!(
+            MetadataFile::from_str(contents),
+            Err(MetadataFileParseError::InvalidRequirement(value, _)) if value == "-bad",
+        ));
+        assert!(MetadataFile::from_str_with_warnings(contents, true).is_err());
+
+        let (metadata_file, warnings) = MetadataFile::from_str_with_warnings(contents, false)?;
+        assert_eq!(metadata_file.requires_dist.len(), 1);
+        assert_eq!(metadata_file.requires_dist[0].name, "requests");
+        assert_eq!(
+            metadata_file.extra_fields,
+            [("Requires-Dist", "-bad"), ("X-Custom", "kept")]
+                .map(|(key, value)| (key.to_string(), value.to_string())),
+        );
+        assert_eq!(warnings, [Warning::InvalidRequirement("-bad".to_string())]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_requires_python() -> Result<(), MetadataFileParseError> {
+        let contents = "Name: demo\nRequires-Python: >=3.6.*\n";
+        assert!(matches!(
+            MetadataFile::from_str_with_warnings(contents, true),
+            Err(MetadataFileParseError::InvalidRequiresPython(value)) if value == ">=3.6.*",
+        ));
+
+        let (metadata_file, warnings) = MetadataFile::from_str_with_warnings(contents, false)?;
+        assert_eq!(metadata_file.requires_python, None);
+        assert_eq!(
+            metadata_file.extra_fields,
+            [("Requires-Python".to_string(), ">=3.6.*".to_string())],
+        );
+        assert_eq!(
+            warnings,
+            [Warning::InvalidRequiresPython(">=3.6.*".to_string())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_description() {
+        let description = Description {
+            content_type: "Text/Markdown; charset=UTF-8; Variant=CommonMark".to_string(),
+            text: "# Demo\n".to_string(),
+        };
+        assert_eq!(description.media_type(), "text/markdown");
+        assert_eq!(description.parameter("variant"), Some("CommonMark"));
+        assert_eq!(description.parameter("charset"), Some("UTF-8"));
+        assert!(description.is_markdown());
+        assert!(!Description::default().is_markdown());
+    }
+
+    #[test]
+    fn test_email_address() {
+        assert_eq!(
+            EmailAddress::parse(" Jane Doe <jane@example.com> "),
+            EmailAddress {
+                name: Some("Jane Doe".to_string()),
+                email: "jane@example.com".to_string(),
+            },
+        );
+        let quoted = EmailAddress::parse(r#""Doe, \"JD\" Jane" <jane@example.com>"#);
+        assert_eq!(quoted.name.as_deref(), Some(r#"Doe, "JD" Jane"#));
+        assert_eq!(
+            quoted.to_string(),
+            r#""Doe, \"JD\" Jane" <jane@example.com>"#
+        );
+        assert_eq!(
+            EmailAddress::parse("<jane@example.com>"),
+            EmailAddress::parse("jane@example.com"),
+        );
+        assert_eq!(
+            EmailAddress::parse("Jane <jane@example.com>").to_string(),
+            "Jane <jane@example.com>"
+        );
+    }
+
+    #[test]
+    fn test_parse_requests_metadata() -> Result<(), MetadataFileParseError> {
+        let contents = fs::read_to_string("fixtures/requests_METADATA.txt").unwrap();
+        let metadata_file = MetadataFile::from_str(&contents)?;
+        assert_eq!(metadata_file.metadata_version, "2.1");
+        assert_eq!(metadata_file.name, "requests");
+        assert_eq!(metadata_file.version, "2.31.0");
+        assert_eq!(metadata_file.summary, "Python HTTP for Humans.");
+        assert_eq!(
+            metadata_file.author_email,
+            [EmailAddress::parse("me@kennethreitz.org")]
+        );
+        assert_eq!(metadata_file.license, "Apache 2.0");
+        assert_eq!(metadata_file.classifier.len(), 8);
+        assert_eq!(
+            metadata_file.requires_python,
+            Some(SpecifierSet::from_str(">=3.7").unwrap())
+        );
+        assert!(metadata_file.supports_python(&Version::from_str("3.12").unwrap()));
+        assert!(!metadata_file.supports_python(&Version::from_str("3.6").unwrap()));
+        assert_eq!(metadata_file.description.content_type, "text/markdown");
+        assert!(metadata_file.description.is_markdown());
+        assert_eq!(
+            metadata_file.project_url,
+            ProjectURL {
+                label: "Documentation".to_string(),
+                url: "https://requests.readthedocs.io".to_string(),
+            },
+        );
+        assert_eq!(
+            metadata_file.provides_extra,
+            ["security", "socks", "use_chardet_on_py3"],
+        );
+        assert_eq!(
+            metadata_file.description.text,
+            "# Requests\n\n**Requests** is a simple, yet elegant, HTTP library.\n",
+        );
+        assert_eq!(metadata_file.requires_dist.len(), 6);
+        let pysocks = &metadata_file.requires_dist[4];
+        assert_eq!(pysocks.name, "PySocks");
+        assert_eq!(pysocks.marker.as_deref(), Some("extra == 'socks'"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_legacy_metadata() -> Result<(), MetadataFileParseError> {
+        let contents = fs::read_to_string("fixtures/legacy_METADATA.txt").unwrap();
+        let metadata_file = MetadataFile::from_str(&contents)?;
+        assert_eq!(metadata_file.name, "numpy");
+        assert_eq!(
+            metadata_file.author_email,
+            [
+                EmailAddress {
+                    name: Some("NumPy Developers, et al.".to_string()),
+                    email: "numpy-discussion@python.org".to_string(),
+                },
+                EmailAddress {
+                    name: None,
+                    email: "travis@example.com".to_string(),
+                },
+            ],
+        );
+        assert_eq!(
+            metadata_file.author_email[0].to_string(),
+            "\"NumPy Developers, et al.\" <numpy-discussion@python.org>",
+        );
+        assert_eq!(
+            metadata_file.license,
+            "BSD\n\nCopyright (c) 2005-2019, NumPy Developers.",
+        );
+        assert_eq!(
+            metadata_file.description.text,
+            concat!(
+                "It provides:\n",
+                "\n",
+                " - a powerful N-dimensional array object\n",
+                " - sophisticated (broadcasting) functions",
+            ),
+        );
+        assert_eq!(metadata_file.keywords, ["array", "numerical", "scientific"]);
+        assert_eq!(metadata_file.platform, "Windows");
+        Ok(())
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn test_check_urls() {
+        let metadata_file = MetadataFile {
+            home_page: "https://example.com".to_string(),
+            project_url: ProjectURL {
+                label: "Source".to_string(),
+                url: "not a url".to_string(),
+            },
+            ..MetadataFile::default()
+        };
+        assert_eq!(
+            metadata_file.check_urls(),
+            vec![Warning::InvalidUrl("project_url", "not a url".to_string())],
+        );
+    }
+}
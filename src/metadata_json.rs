@@ -0,0 +1,245 @@
+//! The `metadata.json` file written by `bdist_wheel` before 2016, from the withdrawn
+//! [PEP 426](https://peps.python.org/pep-0426/) draft.
+
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::io::Seek;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::metadata_file::parse_requires_python;
+use crate::EmailAddress;
+use crate::MetadataFile;
+use crate::MetadataFileParseError;
+use crate::ProjectURL;
+use crate::Requirement;
+use crate::Wheel;
+use crate::WheelError;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct MetadataJson {
+    metadata_version: String,
+    name: String,
+    version: String,
+    summary: String,
+    license: String,
+    classifiers: Vec<String>,
+    keywords: Vec<String>,
+    extras: Vec<String>,
+    requires_python: String,
+    run_requires: Vec<RequiresGroup>,
+    extensions: Extensions,
+}
+
+/// A group of `run_requires`, which apply for an extra or in an environment.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RequiresGroup {
+    requires: Vec<String>,
+    extra: Option<String>,
+    environment: Option<String>,
+}
+
+impl RequiresGroup {
+    /// The environment marker for the group, as `Requires-Dist` would write it.
+    fn marker(&self) -> Option<String> {
+        let extra = self
+            .extra
+            .as_ref()
+            .map(|extra| format!("extra == \"{extra}\""));
+        match (&self.environment, extra) {
+            (Some(environment), Some(extra)) => Some(format!("({environment}) and {extra}")),
+            (Some(environment), None) => Some(environment.clone()),
+            (None, extra) => extra,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Extensions {
+    #[serde(rename = "python.details")]
+    python_details: PythonDetails,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PythonDetails {
+    project_urls: BTreeMap<String, String>,
+    contacts: Vec<Contact>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Contact {
+    name: String,
+    email: String,
+    role: String,
+}
+
+impl MetadataFile {
+    /// Parses a `metadata.json` file into the fields it shares with `METADATA`.
+    ///
+    /// The `Home` project URL becomes `home_page`, and the first other one `project_url`.
+    /// Contacts with the `author` role become the author, and the rest the maintainer.
+    /// `run_requires` become `requires_dist`, with the group's extra and environment as the
+    /// marker. Other dependencies aren't mapped, and [`MetadataFile::headers`] is empty.
+    pub fn from_metadata_json(s: &str) -> Result<Self, MetadataFileParseError> {
+        let value = serde_json::from_str::<serde_json::Value>(s)
+            .map_err(|e| MetadataFileParseError::InvalidJson(e.to_string()))?;
+        if !value.is_object() {
+            return Err(MetadataFileParseError::InvalidJson(
+                "expected a JSON object".to_owned(),
+            ));
+        }
+        let json = serde_json::from_value::<MetadataJson>(value)
+            .map_err(|e| MetadataFileParseError::InvalidJson(e.to_string()))?;
+        let mut requires_dist = Vec::new();
+        for group in &json.run_requires {
+            let marker = group.marker();
+            for requires in &group.requires {
+                let mut requirement = Requirement::from_str(requires)
+                    .map_err(|e| MetadataFileParseError::InvalidRequirement(requires.clone(), e))?;
+                requirement.marker = match (requirement.marker, &marker) {
+                    (Some(own), Some(marker)) => Some(format!("({own}) and ({marker})")),
+                    (own, marker) => own.or_else(|| marker.clone()),
+                };
+                requires_dist.push(requirement);
+            }
+        }
+        let mut metadata_file = MetadataFile {
+            metadata_version: json.metadata_version,
+            name: json.name,
+            version: json.version,
+            summary: json.summary,
+            license: json.license,
+            classifier: json.classifiers,
+            keywords: json.keywords,
+            provides_extra: json.extras,
+            requires_dist,
+            requires_python: parse_requires_python(Some(&json.requires_python))?,
+            ..MetadataFile::default()
+        };
+
+        let details = json.extensions.python_details;
+        for (label, url) in details.project_urls {
+            if label == "Home" {
+                metadata_file.home_page = url;
+            } else if metadata_file.project_url.url.is_empty() {
+                metadata_file.project_url = ProjectURL { label, url };
+            }
+        }
+        for contact in details.contacts {
+            let (name, emails) = if contact.role == "author" {
+                (&mut metadata_file.author, &mut metadata_file.author_email)
+            } else {
+                (
+                    &mut metadata_file.maintainer,
+                    &mut metadata_file.maintainer_email,
+                )
+            };
+            if !contact.email.is_empty() {
+                emails.push(EmailAddress {
+                    name: (!contact.name.is_empty()).then(|| contact.name.clone()),
+                    email: contact.email,
+                });
+            }
+            if name.is_empty() {
+                *name = contact.name;
+            }
+        }
+        Ok(metadata_file)
+    }
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Reads the deprecated `metadata.json`, for wheels built before 2016. Returns `None`
+    /// if the wheel doesn't have one. See [`MetadataFile::from_metadata_json`].
+    pub fn metadata_json(&mut self) -> Result<Option<MetadataFile>, WheelError> {
+        match self.dist_info_contents("metadata.json") {
+            Ok(contents) => Ok(Some(MetadataFile::from_metadata_json(&contents)?)),
+            Err(WheelError::ZipError(zip::result::ZipError::FileNotFound)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    const METADATA_JSON: &str = r#"{
+        "classifiers": ["Programming Language :: Python :: 2"],
+        "extensions": {
+            "python.details": {
+                "contacts": [
+                    {"email": "alice@example.com", "name": "Alice", "role": "author"}
+                ],
+                "document_names": {"description": "DESCRIPTION.rst"},
+                "project_urls": {"Home": "https://example.com"}
+            }
+        },
+        "extras": ["test"],
+        "generator": "bdist_wheel (0.24.0)",
+        "metadata_version": "2.0",
+        "name": "demo",
+        "run_requires": [
+            {"requires": ["six"]},
+            {"extra": "test", "environment": "python_version < '3'", "requires": ["mock"]}
+        ],
+        "summary": "A demo.",
+        "version": "1.0"
+    }"#;
+
+    #[test]
+    fn test_from_metadata_json() -> Result<(), MetadataFileParseError> {
+        assert_eq!(
+            MetadataFile::from_metadata_json(METADATA_JSON)?,
+            MetadataFile {
+                metadata_version: "2.0".to_string(),
+                name: "demo".to_string(),
+                version: "1.0".to_string(),
+                summary: "A demo.".to_string(),
+                home_page: "https://example.com".to_string(),
+                author: "Alice".to_string(),
+                author_email: vec![EmailAddress {
+                    name: Some("Alice".to_string()),
+                    email: "alice@example.com".to_string(),
+                }],
+                classifier: vec!["Programming Language :: Python :: 2".to_string()],
+                provides_extra: vec!["test".to_string()],
+                requires_dist: vec![
+                    Requirement::from_str("six").unwrap(),
+                    Requirement::from_str("mock; (python_version < '3') and extra == \"test\"")
+                        .unwrap(),
+                ],
+                ..MetadataFile::default()
+            },
+        );
+        assert!(matches!(
+            MetadataFile::from_metadata_json("[]"),
+            Err(MetadataFileParseError::InvalidJson(_)),
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_metadata_json() -> Result<(), WheelError> {
+        let builder = WheelBuilder::new("demo-1.0-py2-none-any.whl")?;
+        assert_eq!(builder.open()?.metadata_json()?, None);
+
+        let mut wheel = builder
+            .file("demo-1.0.dist-info/metadata.json", METADATA_JSON)
+            .open()?;
+        assert_eq!(
+            wheel.metadata_json()?.map(|m| m.name),
+            Some("demo".to_string())
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use std::io::Read;
+use std::io::Seek;
+
+use crate::HashAlgorithm;
+use crate::Limits;
+use crate::StreamedWheel;
+use crate::Wheel;
+use crate::WheelError;
+
+/// Options for opening a [`Wheel`], created with [`Wheel::options`].
+///
+/// ```no_run
+/// # use std::fs::File;
+/// # use pep_427::{Limits, Wheel};
+/// let file = File::open("requests-2.29.0-py3-none-any.whl")?;
+/// let wheel = Wheel::options()
+///     .strict(true)
+///     .limits(Limits::default().max_metadata_size(1 << 20))
+///     .open("requests-2.29.0-py3-none-any.whl", file)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct OpenOptions {
+    pub(crate) strict: bool,
+    pub(crate) limits: Limits,
+    pub(crate) expected_hash: Option<(HashAlgorithm, String)>,
+    pub(crate) normalize_unicode: bool,
+}
+
+impl Default for OpenOptions {
+    fn default() -> Self {
+        Self {
+            strict: false,
+            limits: Limits::default(),
+            expected_hash: None,
+            normalize_unicode: true,
+        }
+    }
+}
+
+impl OpenOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rejects wheels which deviate from the spec in ways that are otherwise tolerated.
+    ///
+    /// This covers every tolerated deviation at once; they can't yet be allowed individually.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Whether to NFC-normalize metadata strings, see [`MetadataFile::normalize_unicode`].
+    /// Enabled by default.
+    ///
+    /// [`MetadataFile::normalize_unicode`]: crate::MetadataFile::normalize_unicode
+    pub fn normalize_unicode(mut self, normalize_unicode: bool) -> Self {
+        self.normalize_unicode = normalize_unicode;
+        self
+    }
+
+    /// Hashes the entire input before opening it, and fails with
+    /// [`VerifyError::HashMismatch`](crate::VerifyError::HashMismatch)
+    /// unless it matches `hex_digest`.
+    pub fn expected_hash(mut self, algorithm: HashAlgorithm, hex_digest: &str) -> Self {
+        self.expected_hash = Some((algorithm, hex_digest.to_ascii_lowercase()));
+        self
+    }
+
+    pub fn open<R: Read + Seek>(&self, name: &str, reader: R) -> Result<Wheel<R>, WheelError> {
+        Wheel::open_with_options(name, reader, self)
+    }
+
+    /// Reads a wheel from a stream which can't seek, e.g. stdin, see [`StreamedWheel`] for
+    /// how this differs from [`OpenOptions::open`].
+    pub fn open_stream<R: Read>(&self, name: &str, reader: R) -> Result<StreamedWheel, WheelError> {
+        StreamedWheel::open_with_options(name, reader, self)
+    }
+}
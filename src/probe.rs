@@ -0,0 +1,117 @@
+use std::collections::BTreeSet;
+use std::io::Read;
+use std::io::Seek;
+use std::str::FromStr;
+
+use pep440_rs::Version;
+use zip::ZipArchive;
+
+use crate::limits;
+use crate::normalize_distribution_name;
+use crate::ArchiveLayoutError;
+use crate::Limits;
+use crate::Tag;
+use crate::WheelError;
+use crate::WheelFile;
+use crate::WheelFileParseError;
+use crate::WheelNameParseError;
+
+/// A wheel's name, version, and tags, read by [`probe`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct WheelIdentity {
+    /// The normalized distribution name.
+    pub distribution: String,
+    pub version: Version,
+    /// The expanded tags from every `Tag` line in `WHEEL`.
+    pub tags: BTreeSet<Tag>,
+}
+
+/// Reads a wheel's identity from its contents alone, for indexers which need nothing else
+/// and can't trust file names.
+///
+/// Only the zip central directory and `WHEEL` are read: the name and version come from the
+/// `.dist-info` directory's name, and the tags from `WHEEL`. `METADATA` and `RECORD` aren't
+/// parsed, so nothing is checked beyond what's needed to read those.
+pub fn probe<R: Read + Seek>(reader: R) -> Result<WheelIdentity, WheelError> {
+    let mut archive = ZipArchive::new(reader)?;
+    let mut dist_infos = Vec::new();
+    for i in 0..archive.len() {
+        let zip_file = archive.by_index_raw(i)?;
+        if let Some(dist_info) = zip_file.name().strip_suffix(".dist-info/WHEEL") {
+            if !dist_info.contains('/') {
+                dist_infos.push(dist_info.to_owned());
+            }
+        }
+    }
+    let dist_info = match dist_infos.len() {
+        0 => return Err(ArchiveLayoutError::MissingDistInfo.into()),
+        1 => dist_infos.remove(0),
+        _ => {
+            dist_infos.sort();
+            return Err(ArchiveLayoutError::MultipleDistInfo(dist_infos).into());
+        }
+    };
+
+    let Some((distribution, version)) = dist_info.split_once('-') else {
+        return Err(WheelNameParseError::PartMismatch.into());
+    };
+    let version = Version::from_str(version)
+        .map_err(|_| WheelNameParseError::InvalidVersion(version.to_owned()))?;
+
+    let path = format!("{dist_info}.dist-info/WHEEL");
+    let contents =
+        limits::read_entry_to_string(&mut archive, &Limits::default(), &path, Some("WHEEL"))?;
+    let mut tags = BTreeSet::new();
+    for tag in WheelFile::from_str(&contents)?.tags {
+        let expanded = Tag::parse_compressed(&tag)
+            .map_err(|_| WheelFileParseError::InvalidFieldValue("tags", tag.clone()))?;
+        tags.extend(expanded);
+    }
+
+    Ok(WheelIdentity {
+        distribution: normalize_distribution_name(distribution),
+        version,
+        tags,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::io::Write;
+
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    use super::*;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_probe() -> Result<(), WheelError> {
+        let reader = WheelBuilder::new("Demo_Package-1.0-py2.py3-none-any.whl")?
+            .file("demo_package/__init__.py", "")
+            .build()?;
+        assert_eq!(
+            probe(reader)?,
+            WheelIdentity {
+                distribution: "demo-package".to_string(),
+                version: Version::from_str("1.0").unwrap(),
+                tags: Tag::parse_compressed("py2.py3-none-any")?,
+            },
+        );
+
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file("demo/__init__.py", FileOptions::default())?;
+        writer.write_all(b"")?;
+        assert!(matches!(
+            probe(writer.finish()?),
+            Err(WheelError::ArchiveLayoutError(
+                ArchiveLayoutError::MissingDistInfo
+            )),
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use pep440_rs::Version;
+
+use crate::normalize_distribution_name;
+use crate::MetadataFile;
+use crate::MetadataFileParseError;
+use crate::Requirement;
+use crate::SpecifierSet;
+
+impl MetadataFile {
+    /// The names in `Provides-Dist`, without their versions or environment markers.
+    pub fn provides_dist(&self) -> Vec<String> {
+        self.headers
+            .get_all("Provides-Dist")
+            .filter_map(|value| {
+                let end = value.find([' ', '\t', '(', ';']).unwrap_or(value.len());
+                let name = value[..end].trim();
+                (!name.is_empty()).then(|| name.to_owned())
+            })
+            .collect()
+    }
+
+    /// Each `Obsoletes-Dist` parsed as a [`Requirement`], e.g. `Gorgon (<1.0)`.
+    pub fn obsoletes_dist(&self) -> Result<Vec<Requirement>, MetadataFileParseError> {
+        self.headers
+            .get_all("Obsoletes-Dist")
+            .map(|value| {
+                Requirement::from_str(value)
+                    .map_err(|e| MetadataFileParseError::InvalidRequirement(value.to_owned(), e))
+            })
+            .collect()
+    }
+}
+
+/// `Provides-Dist` and `Obsoletes-Dist` relationships between many distributions, for
+/// answering which distributions provide or obsolete a name, e.g. when an index validates
+/// an upload. Every name is normalized.
+#[derive(Clone, Debug, Default)]
+pub struct ProvidesIndex {
+    /// Each provided name, and the distributions which provide it.
+    provides: BTreeMap<String, BTreeSet<String>>,
+    /// Each obsoleted name, and the distributions which obsolete it with the versions
+    /// they obsolete.
+    obsoletes: BTreeMap<String, Vec<(String, SpecifierSet)>>,
+}
+
+impl ProvidesIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the relationships declared by a distribution's metadata. A distribution always
+    /// provides its own name.
+    pub fn insert(&mut self, metadata_file: &MetadataFile) -> Result<(), MetadataFileParseError> {
+        let distribution = normalize_distribution_name(&metadata_file.name);
+        let obsoletes = metadata_file.obsoletes_dist()?;
+        for name in std::iter::once(metadata_file.name.clone()).chain(metadata_file.provides_dist())
+        {
+            self.provides
+                .entry(normalize_distribution_name(&name))
+                .or_default()
+                .insert(distribution.clone());
+        }
+        for requirement in obsoletes {
+            self.obsoletes
+                .entry(requirement.normalized_name())
+                .or_default()
+                .push((distribution.clone(), requirement.specifiers));
+        }
+        Ok(())
+    }
+
+    /// The distributions which provide `name`, sorted.
+    pub fn providers(&self, name: &str) -> Vec<&str> {
+        self.provides
+            .get(&normalize_distribution_name(name))
+            .map(|distributions| distributions.iter().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// The distributions which obsolete `version` of `name`, sorted and without duplicates.
+    pub fn obsoleted_by(&self, name: &str, version: &Version) -> Vec<&str> {
+        let mut distributions = self
+            .obsoletes
+            .get(&normalize_distribution_name(name))
+            .into_iter()
+            .flatten()
+            .filter(|(_, specifiers)| specifiers.contains(version))
+            .map(|(distribution, _)| distribution.as_str())
+            .collect::<Vec<_>>();
+        distributions.sort_unstable();
+        distributions.dedup();
+        distributions
+    }
+
+    /// Every name which at least one distribution provides, sorted.
+    pub fn provided_names(&self) -> impl Iterator<Item = &str> {
+        self.provides.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn version(s: &str) -> Version {
+        Version::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_provides_index() -> Result<(), MetadataFileParseError> {
+        let zope = MetadataFile::from_str(concat!(
+            "Name: ZODB\n",
+            "Provides-Dist: Zope.Persistence (3.1)\n",
+            "Provides-Dist: transaction; python_version >= '3'\n",
+            "Obsoletes-Dist: Gorgon (<2.0)\n",
+        ))?;
+        assert_eq!(zope.provides_dist(), ["Zope.Persistence", "transaction"]);
+
+        let mut index = ProvidesIndex::new();
+        index.insert(&zope)?;
+        index.insert(&MetadataFile::from_str(concat!(
+            "Name: transaction\n",
+            "Obsoletes-Dist: gorgon\n",
+        ))?)?;
+
+        assert_eq!(index.providers("Transaction"), ["transaction", "zodb"]);
+        assert_eq!(index.providers("zope-persistence"), ["zodb"]);
+        assert!(index.providers("missing").is_empty());
+        assert_eq!(
+            index.obsoleted_by("Gorgon", &version("1.5")),
+            ["transaction", "zodb"]
+        );
+        assert_eq!(
+            index.obsoleted_by("gorgon", &version("2.0")),
+            ["transaction"]
+        );
+        assert_eq!(
+            index.provided_names().collect::<Vec<_>>(),
+            ["transaction", "zodb", "zope-persistence"],
+        );
+
+        assert!(matches!(
+            index.insert(&MetadataFile::from_str("Name: x\nObsoletes-Dist: -bad\n")?),
+            Err(MetadataFileParseError::InvalidRequirement(value, _)) if value == "-bad",
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+
+/// Storage which can serve byte ranges of an object, e.g. S3 or GCS via HTTP range requests.
+pub trait RangeSource {
+    /// The total size of the object in bytes.
+    fn size(&self) -> io::Result<u64>;
+
+    /// Reads `len` bytes starting at `start`. May return fewer bytes only at the end of the object.
+    fn read_range(&self, start: u64, len: u64) -> io::Result<Vec<u8>>;
+}
+
+/// Adapts a [`RangeSource`] to `Read + Seek`, fetching fixed-size blocks on demand
+/// and keeping the most recently fetched ones in memory.
+///
+/// Reading a wheel's metadata only touches the central directory and a few entries,
+/// so this avoids downloading the whole archive.
+pub struct RangeReader<S> {
+    source: S,
+    len: u64,
+    position: u64,
+    block_size: u64,
+    max_cached_blocks: usize,
+    cache: HashMap<u64, Vec<u8>>,
+    cache_order: VecDeque<u64>,
+}
+
+impl<S: RangeSource> RangeReader<S> {
+    pub const DEFAULT_BLOCK_SIZE: u64 = 256 << 10;
+    pub const DEFAULT_MAX_CACHED_BLOCKS: usize = 16;
+
+    pub fn new(source: S) -> io::Result<Self> {
+        Ok(Self {
+            len: source.size()?,
+            source,
+            position: 0,
+            block_size: Self::DEFAULT_BLOCK_SIZE,
+            max_cached_blocks: Self::DEFAULT_MAX_CACHED_BLOCKS,
+            cache: HashMap::new(),
+            cache_order: VecDeque::new(),
+        })
+    }
+
+    /// Sets the size of each range request, at least one byte. Clears the cache.
+    pub fn block_size(mut self, block_size: u64) -> Self {
+        self.block_size = block_size.max(1);
+        self.cache.clear();
+        self.cache_order.clear();
+        self
+    }
+
+    pub fn max_cached_blocks(mut self, max_cached_blocks: usize) -> Self {
+        self.max_cached_blocks = max_cached_blocks.max(1);
+        self
+    }
+
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn block(&mut self, index: u64) -> io::Result<&[u8]> {
+        if !self.cache.contains_key(&index) {
+            let start = index * self.block_size;
+            let len = self.block_size.min(self.len - start);
+            let block = self.source.read_range(start, len)?;
+            if self.cache_order.len() >= self.max_cached_blocks {
+                if let Some(evicted) = self.cache_order.pop_front() {
+                    self.cache.remove(&evicted);
+                }
+            }
+            self.cache_order.push_back(index);
+            self.cache.insert(index, block);
+        }
+        Ok(&self.cache[&index])
+    }
+}
+
+impl<S: RangeSource> Read for RangeReader<S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position >= self.len || buf.is_empty() {
+            return Ok(0);
+        }
+        let index = self.position / self.block_size;
+        let offset = (self.position % self.block_size) as usize;
+        let block = self.block(index)?;
+        let Some(available) = block.get(offset..) else {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        };
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl<S: RangeSource> Seek for RangeReader<S> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        let Some(position) = position else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            ));
+        };
+        self.position = position;
+        Ok(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::io::Cursor;
+    use std::io::Write;
+
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    use super::*;
+    use crate::Wheel;
+    use crate::WheelError;
+
+    use pretty_assertions::assert_eq;
+
+    struct CountingSource {
+        bytes: Vec<u8>,
+        requests: Cell<usize>,
+    }
+
+    impl RangeSource for CountingSource {
+        fn size(&self) -> io::Result<u64> {
+            Ok(self.bytes.len() as u64)
+        }
+
+        fn read_range(&self, start: u64, len: u64) -> io::Result<Vec<u8>> {
+            self.requests.set(self.requests.get() + 1);
+            Ok(self.bytes[start as usize..(start + len) as usize].to_vec())
+        }
+    }
+
+    #[test]
+    fn test_read_seek() -> io::Result<()> {
+        let source = CountingSource {
+            bytes: (0..=255).collect(),
+            requests: Cell::new(0),
+        };
+        let mut reader = RangeReader::new(source)?.block_size(100);
+
+        let mut buf = [0; 4];
+        reader.seek(SeekFrom::Start(98))?;
+        reader.read_exact(&mut buf)?;
+        assert_eq!(buf, [98, 99, 100, 101]);
+
+        reader.seek(SeekFrom::End(-2))?;
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest)?;
+        assert_eq!(rest, [254, 255]);
+
+        reader.seek(SeekFrom::Start(0))?;
+        reader.read_exact(&mut buf)?;
+        assert_eq!(reader.into_inner().requests.get(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_zero_block_size() -> io::Result<()> {
+        let source = CountingSource {
+            bytes: (0..=255).collect(),
+            requests: Cell::new(0),
+        };
+        let mut reader = RangeReader::new(source)?.block_size(0);
+
+        let mut buf = [0; 2];
+        reader.seek(SeekFrom::Start(10))?;
+        reader.read_exact(&mut buf)?;
+        assert_eq!(buf, [10, 11]);
+        assert_eq!(reader.into_inner().requests.get(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_wheel_over_ranges() -> Result<(), WheelError> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file("demo/data.bin", FileOptions::default())?;
+        writer.write_all(&vec![0; 1 << 20])?;
+        writer.start_file("demo-1.0.dist-info/WHEEL", FileOptions::default())?;
+        writer.write_all(b"Wheel-Version: 1.0\nGenerator: demo\nRoot-Is-Purelib: true\n")?;
+        let source = CountingSource {
+            bytes: writer.finish()?.into_inner(),
+            requests: Cell::new(0),
+        };
+
+        let reader = RangeReader::new(source)?.block_size(4096);
+        let mut wheel = Wheel::open("demo-1.0-py3-none-any.whl", reader)?;
+        assert_eq!(wheel.wheel_file()?.generator, "demo");
+        Ok(())
+    }
+}
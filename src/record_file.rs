@@ -1,7 +1,12 @@
 use csv::ReaderBuilder;
+use csv::StringRecord;
 
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
+use crate::text::normalize_text;
+use crate::Warning;
+
 /// Used for parsing `... .dist-info/RECORD` files.
 #[derive(Debug, Eq, PartialEq)]
 pub struct RecordFile {
@@ -12,20 +17,172 @@ impl FromStr for RecordFile {
     type Err = RecordFileParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RecordFile::from_str_with_max_rows(s, None)
+    }
+}
+
+impl RecordFile {
+    /// Parses a `RECORD` file, failing once it contains more than `max_rows` rows.
+    pub fn from_str_with_max_rows(
+        s: &str,
+        max_rows: Option<usize>,
+    ) -> Result<Self, RecordFileParseError> {
+        let s = normalize_text(s);
         let mut reader = ReaderBuilder::default()
             .has_headers(false)
             .from_reader(s.as_bytes());
 
         let mut records = Vec::new();
         for record in reader.records() {
+            if let Some(max_rows) = max_rows {
+                if records.len() >= max_rows {
+                    return Err(RecordFileParseError::TooManyRows(max_rows));
+                }
+            }
             let record = record?;
             records.push(record.try_into()?);
         }
         Ok(RecordFile { records })
     }
+
+    /// Like [`RecordFile::from_str_with_max_rows`], but rejecting blank lines and `#` comment
+    /// lines, which some generators have emitted. Unless `strict`, they're skipped instead,
+    /// with a [`Warning::IgnoredRecordLine`] for each.
+    pub fn from_str_with_warnings(
+        s: &str,
+        max_rows: Option<usize>,
+        strict: bool,
+    ) -> Result<(Self, Vec<Warning>), RecordFileParseError> {
+        let s = normalize_text(s);
+        let mut reader = ReaderBuilder::default()
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(s.as_bytes());
+
+        let mut records = Vec::new();
+        let mut warnings = Vec::new();
+        let mut ignore = |line: u64, text: String| {
+            let line = line as usize;
+            if strict {
+                return Err(RecordFileParseError::MalformedLine(line));
+            }
+            warnings.push(Warning::IgnoredRecordLine(line, text));
+            Ok(())
+        };
+        let mut row = StringRecord::new();
+        loop {
+            // The CSV reader skips empty lines, so they're found in the gaps between rows.
+            let next_line = reader.position().line();
+            let more = reader.read_record(&mut row)?;
+            let line = match row.position() {
+                Some(position) if more => position.line(),
+                _ => reader.position().line(),
+            };
+            for blank_line in next_line..line {
+                ignore(blank_line, String::new())?;
+            }
+            if !more {
+                break;
+            }
+            if is_ignorable_row(&row) {
+                ignore(line, row.iter().collect::<Vec<_>>().join(","))?;
+                continue;
+            }
+            if let Some(max_rows) = max_rows {
+                if records.len() >= max_rows {
+                    return Err(RecordFileParseError::TooManyRows(max_rows));
+                }
+            }
+            records.push(row.clone().try_into()?);
+        }
+        Ok((RecordFile { records }, warnings))
+    }
+
+    /// Checks the rows for `RECORD` itself and its signature files against the spec:
+    /// `RECORD` must be listed, without a digest or size, since it can't contain its own
+    /// digest; `RECORD.jws` and `RECORD.p7s` must not be listed, since they sign `RECORD`.
+    ///
+    /// `dist_info` is the `.dist-info` directory, e.g. `demo-1.0.dist-info`. Returns every
+    /// violation, in `RECORD` order.
+    pub fn check_self_entries(&self, dist_info: &str) -> Vec<RecordEntryError> {
+        let record_path = format!("{dist_info}/RECORD");
+        let signature_paths = [format!("{record_path}.jws"), format!("{record_path}.p7s")];
+        let mut errors = Vec::new();
+        let mut listed = false;
+        for record in &self.records {
+            if record.filename == record_path {
+                listed = true;
+                if record.digest.is_some() || record.file_size.is_some() {
+                    errors.push(RecordEntryError::RecordHasDigest(record_path.clone()));
+                }
+            } else if signature_paths.contains(&record.filename) {
+                errors.push(RecordEntryError::SignatureListed(record.filename.clone()));
+            }
+        }
+        if !listed {
+            errors.push(RecordEntryError::RecordNotListed(record_path));
+        }
+        errors
+    }
+
+    /// Summarizes the files listed in `RECORD`.
+    pub fn stats(&self) -> RecordStats {
+        let mut stats = RecordStats::default();
+        for record in &self.records {
+            stats.file_count += 1;
+            stats.total_size += record.file_size.unwrap_or(0) as u64;
+
+            let basename = record.filename.rsplit('/').next().unwrap_or_default();
+            let extension = match basename.rsplit_once('.') {
+                Some((stem, extension)) if !stem.is_empty() => extension.to_ascii_lowercase(),
+                _ => String::new(),
+            };
+            *stats.extensions.entry(extension).or_default() += 1;
+
+            match &record.digest {
+                Some(digest) => {
+                    let count = stats.hash_algorithms.entry(digest.method.clone());
+                    *count.or_default() += 1;
+                }
+                None => stats.unhashed_count += 1,
+            }
+
+            if let Some(file_size) = record.file_size {
+                let file = (record.filename.clone(), file_size as u64);
+                stats.largest_files.push(file);
+            }
+        }
+        let largest_files = &mut stats.largest_files;
+        largest_files.sort_by(|(a, a_size), (b, b_size)| b_size.cmp(a_size).then(a.cmp(b)));
+        largest_files.truncate(RecordStats::LARGEST_FILES);
+        stats
+    }
+}
+
+/// Totals over the files listed in a `RECORD` file, see [`RecordFile::stats`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RecordStats {
+    pub file_count: usize,
+    /// Sum of the recorded sizes; files without a size aren't counted.
+    pub total_size: u64,
+    /// Number of files per lowercased extension, without the dot. Files without one count under `""`.
+    pub extensions: BTreeMap<String, usize>,
+    /// The largest files by recorded size, largest first.
+    pub largest_files: Vec<(String, u64)>,
+    /// Number of files hashed with each algorithm, e.g. `{"sha256": 12}`.
+    pub hash_algorithms: BTreeMap<String, usize>,
+    /// Number of files without a digest, usually just `RECORD` itself.
+    pub unhashed_count: usize,
+}
+
+impl RecordStats {
+    /// How many files [`RecordStats::largest_files`] keeps.
+    pub const LARGEST_FILES: usize = 10;
 }
 
 #[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
 pub enum RecordFileParseError {
     #[error(transparent)]
     CSVError(#[from] csv::Error),
@@ -35,31 +192,82 @@ pub enum RecordFileParseError {
 
     #[error("malformed file size")]
     MalformedFileSize,
+
+    #[error("RECORD exceeds the configured row limit")]
+    TooManyRows(usize),
+
+    /// The line number of a blank or comment line.
+    #[error("RECORD contains a line which is not a row")]
+    MalformedLine(usize),
+
+    /// The number of fields in a row which doesn't have exactly three.
+    #[error("RECORD row does not have three fields")]
+    WrongFieldCount(usize),
+}
+
+/// A violation of the rules for the `RECORD` rows of `RECORD` itself and its signatures,
+/// see [`RecordFile::check_self_entries`]. Each has the path of the offending file.
+#[derive(thiserror::Error, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RecordEntryError {
+    #[error("RECORD does not list itself")]
+    RecordNotListed(String),
+
+    #[error("RECORD lists a digest or size for itself")]
+    RecordHasDigest(String),
+
+    #[error("RECORD lists a signature file")]
+    SignatureListed(String),
+}
+
+/// Whether a `RECORD` row is blank, or a `#` comment which isn't a row of three fields.
+fn is_ignorable_row(row: &StringRecord) -> bool {
+    let first = row.get(0).unwrap_or_default();
+    row.iter().all(|field| field.trim().is_empty())
+        || (row.len() < 3 && first.trim_start().starts_with('#'))
 }
 
 #[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct Record {
     pub filename: String,
     pub digest: Option<Digest>,
     pub file_size: Option<usize>,
 }
 
+impl Record {
+    /// The row for `RECORD` itself in `dist_info`, e.g. `demo-1.0.dist-info`, which has
+    /// neither a digest nor a size.
+    pub fn for_record(dist_info: &str) -> Self {
+        Self {
+            filename: format!("{dist_info}/RECORD"),
+            digest: None,
+            file_size: None,
+        }
+    }
+}
+
 impl TryFrom<csv::StringRecord> for Record {
     type Error = RecordFileParseError;
 
     fn try_from(value: csv::StringRecord) -> Result<Self, Self::Error> {
-        let filename = (&value[0]).to_owned();
+        let (Some(filename), Some(digest), Some(file_size), None) =
+            (value.get(0), value.get(1), value.get(2), value.get(3))
+        else {
+            return Err(RecordFileParseError::WrongFieldCount(value.len()));
+        };
+        let filename = filename.to_owned();
 
-        let digest = if value[1].is_empty() {
+        let digest = if digest.is_empty() {
             None
         } else {
-            Some(Digest::from_str(&value[1])?)
+            Some(Digest::from_str(digest)?)
         };
 
-        let file_size = if value[2].is_empty() {
+        let file_size = if file_size.is_empty() {
             None
         } else {
-            let Ok(file_size) = str::parse::<usize>(&value[2]) else {
+            let Ok(file_size) = str::parse::<usize>(file_size) else {
 		return Err(RecordFileParseError::MalformedFileSize);
 	    };
             Some(file_size)
@@ -130,4 +338,162 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bom_crlf() -> Result<(), RecordFileParseError> {
+        let record_file = RecordFile::from_str(concat!(
+            "\u{feff}file.py,sha256=AAAA,3144\r\n",
+            "distribution-1.0.dist-info/RECORD,,\r",
+        ))?;
+        assert_eq!(record_file.records.len(), 2);
+        assert_eq!(record_file.records[0].filename, "file.py");
+        assert_eq!(record_file.records[0].file_size, Some(3144));
+        assert_eq!(
+            record_file.records[1].filename,
+            "distribution-1.0.dist-info/RECORD"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_ignorable_lines() -> Result<(), RecordFileParseError> {
+        let record_file_text = concat!(
+            "# generated by demo\n",
+            "file.py,sha256=AAAA,3144\n",
+            "#file.py,sha256=BBBB,3144\n",
+            "distribution-1.0.dist-info/RECORD,,\n",
+            "\n",
+            "\n",
+        );
+        let (record_file, warnings) =
+            RecordFile::from_str_with_warnings(record_file_text, None, false)?;
+        assert_eq!(
+            record_file
+                .records
+                .iter()
+                .map(|record| record.filename.as_str())
+                .collect::<Vec<_>>(),
+            ["file.py", "#file.py", "distribution-1.0.dist-info/RECORD"],
+        );
+        assert_eq!(
+            warnings,
+            vec![
+                Warning::IgnoredRecordLine(1, "# generated by demo".to_string()),
+                Warning::IgnoredRecordLine(5, String::new()),
+                Warning::IgnoredRecordLine(6, String::new()),
+            ],
+        );
+
+        assert!(matches!(
+            RecordFile::from_str_with_warnings(record_file_text, None, true),
+            Err(RecordFileParseError::MalformedLine(1)),
+        ));
+        let (_, warnings) =
+            RecordFile::from_str_with_warnings("file.py,sha256=AAAA,3144\n", None, true)?;
+        assert!(warnings.is_empty());
+
+        // A quoted path may span lines, including blank ones.
+        let (record_file, warnings) = RecordFile::from_str_with_warnings(
+            "\"notes\n\n# todo.txt\",sha256=AAAA,3144\n\n",
+            None,
+            false,
+        )?;
+        assert_eq!(record_file.records[0].filename, "notes\n\n# todo.txt");
+        assert_eq!(warnings, vec![Warning::IgnoredRecordLine(4, String::new())]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_self_entries() -> Result<(), RecordFileParseError> {
+        let record_file = RecordFile::from_str(concat!(
+            "file.py,sha256=AAAA,3144\n",
+            "demo-1.0.dist-info/RECORD,,\n",
+        ))?;
+        assert!(record_file
+            .check_self_entries("demo-1.0.dist-info")
+            .is_empty());
+        assert_eq!(
+            record_file.records[1],
+            Record::for_record("demo-1.0.dist-info")
+        );
+
+        let record_file = RecordFile::from_str(concat!(
+            "demo-1.0.dist-info/RECORD.jws,sha256=AAAA,3144\n",
+            "demo-1.0.dist-info/RECORD,sha256=BBBB,100\n",
+        ))?;
+        assert_eq!(
+            record_file.check_self_entries("demo-1.0.dist-info"),
+            [
+                RecordEntryError::SignatureListed("demo-1.0.dist-info/RECORD.jws".to_string()),
+                RecordEntryError::RecordHasDigest("demo-1.0.dist-info/RECORD".to_string()),
+            ],
+        );
+        assert_eq!(
+            record_file.check_self_entries("other-1.0.dist-info"),
+            [RecordEntryError::RecordNotListed(
+                "other-1.0.dist-info/RECORD".to_string()
+            )],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_stats() -> Result<(), RecordFileParseError> {
+        let record_file = RecordFile::from_str(concat!(
+            "demo/__init__.py,sha256=AVTFPZpEKzuHr7OvQZmhaU3LvwKz06AJw8mT\\_pNh2yI,100\n",
+            "demo/_speedups.SO,sha512=AVTFPZpEKzuHr7OvQZmhaU3LvwKz06AJw8mT\\_pNh2yI,4000\n",
+            "demo/.gitignore,sha256=AVTFPZpEKzuHr7OvQZmhaU3LvwKz06AJw8mT\\_pNh2yI,10\n",
+            "demo-1.0.dist-info/RECORD,,\n",
+        ))?;
+        let stats = record_file.stats();
+        assert_eq!(stats.file_count, 4);
+        assert_eq!(stats.total_size, 4110);
+        assert_eq!(
+            stats.extensions,
+            BTreeMap::from([
+                (String::new(), 2),
+                ("py".to_string(), 1),
+                ("so".to_string(), 1),
+            ]),
+        );
+        assert_eq!(
+            stats.largest_files,
+            vec![
+                ("demo/_speedups.SO".to_string(), 4000),
+                ("demo/__init__.py".to_string(), 100),
+                ("demo/.gitignore".to_string(), 10),
+            ],
+        );
+        assert_eq!(
+            stats.hash_algorithms,
+            BTreeMap::from([("sha256".to_string(), 2), ("sha512".to_string(), 1)]),
+        );
+        assert_eq!(stats.unhashed_count, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_rows() {
+        let record_file_text = concat!(
+            "file.py,sha256=AVTFPZpEKzuHr7OvQZmhaU3LvwKz06AJw8mT\\_pNh2yI,3144\n",
+            "distribution-1.0.dist-info/RECORD,,\n",
+        );
+        assert!(RecordFile::from_str_with_max_rows(record_file_text, Some(2)).is_ok());
+        assert!(matches!(
+            RecordFile::from_str_with_max_rows(record_file_text, Some(1)),
+            Err(RecordFileParseError::TooManyRows(1)),
+        ));
+    }
+
+    #[test]
+    fn test_wrong_field_count() {
+        assert!(matches!(
+            RecordFile::from_str("file.py\n"),
+            Err(RecordFileParseError::WrongFieldCount(1)),
+        ));
+        assert!(matches!(
+            RecordFile::from_str("file.py,,,\n"),
+            Err(RecordFileParseError::WrongFieldCount(4)),
+        ));
+    }
 }
@@ -0,0 +1,198 @@
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+use std::io::Write;
+use std::str::FromStr;
+
+use pep440_rs::Version;
+use zip::write::FileOptions;
+use zip::ZipWriter;
+
+use crate::hash::encode_record_digest;
+use crate::HashAlgorithm;
+use crate::MetadataHeaders;
+use crate::Wheel;
+use crate::WheelError;
+use crate::WheelName;
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Writes a copy of this wheel to `writer` under a new distribution name and version,
+    /// and returns its name, for pipelines which re-version internal builds.
+    ///
+    /// The `.dist-info` and `.data` directories are renamed to match, `Name` and `Version`
+    /// in `METADATA` are replaced, and `RECORD` is rebuilt with the new paths and `sha256`
+    /// digests. `RECORD` signatures are dropped, since they no longer match. Everything else
+    /// is copied unchanged, in archive order.
+    pub fn repack<W: Write + Seek>(
+        &mut self,
+        distribution: &str,
+        version: Version,
+        writer: W,
+    ) -> Result<WheelName, WheelError> {
+        let name = self
+            .name
+            .clone()
+            .with_distribution(distribution)?
+            .with_version(version);
+        let dist_info = format!("{}-{}.dist-info", name.escaped_distribution(), name.version);
+        let old_dist_info = format!("{}/", self.dist_info);
+        let old_data = self.data_prefix();
+        let data = format!("{}-{}.data/", name.escaped_distribution(), name.version);
+
+        let mut paths = Vec::new();
+        for path in self.file_names().filter(|path| !path.ends_with('/')) {
+            if let Some(file) = path.strip_prefix(&old_dist_info) {
+                if !matches!(file, "RECORD" | "RECORD.jws" | "RECORD.p7s") {
+                    paths.push((path.to_owned(), format!("{dist_info}/{file}")));
+                }
+            } else if let Some(file) = path.strip_prefix(&old_data) {
+                paths.push((path.to_owned(), format!("{data}{file}")));
+            } else {
+                paths.push((path.to_owned(), path.to_owned()));
+            }
+        }
+
+        let version = name.version.to_string();
+        self.write_repacked(paths, &dist_info, writer, |filename, contents| {
+            if filename != "METADATA" {
+                return Ok(contents);
+            }
+            let metadata = rewrite_metadata(&contents, |key, value| match key {
+                "name" => Some(distribution.to_owned()),
+                "version" => Some(version.clone()),
+                _ => Some(value.to_owned()),
+            })?;
+            Ok(metadata.into_bytes())
+        })?;
+        Ok(name)
+    }
+
+    /// Copies the entries at the first path of each pair in `paths` to the second, into a new
+    /// archive in `writer`, and adds a `RECORD` for them to `dist_info`, e.g. `demo-1.0.dist-info`.
+    ///
+    /// `rewrite` is called with the file name and contents of each file copied into
+    /// `dist_info`, and returns the contents to write instead.
+    pub(crate) fn write_repacked<W: Write + Seek>(
+        &mut self,
+        paths: Vec<(String, String)>,
+        dist_info: &str,
+        writer: W,
+        mut rewrite: impl FnMut(&str, Vec<u8>) -> Result<Vec<u8>, WheelError>,
+    ) -> Result<(), WheelError> {
+        let mut writer = ZipWriter::new(writer);
+        let mut record = csv::Writer::from_writer(Vec::new());
+        let dist_info_prefix = format!("{dist_info}/");
+        for (path, new_path) in paths {
+            let mut contents = self.read_entry(&path)?;
+            if let Some(filename) = new_path.strip_prefix(&dist_info_prefix) {
+                contents = rewrite(filename, contents)?;
+            }
+            let algorithm = HashAlgorithm::Sha256;
+            let digest = format!(
+                "{}={}",
+                algorithm.name(),
+                encode_record_digest(&algorithm.digest(&contents))
+            );
+            let size = contents.len().to_string();
+            record
+                .write_record([new_path.as_str(), digest.as_str(), size.as_str()])
+                .map_err(io::Error::from)?;
+            writer.start_file(new_path, FileOptions::default())?;
+            writer.write_all(&contents)?;
+        }
+        let record_path = format!("{dist_info_prefix}RECORD");
+        record
+            .write_record([record_path.as_str(), "", ""])
+            .map_err(io::Error::from)?;
+        let record = record.into_inner().map_err(|e| e.into_error())?;
+        writer.start_file(record_path, FileOptions::default())?;
+        writer.write_all(&record)?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Reads the entry at `path`, enforcing the configured [`Limits`](crate::Limits).
+    pub(crate) fn read_entry(&mut self, path: &str) -> Result<Vec<u8>, WheelError> {
+        let zip_file = self.archive.by_name(path)?;
+        self.limits.check_size(path, None, zip_file.size())?;
+        let mut contents = Vec::new();
+        self.limits
+            .take_entry(zip_file)
+            .read_to_end(&mut contents)?;
+        self.limits.check_size(path, None, contents.len() as u64)?;
+        Ok(contents)
+    }
+}
+
+/// Rewrites each `METADATA` header with `rewrite`, which is called with the header's
+/// lowercased name and its value, and returns the new value, or `None` to drop the header.
+pub(crate) fn rewrite_metadata(
+    contents: &[u8],
+    mut rewrite: impl FnMut(&str, &str) -> Option<String>,
+) -> Result<String, WheelError> {
+    let contents = String::from_utf8(contents.to_vec())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let headers = MetadataHeaders::from_str(&contents)?;
+    let mut metadata = String::new();
+    for (key, value) in &headers.headers {
+        if let Some(value) = rewrite(&key.to_ascii_lowercase(), value) {
+            metadata.push_str(&format!("{key}: {value}\n"));
+        }
+    }
+    if !headers.body.is_empty() {
+        metadata.push('\n');
+        metadata.push_str(&headers.body);
+    }
+    Ok(metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_repack() -> Result<(), WheelError> {
+        let mut wheel = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .file("demo/__init__.py", "hello world")
+            .file("demo-1.0.data/scripts/demo", "#!python")
+            .file("demo-1.0.dist-info/RECORD.jws", "{}")
+            .metadata("Summary", "A demo.")
+            .open()?;
+
+        let mut archive = Cursor::new(Vec::new());
+        let name = wheel.repack(
+            "Demo.Internal",
+            Version::from_str("1.0.post1").unwrap(),
+            &mut archive,
+        )?;
+        assert_eq!(name.to_string(), "demo_internal-1.0.post1-py3-none-any.whl");
+
+        let mut repacked = Wheel::open(&name.to_string(), archive)?;
+        repacked.verify()?;
+        assert!(repacked.record_coverage()?.is_complete());
+        assert_eq!(
+            repacked
+                .classify_entries()
+                .into_iter()
+                .map(|(path, _)| path)
+                .collect::<Vec<_>>(),
+            [
+                "demo/__init__.py",
+                "demo_internal-1.0.post1.data/scripts/demo",
+                "demo_internal-1.0.post1.dist-info/METADATA",
+                "demo_internal-1.0.post1.dist-info/WHEEL",
+                "demo_internal-1.0.post1.dist-info/RECORD",
+            ],
+        );
+        let headers = repacked.metadata_headers()?;
+        assert_eq!(headers.get("Name"), Some("Demo.Internal"));
+        assert_eq!(headers.get("Version"), Some("1.0.post1"));
+        assert_eq!(headers.get("Summary"), Some("A demo."));
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+//! A stable JSON wire format summarizing a wheel, for services built on this crate.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::MetadataFile;
+use crate::RecordFile;
+use crate::Warning;
+use crate::WheelName;
+
+/// A serializable summary of a wheel.
+///
+/// Readers must accept reports with a `schema_version` newer than the one they
+/// were built against: fields are only ever added, and unknown fields are ignored.
+/// Fields added after version 1 default to empty when missing.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct WheelReport {
+    pub schema_version: u32,
+    pub filename: String,
+    pub name: String,
+    pub version: String,
+    pub build_tag: Option<String>,
+    pub python_tag: String,
+    pub abi_tag: String,
+    pub platform_tag: String,
+    pub metadata: Option<MetadataReport>,
+    pub record: Option<RecordReport>,
+    pub lints: Vec<LintReport>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct MetadataReport {
+    pub metadata_version: String,
+    pub name: String,
+    pub version: String,
+    pub summary: String,
+    pub requires_python: String,
+    pub license: String,
+    pub license_expression: String,
+    pub classifiers: Vec<String>,
+    pub provides_extra: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct RecordReport {
+    pub file_count: usize,
+    /// Sum of the sizes listed in `RECORD`; files without a size aren't counted.
+    pub total_size: u64,
+    /// Number of files hashed with each algorithm, e.g. `{"sha256": 12}`.
+    pub hash_algorithms: BTreeMap<String, usize>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct LintReport {
+    /// See [`Warning::code`].
+    pub code: String,
+    pub message: String,
+}
+
+impl WheelReport {
+    /// The schema version written by this version of the crate.
+    pub const SCHEMA_VERSION: u32 = 1;
+
+    /// Builds a report from whichever parts of the wheel the caller has read.
+    pub fn new(
+        wheel_name: &WheelName,
+        metadata_file: Option<&MetadataFile>,
+        record_file: Option<&RecordFile>,
+        warnings: &[Warning],
+    ) -> Self {
+        WheelReport {
+            schema_version: Self::SCHEMA_VERSION,
+            filename: wheel_name.to_string(),
+            name: wheel_name.distribution.clone(),
+            version: wheel_name.version.to_string(),
+            build_tag: wheel_name.build_tag.as_ref().map(ToString::to_string),
+            python_tag: wheel_name.python_tag.clone(),
+            abi_tag: wheel_name.abi_tag.clone(),
+            platform_tag: wheel_name.platform_tag.clone(),
+            metadata: metadata_file.map(MetadataReport::from),
+            record: record_file.map(RecordReport::from),
+            lints: warnings.iter().map(LintReport::from).collect(),
+        }
+    }
+}
+
+impl From<&MetadataFile> for MetadataReport {
+    fn from(metadata_file: &MetadataFile) -> Self {
+        MetadataReport {
+            metadata_version: metadata_file.metadata_version.clone(),
+            name: metadata_file.name.clone(),
+            version: metadata_file.version.clone(),
+            summary: metadata_file.summary.clone(),
+            requires_python: metadata_file
+                .requires_python
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            license: metadata_file.license.clone(),
+            license_expression: metadata_file.license_expression.clone(),
+            classifiers: metadata_file.classifier.clone(),
+            provides_extra: metadata_file.provides_extra.clone(),
+        }
+    }
+}
+
+impl From<&RecordFile> for RecordReport {
+    fn from(record_file: &RecordFile) -> Self {
+        let stats = record_file.stats();
+        RecordReport {
+            file_count: stats.file_count,
+            total_size: stats.total_size,
+            hash_algorithms: stats.hash_algorithms,
+        }
+    }
+}
+
+impl From<&Warning> for LintReport {
+    fn from(warning: &Warning) -> Self {
+        LintReport {
+            code: warning.code().to_owned(),
+            message: warning.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn report() -> WheelReport {
+        let wheel_name = WheelName::from_str("demo-1.0-1-py3-none-any.whl").unwrap();
+        let record_file = RecordFile::from_str(concat!(
+            "demo/__init__.py,sha256=uU0nuZNNPgilLlLX2n2r-sSE7-N6U4DukIj3rOLvzek,11\n",
+            "demo-1.0.dist-info/RECORD,,\n",
+        ))
+        .unwrap();
+        WheelReport::new(
+            &wheel_name,
+            None,
+            Some(&record_file),
+            &[Warning::LicenseWithLicenseExpression],
+        )
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let report = report();
+        assert_eq!(report.record.as_ref().unwrap().total_size, 11);
+        assert_eq!(report.lints[0].code, "license-with-license-expression");
+
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(serde_json::from_str::<WheelReport>(&json).unwrap(), report);
+    }
+
+    /// Version 1 reports must keep deserializing as the schema grows.
+    #[test]
+    fn test_schema_v1() {
+        let v1 = r#"{
+            "schema_version": 1,
+            "filename": "demo-1.0-1-py3-none-any.whl",
+            "name": "demo",
+            "version": "1.0",
+            "build_tag": "1",
+            "python_tag": "py3",
+            "abi_tag": "none",
+            "platform_tag": "any",
+            "metadata": null,
+            "record": {"file_count": 2, "total_size": 11, "hash_algorithms": {"sha256": 1}},
+            "lints": [{"code": "license-with-license-expression", "message": "both License and License-Expression are set"}]
+        }"#;
+        assert_eq!(serde_json::from_str::<WheelReport>(v1).unwrap(), report());
+    }
+
+    /// Reports from newer versions may carry fields this version doesn't know about.
+    #[test]
+    fn test_schema_forward_compatible() {
+        let future = r#"{"schema_version": 2, "name": "demo", "size": 1024, "record": {"largest_files": []}}"#;
+        let report = serde_json::from_str::<WheelReport>(future).unwrap();
+        assert_eq!(report.schema_version, 2);
+        assert_eq!(report.name, "demo");
+        assert_eq!(report.record, Some(RecordReport::default()));
+    }
+}
@@ -0,0 +1,252 @@
+use std::fmt;
+use std::str::FromStr;
+
+use pep440_rs::VersionSpecifier;
+use pep440_rs::VersionSpecifiers;
+
+use crate::normalize_distribution_name;
+use crate::SpecifierSet;
+
+/// A [PEP 508](https://peps.python.org/pep-0508/) dependency specifier, e.g. a
+/// `Requires-Dist` value like `requests[socks] (>=2.8.1) ; python_version < "3.8"`.
+///
+/// Environment markers aren't evaluated, and are kept as written.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Requirement {
+    /// The distribution name as written.
+    pub name: String,
+    pub extras: Vec<String>,
+    /// The allowed versions, which is empty when any version is allowed or `url` is set.
+    pub specifiers: SpecifierSet,
+    /// The URL of a direct reference, e.g. `name @ https://example.com/name-1.0.tar.gz`.
+    pub url: Option<String>,
+    /// The environment marker after the `;`, e.g. `extra == "socks"`.
+    pub marker: Option<String>,
+}
+
+impl Requirement {
+    /// The name normalized as in [`normalize_distribution_name`].
+    pub fn normalized_name(&self) -> String {
+        normalize_distribution_name(&self.name)
+    }
+}
+
+impl FromStr for Requirement {
+    type Err = RequirementParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let name_end = s.find(|c: char| !is_identifier_char(c)).unwrap_or(s.len());
+        let name = &s[..name_end];
+        if !is_identifier(name) {
+            return Err(RequirementParseError::InvalidName(s.to_owned()));
+        }
+        let mut rest = s[name_end..].trim_start();
+
+        let mut extras = Vec::new();
+        if let Some(after_bracket) = rest.strip_prefix('[') {
+            let Some((inner, after)) = after_bracket.split_once(']') else {
+                return Err(RequirementParseError::UnclosedExtras(s.to_owned()));
+            };
+            if !inner.trim().is_empty() {
+                for extra in inner.split(',').map(str::trim) {
+                    if !is_identifier(extra) {
+                        return Err(RequirementParseError::InvalidExtra(extra.to_owned()));
+                    }
+                    extras.push(extra.to_owned());
+                }
+            }
+            rest = after.trim_start();
+        }
+
+        let mut url = None;
+        let mut specifiers = SpecifierSet::from(
+            Vec::<VersionSpecifier>::new()
+                .into_iter()
+                .collect::<VersionSpecifiers>(),
+        );
+        if let Some(after_at) = rest.strip_prefix('@') {
+            // A URL may contain `;`, so a marker after it must be separated by whitespace.
+            let after_at = after_at.trim_start();
+            let url_end = after_at.find(char::is_whitespace).unwrap_or(after_at.len());
+            if url_end == 0 {
+                return Err(RequirementParseError::MissingUrl(s.to_owned()));
+            }
+            url = Some(after_at[..url_end].to_owned());
+            rest = after_at[url_end..].trim_start();
+        } else {
+            let (version, after) = rest.split_at(rest.find(';').unwrap_or(rest.len()));
+            let mut version = version.trim();
+            if let Some(inner) = version.strip_prefix('(') {
+                let Some(inner) = inner.strip_suffix(')') else {
+                    return Err(RequirementParseError::InvalidSpecifier(version.to_owned()));
+                };
+                version = inner.trim();
+            }
+            if !version.is_empty() {
+                specifiers = SpecifierSet::from_str(version)
+                    .map_err(|_| RequirementParseError::InvalidSpecifier(version.to_owned()))?;
+            }
+            rest = after;
+        }
+
+        let marker = match rest.strip_prefix(';') {
+            Some(marker) if !marker.trim().is_empty() => Some(marker.trim().to_owned()),
+            Some(_) => return Err(RequirementParseError::EmptyMarker(s.to_owned())),
+            None if rest.is_empty() => None,
+            None => return Err(RequirementParseError::UnexpectedText(rest.to_owned())),
+        };
+
+        Ok(Requirement {
+            name: name.to_owned(),
+            extras,
+            specifiers,
+            url,
+            marker,
+        })
+    }
+}
+
+/// Formats the requirement in its normal form, e.g. `requests[socks]>=2.8.1; extra == "x"`.
+impl fmt::Display for Requirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.extras.is_empty() {
+            write!(f, "[{}]", self.extras.join(","))?;
+        }
+        match &self.url {
+            Some(url) => write!(f, " @ {url}")?,
+            None => write!(f, "{}", self.specifiers)?,
+        }
+        match &self.marker {
+            // A space is needed after a URL, so that the `;` isn't read as part of it.
+            Some(marker) if self.url.is_some() => write!(f, " ; {marker}"),
+            Some(marker) => write!(f, "; {marker}"),
+            None => Ok(()),
+        }
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')
+}
+
+/// Whether `s` is a PEP 508 identifier, used for both names and extras.
+fn is_identifier(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && s.ends_with(|c: char| c.is_ascii_alphanumeric())
+        && s.chars().all(is_identifier_char)
+}
+
+/// Each variant has the requirement, or the part of it, which couldn't be parsed.
+#[derive(thiserror::Error, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RequirementParseError {
+    #[error("requirement does not start with a valid name")]
+    InvalidName(String),
+
+    #[error("requirement has an unclosed list of extras")]
+    UnclosedExtras(String),
+
+    #[error("invalid extra name")]
+    InvalidExtra(String),
+
+    #[error("requirement has `@` but no URL")]
+    MissingUrl(String),
+
+    #[error("invalid version specifier")]
+    InvalidSpecifier(String),
+
+    #[error("requirement has `;` but no marker")]
+    EmptyMarker(String),
+
+    #[error("unexpected text in requirement")]
+    UnexpectedText(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse() -> Result<(), RequirementParseError> {
+        let requirement = Requirement::from_str("Requests[socks, security] (>=2.8.1,<3)")?;
+        assert_eq!(requirement.name, "Requests");
+        assert_eq!(requirement.normalized_name(), "requests");
+        assert_eq!(requirement.extras, ["socks", "security"]);
+        assert_eq!(
+            requirement.specifiers,
+            SpecifierSet::from_str(">=2.8.1,<3").unwrap()
+        );
+        assert_eq!(requirement.url, None);
+        assert_eq!(requirement.marker, None);
+
+        let requirement = Requirement::from_str("PySocks!=1.5.7,>=1.5.6; extra == 'socks'")?;
+        assert_eq!(requirement.name, "PySocks");
+        assert_eq!(requirement.marker.as_deref(), Some("extra == 'socks'"));
+        assert_eq!(
+            Requirement::from_str("demo >=1.0 ;extra == 'test'")?.to_string(),
+            "demo>=1.0; extra == 'test'",
+        );
+
+        let requirement = Requirement::from_str(
+            "demo @ https://example.com/demo.whl;x ; python_version < '3.8'",
+        )?;
+        assert_eq!(
+            requirement.url.as_deref(),
+            Some("https://example.com/demo.whl;x")
+        );
+        assert_eq!(
+            requirement.marker.as_deref(),
+            Some("python_version < '3.8'")
+        );
+        assert!(requirement.specifiers.specifiers().is_empty());
+
+        assert_eq!(Requirement::from_str("six")?.to_string(), "six");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for (requirement, error) in [
+            ("", RequirementParseError::InvalidName(String::new())),
+            (
+                "-demo",
+                RequirementParseError::InvalidName("-demo".to_string()),
+            ),
+            (
+                "demo[a",
+                RequirementParseError::UnclosedExtras("demo[a".to_string()),
+            ),
+            (
+                "demo[-a]",
+                RequirementParseError::InvalidExtra("-a".to_string()),
+            ),
+            (
+                "demo @ ",
+                RequirementParseError::MissingUrl("demo @".to_string()),
+            ),
+            (
+                "demo (>=1",
+                RequirementParseError::InvalidSpecifier("(>=1".to_string()),
+            ),
+            (
+                "demo >=x",
+                RequirementParseError::InvalidSpecifier(">=x".to_string()),
+            ),
+            (
+                "demo ;",
+                RequirementParseError::EmptyMarker("demo ;".to_string()),
+            ),
+            (
+                "demo @ https://example.com/demo.whl extra",
+                RequirementParseError::UnexpectedText("extra".to_string()),
+            ),
+        ] {
+            assert_eq!(Requirement::from_str(requirement), Err(error));
+        }
+    }
+}
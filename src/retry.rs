@@ -0,0 +1,260 @@
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::thread;
+use std::time::Duration;
+
+/// How [`RetryReader`] retries reads which fail with a transient I/O error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RetryPolicy {
+    /// Retries per read or seek before the error is returned. Zero disables retrying.
+    pub max_retries: u32,
+    /// Delay before the first retry. Each following retry waits twice as long.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy which never retries.
+    pub fn none() -> Self {
+        Self::default().max_retries(0)
+    }
+
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(1 << attempt.min(31))
+            .min(self.max_backoff)
+    }
+}
+
+/// Wraps a `Read + Seek` reader, e.g. a [`RangeReader`](crate::RangeReader) over the network,
+/// retrying reads and seeks which fail with a transient error after a backoff.
+///
+/// Before a read is retried the inner reader is seeked back to where the failed read started,
+/// so a partially applied read can't corrupt the stream.
+///
+/// ```no_run
+/// # use std::fs::File;
+/// # use pep_427::{RetryPolicy, RetryReader, Wheel};
+/// let file = File::open("requests-2.29.0-py3-none-any.whl")?;
+/// let reader = RetryReader::new(file, RetryPolicy::default())?;
+/// let retry = reader.handle();
+/// let mut wheel = Wheel::open("requests-2.29.0-py3-none-any.whl", reader)?;
+/// wheel.metadata_headers()?;
+///
+/// retry.set_policy(RetryPolicy::default().max_retries(10));
+/// wheel.verify()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct RetryReader<R> {
+    inner: R,
+    position: u64,
+    policy: Arc<Mutex<RetryPolicy>>,
+}
+
+impl<R: Read + Seek> RetryReader<R> {
+    pub fn new(mut inner: R, policy: RetryPolicy) -> io::Result<Self> {
+        Ok(Self {
+            position: inner.stream_position()?,
+            inner,
+            policy: Arc::new(Mutex::new(policy)),
+        })
+    }
+
+    /// A handle for changing the policy after the reader has been handed to a [`Wheel`](crate::Wheel),
+    /// so that each operation can use its own policy.
+    pub fn handle(&self) -> RetryHandle {
+        RetryHandle {
+            policy: Arc::clone(&self.policy),
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn retry<T>(&mut self, mut op: impl FnMut(&mut Self) -> io::Result<T>) -> io::Result<T> {
+        let policy = *self.policy.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut attempt = 0;
+        loop {
+            match op(self) {
+                Err(e) if attempt < policy.max_retries && is_transient(&e) => {
+                    thread::sleep(policy.backoff(attempt));
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl<R: Read + Seek> Read for RetryReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let start = self.position;
+        let mut first_attempt = true;
+        let read = self.retry(|reader| {
+            if !std::mem::take(&mut first_attempt) {
+                reader.inner.seek(SeekFrom::Start(start))?;
+            }
+            reader.inner.read(buf)
+        })?;
+        self.position = start + read as u64;
+        Ok(read)
+    }
+}
+
+impl<R: Read + Seek> Seek for RetryReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        // Resolve relative seeks up front, so a retry can't apply the offset twice.
+        let pos = match pos {
+            SeekFrom::Current(offset) => match self.position.checked_add_signed(offset) {
+                Some(position) => SeekFrom::Start(position),
+                None => pos,
+            },
+            _ => pos,
+        };
+        self.position = self.retry(|reader| reader.inner.seek(pos))?;
+        Ok(self.position)
+    }
+}
+
+/// Changes the policy of the [`RetryReader`] it was created from, see [`RetryReader::handle`].
+#[derive(Clone, Debug)]
+pub struct RetryHandle {
+    policy: Arc<Mutex<RetryPolicy>>,
+}
+
+impl RetryHandle {
+    pub fn policy(&self) -> RetryPolicy {
+        *self.policy.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Applies `policy` to every subsequent read and seek.
+    pub fn set_policy(&self, policy: RetryPolicy) {
+        *self.policy.lock().unwrap_or_else(PoisonError::into_inner) = policy;
+    }
+}
+
+/// Errors which network-backed readers produce for failures that may not recur.
+fn is_transient(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::Interrupted
+            | io::ErrorKind::WouldBlock
+            | io::ErrorKind::TimedOut
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::BrokenPipe
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::io::Write;
+
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    use super::*;
+    use crate::Wheel;
+    use crate::WheelError;
+
+    use pretty_assertions::assert_eq;
+
+    /// Fails every `period`th read with a connection reset, after consuming part of the input.
+    struct FlakyReader {
+        inner: Cursor<Vec<u8>>,
+        reads: usize,
+        period: usize,
+    }
+
+    impl Read for FlakyReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.reads += 1;
+            if self.reads.is_multiple_of(self.period) {
+                let mut partial = [0; 1];
+                self.inner.read(&mut partial)?;
+                return Err(io::ErrorKind::ConnectionReset.into());
+            }
+            self.inner.read(buf)
+        }
+    }
+
+    impl Seek for FlakyReader {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    fn flaky_wheel(period: usize) -> FlakyReader {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .start_file("demo-1.0.dist-info/WHEEL", FileOptions::default())
+            .unwrap();
+        writer
+            .write_all(b"Wheel-Version: 1.0\nGenerator: demo\nRoot-Is-Purelib: true\n")
+            .unwrap();
+        FlakyReader {
+            inner: writer.finish().unwrap(),
+            reads: 0,
+            period,
+        }
+    }
+
+    #[test]
+    fn test_retry() -> Result<(), WheelError> {
+        let policy = RetryPolicy::default().initial_backoff(Duration::ZERO);
+        let reader = RetryReader::new(flaky_wheel(3), policy)?;
+        let mut wheel = Wheel::open("demo-1.0-py3-none-any.whl", reader)?;
+        assert_eq!(wheel.wheel_file()?.generator, "demo");
+        Ok(())
+    }
+
+    #[test]
+    fn test_retry_handle() -> Result<(), WheelError> {
+        let reader = RetryReader::new(flaky_wheel(3), RetryPolicy::none())?;
+        assert!(matches!(
+            Wheel::open("demo-1.0-py3-none-any.whl", reader),
+            Err(WheelError::ZipError(zip::result::ZipError::Io(e)))
+                if e.kind() == io::ErrorKind::ConnectionReset,
+        ));
+
+        let reader = RetryReader::new(flaky_wheel(3), RetryPolicy::none())?;
+        let handle = reader.handle();
+        handle.set_policy(RetryPolicy::default().initial_backoff(Duration::ZERO));
+        Wheel::open("demo-1.0-py3-none-any.whl", reader)?;
+        assert_eq!(handle.policy().max_retries, 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+use std::str::FromStr;
+
+use pep440_rs::Operator;
+use pep440_rs::Pep440Error;
+use pep440_rs::Version;
+use pep440_rs::VersionSpecifier;
+use pep440_rs::VersionSpecifiers;
+
+/// A set of PEP 440 version specifiers, e.g. the value of `Requires-Python`,
+/// with the set operations needed when combining constraints across wheels.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpecifierSet(VersionSpecifiers);
+
+impl SpecifierSet {
+    pub fn specifiers(&self) -> &VersionSpecifiers {
+        &self.0
+    }
+
+    pub fn contains(&self, version: &Version) -> bool {
+        self.0.contains(version)
+    }
+
+    /// The set of versions matched by both `self` and `other`.
+    pub fn intersection(&self, other: &SpecifierSet) -> SpecifierSet {
+        let mut specifiers: Vec<VersionSpecifier> = self.0.to_vec();
+        for specifier in other.0.iter() {
+            if !specifiers.contains(specifier) {
+                specifiers.push(specifier.clone());
+            }
+        }
+        SpecifierSet(specifiers.into_iter().collect())
+    }
+
+    /// Whether every version matched by `self` is also matched by `other`.
+    ///
+    /// This is conservative: it returns `true` only when each specifier in `other`
+    /// is implied by a single specifier in `self`, so it may return `false` for
+    /// subsets which can only be proven by combining specifiers.
+    pub fn is_subset_of(&self, other: &SpecifierSet) -> bool {
+        other
+            .0
+            .iter()
+            .all(|theirs| self.0.iter().any(|ours| implies(ours, theirs)))
+    }
+}
+
+/// Whether every version matched by `a` is matched by `b`.
+fn implies(a: &VersionSpecifier, b: &VersionSpecifier) -> bool {
+    use Operator::*;
+
+    if a == b {
+        return true;
+    }
+    let (v, w) = (a.version(), b.version());
+    match (a.operator(), b.operator()) {
+        (Equal, _) => b.contains(v),
+        (GreaterThanEqual, GreaterThanEqual) | (GreaterThan, GreaterThanEqual) => v >= w,
+        (GreaterThan, GreaterThan) => v >= w,
+        (GreaterThanEqual, GreaterThan) => v > w,
+        (LessThanEqual, LessThanEqual) | (LessThan, LessThanEqual) => v <= w,
+        (LessThan, LessThan) => v <= w,
+        (LessThanEqual, LessThan) => v < w,
+        _ => false,
+    }
+}
+
+impl FromStr for SpecifierSet {
+    type Err = Pep440Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(SpecifierSet(VersionSpecifiers::from_str(s)?))
+    }
+}
+
+impl From<VersionSpecifiers> for SpecifierSet {
+    fn from(specifiers: VersionSpecifiers) -> Self {
+        SpecifierSet(specifiers)
+    }
+}
+
+impl fmt::Display for SpecifierSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn set(s: &str) -> SpecifierSet {
+        SpecifierSet::from_str(s).unwrap()
+    }
+
+    fn version(s: &str) -> Version {
+        Version::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_contains() {
+        assert!(set(">=3.8, <4").contains(&version("3.11")));
+        assert!(!set(">=3.8, <4").contains(&version("3.7")));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let intersection = set(">=3.7").intersection(&set(">=3.7, <3.12"));
+        assert_eq!(intersection, set(">=3.7, <3.12"));
+        assert!(!intersection.contains(&version("3.12")));
+    }
+
+    #[test]
+    fn test_is_subset_of() {
+        assert!(set(">=3.8").is_subset_of(&set(">=3.7")));
+        assert!(set(">=3.8, <3.12").is_subset_of(&set("<4")));
+        assert!(set("==3.9").is_subset_of(&set(">=3.7, !=3.8")));
+        assert!(!set(">=3.7").is_subset_of(&set(">=3.8")));
+        assert!(!set(">=3.7").is_subset_of(&set("<4")));
+    }
+}
@@ -0,0 +1,230 @@
+//! Experimental: splitting a wheel into a smaller one with only some of its packages.
+
+use std::collections::BTreeSet;
+use std::io::Read;
+use std::io::Seek;
+use std::io::Write;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::normalize_distribution_name;
+use crate::repack::rewrite_metadata;
+use crate::Wheel;
+use crate::WheelError;
+use crate::WheelName;
+
+lazy_static! {
+    static ref EXTRA_MARKER_RE: Regex = Regex::new(r#"extra\s*==\s*['"]([^'"]+)['"]"#).unwrap();
+}
+
+/// What [`Wheel::split`] keeps from a wheel.
+///
+/// This is experimental, and the rules for which files are kept may change.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SplitOptions {
+    distribution: String,
+    packages: BTreeSet<String>,
+    extras: BTreeSet<String>,
+}
+
+impl SplitOptions {
+    /// `distribution` is the name of the new wheel, which replaces `Name` in `METADATA`.
+    pub fn new(distribution: &str) -> Self {
+        Self {
+            distribution: distribution.to_owned(),
+            ..Self::default()
+        }
+    }
+
+    /// Keeps a top-level package or module, e.g. `demo` for `demo/__init__.py`, `demo.py`,
+    /// and `demo.cpython-311-x86_64-linux-gnu.so`, along with its `demo.libs/` directory of
+    /// vendored shared libraries, as written by auditwheel and delvewheel.
+    pub fn package(mut self, package: &str) -> Self {
+        self.packages.insert(package.to_owned());
+        self
+    }
+
+    /// Keeps an extra. Extras which aren't kept are removed from `Provides-Extra`, along with
+    /// the `Requires-Dist` entries which are only needed by them.
+    pub fn extra(mut self, extra: &str) -> Self {
+        self.extras.insert(normalize_distribution_name(extra));
+        self
+    }
+
+    fn selects(&self, path: &str) -> bool {
+        let top_level = match path.split_once('/') {
+            Some((dir, _)) => dir.strip_suffix(".libs").unwrap_or(dir),
+            None => path.split('.').next().unwrap_or(path),
+        };
+        self.packages.contains(top_level)
+    }
+
+    fn selects_requirement(&self, requirement: &str) -> bool {
+        let mut extras = EXTRA_MARKER_RE
+            .captures_iter(requirement)
+            .map(|captures| normalize_distribution_name(&captures[1]))
+            .peekable();
+        extras.peek().is_none() || extras.any(|extra| self.extras.contains(&extra))
+    }
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Writes a new wheel to `writer` with only the packages and extras `options` selects,
+    /// and returns its name. This is experimental.
+    ///
+    /// Files at the root of the archive and in `.data/purelib/` or `.data/platlib/` are kept
+    /// if they're in a selected package. Every other `.data/` file is dropped. `.dist-info`
+    /// files are copied into the new wheel's `.dist-info` directory, except that `METADATA`,
+    /// `top_level.txt`, and `entry_points.txt` are rewritten for the selection, `RECORD` is
+    /// rebuilt with `sha256` digests, and `RECORD` signatures are dropped.
+    pub fn split<W: Write + Seek>(
+        &mut self,
+        options: &SplitOptions,
+        writer: W,
+    ) -> Result<WheelName, WheelError> {
+        let name = self.name.clone().with_distribution(&options.distribution)?;
+        let dist_info = format!("{}-{}.dist-info", name.escaped_distribution(), name.version);
+        let old_dist_info = format!("{}/", self.dist_info);
+        let old_data = self.data_prefix();
+        let data = format!("{}-{}.data/", name.escaped_distribution(), name.version);
+
+        let mut paths = Vec::new();
+        for path in self.file_names().filter(|path| !path.ends_with('/')) {
+            if let Some(file) = path.strip_prefix(&old_dist_info) {
+                if !matches!(file, "RECORD" | "RECORD.jws" | "RECORD.p7s") {
+                    paths.push((path.to_owned(), format!("{dist_info}/{file}")));
+                }
+            } else if let Some(file) = path.strip_prefix(&old_data) {
+                if let Some(("purelib" | "platlib", module_path)) = file.split_once('/') {
+                    if options.selects(module_path) {
+                        paths.push((path.to_owned(), format!("{data}{file}")));
+                    }
+                }
+            } else if options.selects(path) {
+                paths.push((path.to_owned(), path.to_owned()));
+            }
+        }
+
+        self.write_repacked(paths, &dist_info, writer, |filename, contents| {
+            Ok(match filename {
+                "METADATA" => split_metadata(&contents, options)?.into_bytes(),
+                "top_level.txt" => options
+                    .packages
+                    .iter()
+                    .map(|package| format!("{package}\n"))
+                    .collect::<String>()
+                    .into_bytes(),
+                "entry_points.txt" => split_entry_points(&contents, options).into_bytes(),
+                _ => contents,
+            })
+        })?;
+        Ok(name)
+    }
+}
+
+/// Rewrites `Name`, `Provides-Extra`, and `Requires-Dist` in `METADATA` for the selection.
+fn split_metadata(contents: &[u8], options: &SplitOptions) -> Result<String, WheelError> {
+    rewrite_metadata(contents, |key, value| match key {
+        "name" => Some(options.distribution.clone()),
+        "provides-extra" if !options.extras.contains(&normalize_distribution_name(value)) => None,
+        "requires-dist" if !options.selects_requirement(value) => None,
+        _ => Some(value.to_owned()),
+    })
+}
+
+/// Drops the entry points whose module isn't in a selected package.
+fn split_entry_points(contents: &[u8], options: &SplitOptions) -> String {
+    String::from_utf8_lossy(contents)
+        .lines()
+        .filter(|line| {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(['#', ';', '[']) {
+                return true;
+            }
+            let Some((_, value)) = line.split_once('=') else {
+                return true;
+            };
+            let module = value.split(':').next().unwrap_or(value).trim();
+            options.selects(module.split('.').next().unwrap_or(module))
+        })
+        .map(|line| format!("{line}\n"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_split() -> Result<(), WheelError> {
+        let mut wheel = WheelBuilder::new("monolith-1.0-py3-none-any.whl")?
+            .file("alpha/__init__.py", "alpha")
+            .file("alpha.libs/helper.so", "helper")
+            .file("beta/__init__.py", "beta")
+            .file("beta.py", "beta")
+            .file("monolith-1.0.data/purelib/alpha/extra.py", "extra")
+            .file("monolith-1.0.data/scripts/tool", "#!python")
+            .file("monolith-1.0.dist-info/top_level.txt", "alpha\nbeta\n")
+            .file(
+                "monolith-1.0.dist-info/entry_points.txt",
+                "[console_scripts]\na = alpha.cli:main\nb = beta:main\n",
+            )
+            .metadata("Provides-Extra", "fast")
+            .metadata("Provides-Extra", "test")
+            .metadata("Requires-Dist", "six")
+            .metadata("Requires-Dist", "cython; extra == \"fast\"")
+            .metadata("Requires-Dist", "pytest; extra == 'test'")
+            .open()?;
+
+        let options = SplitOptions::new("alpha").package("alpha").extra("fast");
+        let mut archive = Cursor::new(Vec::new());
+        let name = wheel.split(&options, &mut archive)?;
+        assert_eq!(name.to_string(), "alpha-1.0-py3-none-any.whl");
+
+        let mut split = Wheel::open(&name.to_string(), archive)?;
+        split.verify()?;
+        assert_eq!(
+            split
+                .classify_entries()
+                .into_iter()
+                .map(|(path, _)| path)
+                .collect::<Vec<_>>(),
+            [
+                "alpha/__init__.py",
+                "alpha.libs/helper.so",
+                "alpha-1.0.data/purelib/alpha/extra.py",
+                "alpha-1.0.dist-info/top_level.txt",
+                "alpha-1.0.dist-info/entry_points.txt",
+                "alpha-1.0.dist-info/METADATA",
+                "alpha-1.0.dist-info/WHEEL",
+                "alpha-1.0.dist-info/RECORD",
+            ],
+        );
+        let headers = split.metadata_headers()?;
+        assert_eq!(headers.get("Name"), Some("alpha"));
+        assert_eq!(
+            headers.get_all("Provides-Extra").collect::<Vec<_>>(),
+            ["fast"]
+        );
+        assert_eq!(
+            headers.get_all("Requires-Dist").collect::<Vec<_>>(),
+            ["six", "cython; extra == \"fast\""],
+        );
+        assert_eq!(
+            split
+                .entry_points()?
+                .entry_points
+                .iter()
+                .map(|entry_point| entry_point.name.as_str())
+                .collect::<Vec<_>>(),
+            ["a"],
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,386 @@
+//! Reading wheels from streams which can't seek.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::io::Read;
+use std::str::FromStr;
+
+use crate::copy::check_sha256_digests;
+use crate::copy::Tee;
+use crate::hash::encode_hex;
+use crate::hash::HASH_BUFFER_SIZE;
+use crate::layout::matching_dist_info;
+use crate::metadata_file::decode_metadata;
+use crate::EntryPoints;
+use crate::HashAlgorithm;
+use crate::Limits;
+use crate::MetadataFile;
+use crate::MetadataHeaders;
+use crate::NameValidation;
+use crate::OpenOptions;
+use crate::RecordFile;
+use crate::VerifyError;
+use crate::Warning;
+use crate::WheelError;
+use crate::WheelFile;
+use crate::WheelName;
+
+/// A wheel read in a single pass from a stream which can't seek, e.g. stdin or a network
+/// response, for inspecting wheels without buffering them to disk first.
+///
+/// This reads archives differently from [`Wheel`](crate::Wheel), so it's meant for inspection
+/// rather than deciding whether a wheel is safe to install:
+///
+/// - Entries are read from their local headers, in the order they're stored. The central
+///   directory, which installers use, is never read, so an archive whose central directory
+///   disagrees with its local headers can look different here than when it's installed.
+/// - In strict mode, data before the first entry and zip comments aren't detected.
+/// - Entries with data descriptors instead of sizes in their local headers aren't supported.
+///
+/// Files in `.dist-info` directories are kept in memory, within the configured [`Limits`].
+/// Every other file is hashed with `sha256` as it streams past and then discarded, so
+/// [`StreamedWheel::verify`] only supports `sha256` digests in `RECORD`.
+pub struct StreamedWheel {
+    name: WheelName,
+    strict: bool,
+    limits: Limits,
+    normalize_unicode: bool,
+    warnings: Vec<Warning>,
+    /// The `.dist-info` directory, see [`StreamedWheel::dist_info_dir`].
+    dist_info: String,
+    /// The path of every file, in the order they were read.
+    paths: Vec<String>,
+    /// The `sha256` digest and size of every file.
+    digests: BTreeMap<String, (Vec<u8>, u64)>,
+    /// The contents of every file in a `.dist-info` directory.
+    dist_info_files: BTreeMap<String, Vec<u8>>,
+}
+
+impl StreamedWheel {
+    /// Reads a wheel from `reader` to its end, see [`OpenOptions::open_stream`] to set options.
+    pub fn open<R: Read>(name: &str, reader: R) -> Result<StreamedWheel, WheelError> {
+        Self::open_with_options(name, reader, &OpenOptions::default())
+    }
+
+    pub(crate) fn open_with_options<R: Read>(
+        name: &str,
+        mut reader: R,
+        options: &OpenOptions,
+    ) -> Result<StreamedWheel, WheelError> {
+        let name_validation = if options.strict {
+            NameValidation::Strict
+        } else {
+            NameValidation::Legacy
+        };
+        let name = WheelName::parse(name, name_validation)?;
+        let mut wheel = StreamedWheel {
+            dist_info: format!("{}-{}.dist-info", name.escaped_distribution(), name.version),
+            name,
+            strict: options.strict,
+            limits: options.limits,
+            normalize_unicode: options.normalize_unicode,
+            warnings: Vec::new(),
+            paths: Vec::new(),
+            digests: BTreeMap::new(),
+            dist_info_files: BTreeMap::new(),
+        };
+
+        match &options.expected_hash {
+            Some((algorithm, expected_hex)) => {
+                let mut tee = Tee {
+                    reader,
+                    writer: io::sink(),
+                    hasher: algorithm.hasher(),
+                };
+                wheel.read_entries(&mut tee)?;
+                // The central directory isn't read, but is part of the hash.
+                io::copy(&mut tee, &mut io::sink())?;
+                let actual_hex = encode_hex(&tee.hasher.finalize());
+                if actual_hex != *expected_hex {
+                    return Err(VerifyError::HashMismatch(expected_hex.clone(), actual_hex).into());
+                }
+            }
+            None => wheel.read_entries(&mut reader)?,
+        }
+
+        let expected = format!("{}/", wheel.dist_info);
+        if !wheel.strict && !wheel.paths.iter().any(|path| path.starts_with(&expected)) {
+            if let Some(actual) =
+                matching_dist_info(&wheel.name, wheel.paths.iter().map(String::as_str))
+            {
+                wheel.warnings.push(Warning::NameMismatch(
+                    wheel.dist_info.clone(),
+                    actual.clone(),
+                ));
+                wheel.dist_info = actual;
+            }
+        }
+        Ok(wheel)
+    }
+
+    /// Reads every entry up to the central directory.
+    fn read_entries<R: Read>(&mut self, reader: &mut R) -> Result<(), WheelError> {
+        let mut buffer = vec![0; HASH_BUFFER_SIZE];
+        while let Some(mut entry) = zip::read::read_zipfile_from_stream(reader)? {
+            if entry.is_dir() {
+                continue;
+            }
+            let path = entry.name().to_owned();
+            let dist_info_filename = path
+                .split_once('/')
+                .filter(|(dir, _)| dir.ends_with(".dist-info"))
+                .map(|(_, filename)| filename);
+            self.limits
+                .check_size(&path, dist_info_filename, entry.size())?;
+
+            let (digest, size) = match dist_info_filename {
+                Some(filename) => {
+                    let mut contents = Vec::new();
+                    (&mut entry)
+                        .take(
+                            self.limits
+                                .max_size(Some(filename))
+                                .map_or(u64::MAX, |limit| limit + 1),
+                        )
+                        .read_to_end(&mut contents)?;
+                    let digest = HashAlgorithm::Sha256.digest(&contents);
+                    let size = contents.len() as u64;
+                    self.dist_info_files.insert(path.clone(), contents);
+                    (digest, size)
+                }
+                None => HashAlgorithm::Sha256
+                    .hash_reader(&mut self.limits.take_entry(&mut entry), &mut buffer)?,
+            };
+            self.limits.check_size(&path, dist_info_filename, size)?;
+            self.digests.insert(path.clone(), (digest, size));
+            self.paths.push(path);
+        }
+        Ok(())
+    }
+
+    /// Checks every file listed in `RECORD` against its recorded digest and size, like
+    /// [`Wheel::verify`](crate::Wheel::verify). `RECORD` digests other than `sha256` fail
+    /// with [`VerifyError::UnsupportedHashAlgorithm`].
+    pub fn verify(&mut self) -> Result<(), WheelError> {
+        let record_file = self.record_file()?;
+        check_sha256_digests(&record_file, &self.digests)
+    }
+
+    /// The path of every file in the archive, in the order they were stored.
+    pub fn file_names(&self) -> impl Iterator<Item = &str> {
+        self.paths.iter().map(String::as_str)
+    }
+
+    /// The uncompressed size of the file at `path`, if there is one.
+    pub fn file_size(&self, path: &str) -> Option<u64> {
+        self.digests.get(path).map(|(_, size)| *size)
+    }
+
+    pub fn limits(&self) -> &Limits {
+        &self.limits
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Spec deviations tolerated so far because the wheel wasn't opened in strict mode, each
+    /// recorded once.
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    /// Records the `warnings` which haven't been recorded already, see
+    /// [`Wheel::warnings`](crate::Wheel::warnings).
+    fn record_warnings(&mut self, warnings: impl IntoIterator<Item = Warning>) {
+        for warning in warnings {
+            if !self.warnings.contains(&warning) {
+                self.warnings.push(warning);
+            }
+        }
+    }
+
+    /// Reads `entry_points.txt`, which is optional: a wheel without one has no entry points.
+    pub fn entry_points(&mut self) -> Result<EntryPoints, WheelError> {
+        match self.dist_info_contents("entry_points.txt") {
+            Ok(contents) => Ok(EntryPoints::from_str(&contents)?),
+            Err(WheelError::ZipError(zip::result::ZipError::FileNotFound)) => {
+                Ok(EntryPoints::default())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn metadata_file(&mut self) -> Result<MetadataFile, WheelError> {
+        let (mut metadata_file, warnings) =
+            MetadataFile::from_str_with_warnings(&self.metadata_contents()?, self.strict)?;
+        self.record_warnings(warnings);
+        if self.normalize_unicode {
+            metadata_file.normalize_unicode();
+        }
+        let warning = metadata_file.check_summary(self.strict)?;
+        self.record_warnings(warning);
+        self.record_warnings(metadata_file.check_metadata_version());
+        #[cfg(feature = "url")]
+        self.record_warnings(metadata_file.check_urls());
+        Ok(metadata_file)
+    }
+
+    /// Reads `METADATA` as ordered raw headers, without interpreting any fields.
+    pub fn metadata_headers(&mut self) -> Result<MetadataHeaders, WheelError> {
+        Ok(MetadataHeaders::from_str(&self.metadata_contents()?)?)
+    }
+
+    /// Reads `RECORD`, see [`Wheel::record_file`](crate::Wheel::record_file).
+    pub fn record_file(&mut self) -> Result<RecordFile, WheelError> {
+        let (record_file, warnings) = RecordFile::from_str_with_warnings(
+            &self.dist_info_contents("RECORD")?,
+            self.limits.max_record_rows,
+            self.strict,
+        )?;
+        self.record_warnings(warnings);
+        Ok(record_file)
+    }
+
+    pub fn wheel_file(&mut self) -> Result<WheelFile, WheelError> {
+        Ok(WheelFile::from_str(&self.dist_info_contents("WHEEL")?)?)
+    }
+
+    pub fn wheel_name(&self) -> &WheelName {
+        &self.name
+    }
+
+    /// The `.dist-info` directory which files such as `METADATA` are read from, see
+    /// [`Wheel::dist_info_dir`](crate::Wheel::dist_info_dir).
+    pub fn dist_info_dir(&self) -> &str {
+        &self.dist_info
+    }
+
+    /// Decodes `METADATA` like [`Wheel::metadata_file`](crate::Wheel::metadata_file) does,
+    /// recording a warning if it isn't plain UTF-8.
+    fn metadata_contents(&mut self) -> Result<String, WheelError> {
+        let (contents, warning) = decode_metadata(self.dist_info_bytes("METADATA")?, self.strict)?;
+        let contents = contents.into_owned();
+        self.record_warnings(warning);
+        Ok(contents)
+    }
+
+    fn dist_info_contents(&self, filename: &str) -> Result<String, WheelError> {
+        String::from_utf8(self.dist_info_bytes(filename)?.to_vec())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e).into())
+    }
+
+    fn dist_info_bytes(&self, filename: &str) -> Result<&[u8], WheelError> {
+        let path = format!("{}/{}", self.dist_info, filename);
+        match self.dist_info_files.get(&path) {
+            Some(contents) => Ok(contents),
+            None => Err(zip::result::ZipError::FileNotFound.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Corruption;
+    use crate::LimitError;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_open_stream() -> Result<(), WheelError> {
+        let builder = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .file("demo/__init__.py", "hello world")
+            .metadata("Summary", "A demo.");
+        let bytes = builder.build()?.into_inner();
+
+        let mut wheel = StreamedWheel::open("demo-1.0-py3-none-any.whl", bytes.as_slice())?;
+        wheel.verify()?;
+        assert_eq!(wheel.metadata_file()?.summary, "A demo.");
+        assert_eq!(wheel.wheel_file()?.wheel_version, "1.0");
+        assert_eq!(wheel.file_size("demo/__init__.py"), Some(11));
+        assert_eq!(
+            wheel.file_names().collect::<Vec<_>>(),
+            [
+                "demo/__init__.py",
+                "demo-1.0.dist-info/METADATA",
+                "demo-1.0.dist-info/WHEEL",
+                "demo-1.0.dist-info/RECORD",
+            ],
+        );
+
+        let corrupted = builder
+            .corrupt(Corruption::FlipByte {
+                path: "demo/__init__.py".to_string(),
+                offset: 0,
+            })
+            .build()?
+            .into_inner();
+        let mut wheel = StreamedWheel::open("demo-1.0-py3-none-any.whl", corrupted.as_slice())?;
+        assert!(matches!(
+            wheel.verify(),
+            Err(WheelError::VerifyError(VerifyError::DigestMismatch(path)))
+                if path == "demo/__init__.py",
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_warnings_once() -> Result<(), WheelError> {
+        use std::io::Cursor;
+        use std::io::Write;
+
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file("demo-1.0.dist-info/RECORD", Default::default())?;
+        writer.write_all(b"# generated by demo\ndemo-1.0.dist-info/RECORD,,\n")?;
+        writer.start_file("demo-1.0.dist-info/METADATA", Default::default())?;
+        writer.write_all(b"Metadata-Version: 2.1\nName: demo\nVersion: 1.0\nDynamic: Summary\n")?;
+        let bytes = writer.finish()?.into_inner();
+
+        let mut wheel = StreamedWheel::open("demo-1.0-py3-none-any.whl", bytes.as_slice())?;
+        for _ in 0..2 {
+            wheel.record_file()?;
+            wheel.metadata_file()?;
+        }
+        assert_eq!(
+            wheel.warnings(),
+            [
+                Warning::IgnoredRecordLine(1, "# generated by demo".to_string()),
+                Warning::FieldRequiresMetadataVersion(
+                    "Dynamic",
+                    "2.2".to_string(),
+                    "2.1".to_string()
+                ),
+            ],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_stream_options() -> Result<(), WheelError> {
+        let bytes = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .file("demo/__init__.py", "hello world")
+            .build()?
+            .into_inner();
+        let hex_digest = encode_hex(&HashAlgorithm::Sha256.digest(&bytes));
+
+        OpenOptions::new()
+            .expected_hash(HashAlgorithm::Sha256, &hex_digest)
+            .open_stream("demo-1.0-py3-none-any.whl", bytes.as_slice())?;
+        assert!(matches!(
+            OpenOptions::new()
+                .expected_hash(HashAlgorithm::Sha256, "00")
+                .open_stream("demo-1.0-py3-none-any.whl", bytes.as_slice()),
+            Err(WheelError::VerifyError(VerifyError::HashMismatch(_, _))),
+        ));
+        assert!(matches!(
+            OpenOptions::new()
+                .limits(Limits::default().max_entry_size(10))
+                .open_stream("demo-1.0-py3-none-any.whl", bytes.as_slice()),
+            Err(WheelError::LimitError(LimitError::EntryTooLarge(path, 10)))
+                if path == "demo/__init__.py",
+        ));
+        Ok(())
+    }
+}
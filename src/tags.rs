@@ -0,0 +1,766 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::wheel_name::validate_tag;
+use crate::WheelFile;
+use crate::WheelFileParseError;
+use crate::WheelName;
+use crate::WheelNameParseError;
+
+/// A single compatibility tag, e.g. `cp311-cp311-manylinux_2_17_x86_64`.
+///
+/// Like `packaging.tags.Tag`, every component is lowercased, so tags compare equal
+/// regardless of how the producer spelled them.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub struct Tag {
+    pub interpreter: String,
+    pub abi: String,
+    pub platform: String,
+}
+
+impl Tag {
+    pub fn new(interpreter: &str, abi: &str, platform: &str) -> Self {
+        Self {
+            interpreter: interpreter.to_ascii_lowercase(),
+            abi: abi.to_ascii_lowercase(),
+            platform: platform.to_ascii_lowercase(),
+        }
+    }
+
+    /// Expands a compressed tag set like `py2.py3-none-any` into its individual tags,
+    /// like `packaging.tags.parse_tag`.
+    pub fn parse_compressed(tag: &str) -> Result<BTreeSet<Tag>, WheelNameParseError> {
+        let mut parts = tag.split('-');
+        let (Some(python_tag), Some(abi_tag), Some(platform_tag), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(WheelNameParseError::InvalidTag(tag.to_owned()));
+        };
+        for tag in [python_tag, abi_tag, platform_tag] {
+            validate_tag(tag)?;
+        }
+        Ok(expand(python_tag, abi_tag, platform_tag))
+    }
+}
+
+/// Formats the tag as `str(packaging.tags.Tag)` does.
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}-{}", self.interpreter, self.abi, self.platform)
+    }
+}
+
+impl WheelName {
+    /// The individual tags this wheel's compressed tag set expands to.
+    pub fn tags(&self) -> BTreeSet<Tag> {
+        expand(&self.python_tag, &self.abi_tag, &self.platform_tag)
+    }
+
+    /// The tags as strings, identical to the `str()` of each tag in the frozenset
+    /// `packaging.utils.parse_wheel_filename` returns.
+    pub fn to_packaging_tags(&self) -> BTreeSet<String> {
+        self.tags().iter().map(Tag::to_string).collect()
+    }
+}
+
+impl WheelFile {
+    /// The union of every `Tag` line, expanded and formatted like [`WheelName::to_packaging_tags`].
+    pub fn to_packaging_tags(&self) -> Result<BTreeSet<String>, WheelFileParseError> {
+        let mut tags = BTreeSet::new();
+        for tag in &self.tags {
+            let expanded = Tag::parse_compressed(tag)
+                .map_err(|_| WheelFileParseError::InvalidFieldValue("tags", tag.clone()))?;
+            tags.extend(expanded.iter().map(Tag::to_string));
+        }
+        Ok(tags)
+    }
+}
+
+/// A Python implementation, as identified in interpreter and ABI tags.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Implementation {
+    CPython,
+    /// PyPy, and the version of PyPy itself (not the Python version it implements), e.g. `(7, 3)`.
+    PyPy {
+        version: (u8, u8),
+    },
+    /// GraalPy, and the version of GraalPy itself, e.g. `(24, 2)`.
+    GraalPy {
+        version: (u8, u8),
+    },
+    /// Any other implementation, by its interpreter tag prefix, e.g. `ip` for IronPython.
+    Other(String),
+}
+
+/// The platform a Python interpreter runs on, which determines its platform tags.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Platform {
+    /// Linux on `arch`, e.g. `x86_64` or `aarch64`.
+    Linux { arch: String, libc: Libc },
+    /// macOS `version` on `arch` (`x86_64` or `arm64`).
+    MacOS { arch: String, version: (u16, u16) },
+    /// Windows on `arch`: `x86`, `amd64`, or `arm64`.
+    Windows { arch: String },
+    /// Android at `api_level` on `abi`: `arm64_v8a`, `armeabi_v7a`, `x86_64`, or `x86`.
+    Android { api_level: u16, abi: String },
+    /// iOS `version` on `multiarch`, e.g. `arm64_iphoneos` or `arm64_iphonesimulator`.
+    Ios {
+        version: (u16, u16),
+        multiarch: String,
+    },
+    /// A single platform tag, used as-is.
+    Other(String),
+}
+
+/// The C library a Linux interpreter is linked against, which decides between
+/// `manylinux` and `musllinux` wheels.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Libc {
+    /// glibc `(major, minor)`.
+    Glibc(u16, u16),
+    /// musl `(major, minor)`.
+    Musl(u16, u16),
+    /// Neither is known to be present. Only `linux_*` wheels are supported.
+    Unknown,
+}
+
+#[cfg(feature = "detect-libc")]
+impl Libc {
+    /// Detects the C library of the running system from the output of `ldd --version`.
+    pub fn detect() -> Libc {
+        let Ok(output) = std::process::Command::new("ldd").arg("--version").output() else {
+            return Libc::Unknown;
+        };
+        // musl's ldd prints its version to stderr and exits with a failure.
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        parse_ldd_version(&stdout)
+            .or_else(|| parse_ldd_version(&stderr))
+            .unwrap_or(Libc::Unknown)
+    }
+}
+
+/// Parses `ldd --version` output, e.g. `ldd (GNU libc) 2.35` or `musl libc (x86_64)\nVersion 1.2.4`.
+#[cfg(feature = "detect-libc")]
+fn parse_ldd_version(output: &str) -> Option<Libc> {
+    let parse_version = |version: &str| -> Option<(u16, u16)> {
+        let mut parts = version.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        Some((major, minor))
+    };
+    let first_line = output.lines().next()?;
+    if first_line.starts_with("musl libc") {
+        let version = output
+            .lines()
+            .find_map(|line| line.strip_prefix("Version "))?;
+        let (major, minor) = parse_version(version)?;
+        return Some(Libc::Musl(major, minor));
+    }
+    if first_line.contains("GNU libc") || first_line.contains("GLIBC") {
+        let (major, minor) = parse_version(first_line.rsplit(' ').next()?)?;
+        return Some(Libc::Glibc(major, minor));
+    }
+    None
+}
+
+impl Platform {
+    /// The platform tags this platform supports, most specific first.
+    pub fn tags(&self) -> Vec<String> {
+        match self {
+            Platform::Linux { arch, libc } => linux_platforms(arch, *libc),
+            Platform::MacOS { arch, version } => mac_platforms(arch, *version),
+            Platform::Windows { arch } if arch == "x86" => vec!["win32".to_owned()],
+            Platform::Windows { arch } => vec![format!("win_{arch}")],
+            Platform::Android { api_level, abi } => android_platforms(*api_level, abi),
+            Platform::Ios { version, multiarch } => ios_platforms(*version, multiarch),
+            Platform::Other(tag) => vec![tag.to_ascii_lowercase()],
+        }
+    }
+}
+
+/// A Python interpreter and the platform it runs on, for which wheels are being selected.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Environment {
+    /// The `(major, minor)` version of the language the interpreter implements.
+    pub python_version: (u8, u8),
+    pub implementation: Implementation,
+    pub platform: Platform,
+}
+
+impl Environment {
+    pub fn new(
+        python_version: (u8, u8),
+        implementation: Implementation,
+        platform: Platform,
+    ) -> Self {
+        Self {
+            python_version,
+            implementation,
+            platform,
+        }
+    }
+
+    /// The tags this environment supports, in priority order, see [`compatible_tags`].
+    pub fn compatible_tags(&self) -> Vec<Tag> {
+        compatible_tags(self.python_version, &self.implementation, &self.platform)
+    }
+}
+
+/// The tags an interpreter supports, in priority order, like `packaging.tags.sys_tags`.
+///
+/// `python_version` is the `(major, minor)` version of the language the interpreter implements.
+/// Debug and free-threaded CPython builds, which use different ABI tags, aren't covered;
+/// see [`AbiTag::supports`](crate::AbiTag::supports) for their ABI compatibility.
+pub fn compatible_tags(
+    python_version: (u8, u8),
+    implementation: &Implementation,
+    platform: &Platform,
+) -> Vec<Tag> {
+    let (major, minor) = python_version;
+    let platforms = platform.tags();
+    let mut tags = Vec::new();
+    let push_all = |tags: &mut Vec<Tag>, interpreter: &str, abi: &str| {
+        for platform in &platforms {
+            tags.push(Tag::new(interpreter, abi, platform));
+        }
+    };
+
+    let interpreter = match implementation {
+        Implementation::CPython => {
+            // `cpython_tags`: the version-specific ABI, then the stable ABI, then no ABI,
+            // then the stable ABI of every older minor version.
+            let interpreter = format!("cp{major}{minor}");
+            let abi = if python_version < (3, 8) {
+                format!("{interpreter}m")
+            } else {
+                interpreter.clone()
+            };
+            push_all(&mut tags, &interpreter, &abi);
+            let abi3 = python_version >= (3, 2);
+            if abi3 {
+                push_all(&mut tags, &interpreter, "abi3");
+            }
+            push_all(&mut tags, &interpreter, "none");
+            if abi3 {
+                for older_minor in (2..minor).rev() {
+                    push_all(&mut tags, &format!("cp{major}{older_minor}"), "abi3");
+                }
+            }
+            Some(interpreter)
+        }
+        Implementation::PyPy { version } => {
+            // `generic_tags`, with the ABI PyPy derives from its extension suffix.
+            let interpreter = format!("pp{major}{minor}");
+            let abi = format!("pypy{major}{minor}_pp{}{}", version.0, version.1);
+            push_all(&mut tags, &interpreter, &abi);
+            push_all(&mut tags, &interpreter, "none");
+            Some(format!("pp{major}"))
+        }
+        Implementation::GraalPy { version } => {
+            // `generic_tags`: GraalPy's extension suffix, e.g. `.graalpy242-311-native-x86_64-linux.so`,
+            // names its own version, the Python version, and the native ABI.
+            let interpreter = format!("graalpy{major}{minor}");
+            let abi = format!("graalpy{}{}_{major}{minor}_native", version.0, version.1);
+            push_all(&mut tags, &interpreter, &abi);
+            push_all(&mut tags, &interpreter, "none");
+            None
+        }
+        Implementation::Other(name) => {
+            push_all(&mut tags, &format!("{name}{major}{minor}"), "none");
+            None
+        }
+    };
+
+    // `compatible_tags`: pure-Python tags for this and older versions.
+    let py_versions = py_interpreter_range(major, minor);
+    for py_version in &py_versions {
+        push_all(&mut tags, py_version, "none");
+    }
+    if let Some(interpreter) = interpreter {
+        tags.push(Tag::new(&interpreter, "none", "any"));
+    }
+    for py_version in &py_versions {
+        tags.push(Tag::new(py_version, "none", "any"));
+    }
+    tags
+}
+
+impl WheelName {
+    /// The position in `supported` of the best tag this wheel matches, where `supported`
+    /// is in priority order, e.g. from [`compatible_tags`]. `None` if the wheel is incompatible.
+    pub fn compatibility(&self, supported: &[Tag]) -> Option<usize> {
+        let tags = self.tags();
+        supported.iter().position(|tag| tags.contains(tag))
+    }
+
+    /// For a wheel built against CPython's stable ABI, e.g. `cp38-abi3-manylinux_2_17_x86_64`,
+    /// the oldest `(major, minor)` CPython version it supports, which is the lowest `cpXY`
+    /// interpreter tag. `None` if the wheel isn't an `abi3` wheel.
+    pub fn abi3_minimum_python(&self) -> Option<(u8, u8)> {
+        self.tags()
+            .iter()
+            .filter(|tag| tag.abi == "abi3")
+            .filter_map(|tag| parse_cpython_version(&tag.interpreter))
+            .min()
+    }
+
+    /// Whether CPython `version` can use this wheel, judged by its interpreter and ABI tags
+    /// alone, with the same rules as [`compatible_tags`]. In particular, `abi3` wheels support
+    /// every later version with the same major version, not only the one they name.
+    pub fn supports_python_abi(&self, version: (u8, u8)) -> bool {
+        let supported = compatible_tags(
+            version,
+            &Implementation::CPython,
+            &Platform::Other(String::new()),
+        )
+        .into_iter()
+        .map(|tag| (tag.interpreter, tag.abi))
+        .collect::<BTreeSet<_>>();
+        self.tags()
+            .into_iter()
+            .any(|tag| supported.contains(&(tag.interpreter, tag.abi)))
+    }
+}
+
+/// Parses a CPython interpreter tag like `cp38` or `cp312` into its `(major, minor)` version.
+fn parse_cpython_version(interpreter: &str) -> Option<(u8, u8)> {
+    let digits = interpreter.strip_prefix("cp")?;
+    if digits.len() < 2 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (major, minor) = digits.split_at(1);
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+/// `py3X`, `py3`, then every older `py3Y`, like `packaging.tags._py_interpreter_range`.
+fn py_interpreter_range(major: u8, minor: u8) -> Vec<String> {
+    let mut versions = vec![format!("py{major}{minor}"), format!("py{major}")];
+    versions.extend((0..minor).rev().map(|minor| format!("py{major}{minor}")));
+    versions
+}
+
+/// The newest minor version of each glibc major version before the current one.
+/// glibc 2 is the only major version so far.
+const LAST_GLIBC_MINOR: u16 = 50;
+
+/// Legacy manylinux tags, and the glibc version each one is an alias for.
+const LEGACY_MANYLINUX: &[((u16, u16), &str)] = &[
+    ((2, 17), "manylinux2014"),
+    ((2, 12), "manylinux2010"),
+    ((2, 5), "manylinux1"),
+];
+
+fn linux_platforms(arch: &str, libc: Libc) -> Vec<String> {
+    let archs = match arch {
+        "armv8l" => vec!["armv8l", "armv7l"],
+        _ => vec![arch],
+    };
+    let mut platforms = Vec::new();
+    match libc {
+        Libc::Glibc(major, minor) => platforms.extend(manylinux_platforms(&archs, (major, minor))),
+        Libc::Musl(major, minor) => {
+            for arch in &archs {
+                for minor in (0..=minor).rev() {
+                    platforms.push(format!("musllinux_{major}_{minor}_{arch}"));
+                }
+            }
+        }
+        Libc::Unknown => {}
+    }
+    platforms.extend(archs.iter().map(|arch| format!("linux_{arch}")));
+    platforms
+}
+
+/// Every `manylinux` tag from `glibc` down to the oldest glibc the architecture
+/// has a manylinux policy for, like `packaging._manylinux.platform_tags`.
+fn manylinux_platforms(archs: &[&str], glibc: (u16, u16)) -> Vec<String> {
+    let too_old = if archs.iter().any(|arch| matches!(*arch, "x86_64" | "i686")) {
+        (2, 4)
+    } else {
+        (2, 16)
+    };
+    let mut glibc_max_list = vec![glibc];
+    glibc_max_list.extend((2..glibc.0).rev().map(|major| (major, LAST_GLIBC_MINOR)));
+
+    let mut platforms = Vec::new();
+    for arch in archs {
+        for &(major, max_minor) in &glibc_max_list {
+            let min_minor = if major == too_old.0 {
+                Some(too_old.1)
+            } else {
+                None
+            };
+            let mut glibc_minor = Some(max_minor);
+            while let Some(minor) = glibc_minor.filter(|minor| Some(*minor) != min_minor) {
+                platforms.push(format!("manylinux_{major}_{minor}_{arch}"));
+                if let Some((_, legacy)) = LEGACY_MANYLINUX
+                    .iter()
+                    .find(|(version, _)| *version == (major, minor))
+                {
+                    platforms.push(format!("{legacy}_{arch}"));
+                }
+                glibc_minor = minor.checked_sub(1);
+            }
+        }
+    }
+    platforms
+}
+
+/// Every `android` tag from `api_level` down to 16, the oldest API level CPython supports,
+/// like `packaging.tags.android_platforms`.
+fn android_platforms(api_level: u16, abi: &str) -> Vec<String> {
+    (16..=api_level)
+        .rev()
+        .map(|api_level| format!("android_{api_level}_{abi}"))
+        .collect()
+}
+
+/// Every `ios` tag from `version` down to 12.0, like `packaging.tags.ios_platforms`.
+/// Minor versions of older major releases are assumed to go up to 9.
+fn ios_platforms(version: (u16, u16), multiarch: &str) -> Vec<String> {
+    let (major, minor) = version;
+    if major < 12 {
+        return Vec::new();
+    }
+    let mut platforms = (0..=minor)
+        .rev()
+        .map(|minor| format!("ios_{major}_{minor}_{multiarch}"))
+        .collect::<Vec<_>>();
+    for major in (12..major).rev() {
+        for minor in (0..=9).rev() {
+            platforms.push(format!("ios_{major}_{minor}_{multiarch}"));
+        }
+    }
+    platforms
+}
+
+/// Every `macosx` tag from `version` down to 10.0, like `packaging.tags.mac_platforms`.
+fn mac_platforms(arch: &str, version: (u16, u16)) -> Vec<String> {
+    let mut platforms = Vec::new();
+    if version.0 == 10 {
+        for minor in (0..=version.1).rev() {
+            for format in mac_binary_formats((10, minor), arch) {
+                platforms.push(format!("macosx_10_{minor}_{format}"));
+            }
+        }
+    }
+    if version.0 >= 11 {
+        for major in (11..=version.0).rev() {
+            for format in mac_binary_formats((major, 0), arch) {
+                platforms.push(format!("macosx_{major}_0_{format}"));
+            }
+        }
+        // macOS 11+ runs binaries built for 10.x: natively on x86_64, and via universal2 on arm64.
+        for minor in (4..=16).rev() {
+            if arch == "x86_64" {
+                for format in mac_binary_formats((10, minor), arch) {
+                    platforms.push(format!("macosx_10_{minor}_{format}"));
+                }
+            } else {
+                platforms.push(format!("macosx_10_{minor}_universal2"));
+            }
+        }
+    }
+    platforms
+}
+
+fn mac_binary_formats(version: (u16, u16), arch: &str) -> Vec<&str> {
+    let mut formats = vec![arch];
+    match arch {
+        "x86_64" if version < (10, 4) => return Vec::new(),
+        "x86_64" => formats.extend(["intel", "fat64", "fat32"]),
+        "i386" if version < (10, 4) => return Vec::new(),
+        "i386" => formats.extend(["intel", "fat32", "fat"]),
+        "ppc64" if !((10, 4)..=(10, 5)).contains(&version) => return Vec::new(),
+        "ppc64" => formats.push("fat64"),
+        "ppc" if version > (10, 6) => return Vec::new(),
+        "ppc" => formats.extend(["fat32", "fat"]),
+        _ => {}
+    }
+    if matches!(arch, "arm64" | "x86_64") {
+        formats.push("universal2");
+    }
+    if matches!(arch, "x86_64" | "i386" | "ppc64" | "ppc" | "intel") {
+        formats.push("universal");
+    }
+    formats
+}
+
+fn expand(python_tag: &str, abi_tag: &str, platform_tag: &str) -> BTreeSet<Tag> {
+    let mut tags = BTreeSet::new();
+    for interpreter in python_tag.split('.') {
+        for abi in abi_tag.split('.') {
+            for platform in platform_tag.split('.') {
+                tags.insert(Tag::new(interpreter, abi, platform));
+            }
+        }
+    }
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_to_packaging_tags() -> Result<(), WheelNameParseError> {
+        let wheel_name = WheelName::from_str(
+            "demo-1.0-cp311-CP311-manylinux_2_17_x86_64.manylinux2014_x86_64.whl",
+        )?;
+        assert_eq!(
+            wheel_name.to_packaging_tags(),
+            BTreeSet::from([
+                "cp311-cp311-manylinux2014_x86_64".to_string(),
+                "cp311-cp311-manylinux_2_17_x86_64".to_string(),
+            ]),
+        );
+
+        let wheel_file = WheelFile {
+            wheel_version: "1.0".to_string(),
+            generator: "demo".to_string(),
+            root_is_purelib: true,
+            tags: vec!["py2-none-any".to_string(), "py2.py3-none-any".to_string()],
+            build: None,
+        };
+        assert_eq!(
+            wheel_file.to_packaging_tags().unwrap(),
+            BTreeSet::from(["py2-none-any".to_string(), "py3-none-any".to_string()]),
+        );
+
+        assert_eq!(
+            Tag::parse_compressed("py3-none"),
+            Err(WheelNameParseError::InvalidTag("py3-none".to_string())),
+        );
+        Ok(())
+    }
+
+    fn tag_strings(tags: &[Tag]) -> Vec<String> {
+        tags.iter().map(Tag::to_string).collect()
+    }
+
+    #[test]
+    fn test_compatible_tags_cpython() {
+        let platform = Platform::Windows {
+            arch: "amd64".to_string(),
+        };
+        assert_eq!(
+            tag_strings(&compatible_tags(
+                (3, 4),
+                &Implementation::CPython,
+                &platform
+            )),
+            [
+                "cp34-cp34m-win_amd64",
+                "cp34-abi3-win_amd64",
+                "cp34-none-win_amd64",
+                "cp33-abi3-win_amd64",
+                "cp32-abi3-win_amd64",
+                "py34-none-win_amd64",
+                "py3-none-win_amd64",
+                "py33-none-win_amd64",
+                "py32-none-win_amd64",
+                "py31-none-win_amd64",
+                "py30-none-win_amd64",
+                "cp34-none-any",
+                "py34-none-any",
+                "py3-none-any",
+                "py33-none-any",
+                "py32-none-any",
+                "py31-none-any",
+                "py30-none-any",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_compatible_tags_pypy() {
+        let tags = compatible_tags(
+            (3, 10),
+            &Implementation::PyPy { version: (7, 3) },
+            &Platform::Other("linux_x86_64".to_string()),
+        );
+        assert_eq!(
+            tag_strings(&tags[..3]),
+            [
+                "pp310-pypy310_pp73-linux_x86_64",
+                "pp310-none-linux_x86_64",
+                "py310-none-linux_x86_64",
+            ],
+        );
+        assert!(tags.contains(&Tag::new("pp3", "none", "any")));
+    }
+
+    #[test]
+    fn test_pypy_wheel_compatibility() -> Result<(), WheelNameParseError> {
+        let tags = compatible_tags(
+            (3, 10),
+            &Implementation::PyPy { version: (7, 3) },
+            &Platform::Linux {
+                arch: "x86_64".to_string(),
+                libc: Libc::Glibc(2, 35),
+            },
+        );
+        for (filename, compatible) in [
+            (
+                "numpy-1.26.4-pp310-pypy310_pp73-manylinux_2_17_x86_64.manylinux2014_x86_64.whl",
+                true,
+            ),
+            (
+                "cffi-1.16.0-pp310-pypy310_pp73-manylinux_2_17_x86_64.whl",
+                true,
+            ),
+            (
+                "numpy-1.26.4-pp39-pypy39_pp73-manylinux_2_17_x86_64.whl",
+                false,
+            ),
+            ("numpy-1.26.4-cp310-cp310-manylinux_2_17_x86_64.whl", false),
+            ("attrs-23.2.0-py3-none-any.whl", true),
+        ] {
+            let wheel_name = WheelName::from_str(filename)?;
+            assert_eq!(
+                wheel_name.compatibility(&tags).is_some(),
+                compatible,
+                "{filename}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compatible_tags_graalpy() -> Result<(), WheelNameParseError> {
+        let tags = compatible_tags(
+            (3, 11),
+            &Implementation::GraalPy { version: (24, 2) },
+            &Platform::Other("manylinux_2_28_x86_64".to_string()),
+        );
+        assert_eq!(
+            tag_strings(&tags[..3]),
+            [
+                "graalpy311-graalpy242_311_native-manylinux_2_28_x86_64",
+                "graalpy311-none-manylinux_2_28_x86_64",
+                "py311-none-manylinux_2_28_x86_64",
+            ],
+        );
+        assert!(!tags
+            .iter()
+            .any(|tag| tag.interpreter.starts_with("graalpy") && tag.platform == "any"));
+
+        let wheel_name = WheelName::from_str(
+            "numpy-2.2.1-graalpy311-graalpy242_311_native-manylinux_2_28_x86_64.whl",
+        )?;
+        assert_eq!(wheel_name.compatibility(&tags), Some(0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_abi3() -> Result<(), WheelNameParseError> {
+        let wheel_name = WheelName::from_str("demo-1.0-cp38-abi3-manylinux_2_17_x86_64.whl")?;
+        assert_eq!(wheel_name.abi3_minimum_python(), Some((3, 8)));
+        assert!(!wheel_name.supports_python_abi((3, 7)));
+        assert!(wheel_name.supports_python_abi((3, 8)));
+        assert!(wheel_name.supports_python_abi((3, 12)));
+
+        let wheel_name = WheelName::from_str("demo-1.0-cp311-cp311-win_amd64.whl")?;
+        assert_eq!(wheel_name.abi3_minimum_python(), None);
+        assert!(wheel_name.supports_python_abi((3, 11)));
+        assert!(!wheel_name.supports_python_abi((3, 12)));
+
+        let wheel_name = WheelName::from_str("demo-1.0-py3-none-any.whl")?;
+        assert!(wheel_name.supports_python_abi((3, 12)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_manylinux_platforms() {
+        let platform = Platform::Linux {
+            arch: "x86_64".to_string(),
+            libc: Libc::Glibc(2, 17),
+        };
+        let platforms = platform.tags();
+        assert_eq!(
+            &platforms[..3],
+            [
+                "manylinux_2_17_x86_64",
+                "manylinux2014_x86_64",
+                "manylinux_2_16_x86_64",
+            ],
+        );
+        assert!(platforms.contains(&"manylinux2010_x86_64".to_string()));
+        assert_eq!(
+            &platforms[platforms.len() - 3..],
+            ["manylinux_2_5_x86_64", "manylinux1_x86_64", "linux_x86_64"],
+        );
+    }
+
+    #[test]
+    fn test_musllinux_platforms() {
+        let platform = Platform::Linux {
+            arch: "aarch64".to_string(),
+            libc: Libc::Musl(1, 2),
+        };
+        assert_eq!(
+            platform.tags(),
+            [
+                "musllinux_1_2_aarch64",
+                "musllinux_1_1_aarch64",
+                "musllinux_1_0_aarch64",
+                "linux_aarch64",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_mobile_platforms() {
+        let android = Platform::Android {
+            api_level: 18,
+            abi: "arm64_v8a".to_string(),
+        };
+        assert_eq!(
+            android.tags(),
+            [
+                "android_18_arm64_v8a",
+                "android_17_arm64_v8a",
+                "android_16_arm64_v8a",
+            ],
+        );
+
+        let ios = Platform::Ios {
+            version: (13, 1),
+            multiarch: "arm64_iphoneos".to_string(),
+        };
+        let tags = ios.tags();
+        assert_eq!(
+            &tags[..3],
+            [
+                "ios_13_1_arm64_iphoneos",
+                "ios_13_0_arm64_iphoneos",
+                "ios_12_9_arm64_iphoneos",
+            ],
+        );
+        assert_eq!(tags.last().unwrap(), "ios_12_0_arm64_iphoneos");
+    }
+
+    #[cfg(feature = "detect-libc")]
+    #[test]
+    fn test_parse_ldd_version() {
+        assert_eq!(
+            parse_ldd_version("ldd (Ubuntu GLIBC 2.35-0ubuntu3.1) 2.35\nCopyright (C) 2022\n"),
+            Some(Libc::Glibc(2, 35)),
+        );
+        assert_eq!(
+            parse_ldd_version("musl libc (x86_64)\nVersion 1.2.4\nDynamic Program Loader\n"),
+            Some(Libc::Musl(1, 2)),
+        );
+        assert_eq!(parse_ldd_version("ldd: unrecognized option"), None);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::Environment;
+use crate::Implementation;
+use crate::Libc;
+use crate::Platform;
+
+/// The oldest glibc Rust's `*-linux-gnu` targets support.
+const RUST_MIN_GLIBC: Libc = Libc::Glibc(2, 17);
+
+/// The oldest musl Rust's `*-linux-musl` targets support.
+const RUST_MIN_MUSL: Libc = Libc::Musl(1, 2);
+
+/// The oldest Android API level Rust's `*-linux-android` targets support.
+const RUST_MIN_ANDROID_API_LEVEL: u16 = 21;
+
+/// The oldest iOS release CPython supports, per [PEP 730](https://peps.python.org/pep-0730/).
+const PYTHON_MIN_IOS: (u16, u16) = (13, 0);
+
+impl Environment {
+    /// Describes a CPython interpreter running on a Rust target, e.g. `x86_64-unknown-linux-musl`
+    /// or `aarch64-apple-darwin`, for selecting wheels when cross-compiling.
+    ///
+    /// OS and libc versions default to the oldest the Rust target supports, so the result only
+    /// selects wheels which run everywhere the target does. Adjust [`Environment::platform`]
+    /// when the deployment target is known to be newer.
+    pub fn from_target_triple(
+        triple: &str,
+        python_version: (u8, u8),
+    ) -> Result<Self, TargetParseError> {
+        let platform = platform_for_target(triple)
+            .ok_or_else(|| TargetParseError::UnsupportedTarget(triple.to_owned()))?;
+        Ok(Self::new(python_version, Implementation::CPython, platform))
+    }
+}
+
+fn platform_for_target(triple: &str) -> Option<Platform> {
+    let (arch, rest) = triple.split_once('-')?;
+    let platform = match rest {
+        "unknown-linux-gnu" | "unknown-linux-gnueabihf" => Platform::Linux {
+            arch: linux_arch(arch)?.to_owned(),
+            libc: RUST_MIN_GLIBC,
+        },
+        "unknown-linux-musl" | "unknown-linux-musleabihf" => Platform::Linux {
+            arch: linux_arch(arch)?.to_owned(),
+            libc: RUST_MIN_MUSL,
+        },
+        "apple-darwin" => Platform::MacOS {
+            arch: match arch {
+                "x86_64" => "x86_64",
+                "aarch64" => "arm64",
+                _ => return None,
+            }
+            .to_owned(),
+            version: match arch {
+                "aarch64" => (11, 0),
+                _ => (10, 12),
+            },
+        },
+        "pc-windows-msvc" | "pc-windows-gnu" => Platform::Windows {
+            arch: match arch {
+                "i686" => "x86",
+                "x86_64" => "amd64",
+                "aarch64" => "arm64",
+                _ => return None,
+            }
+            .to_owned(),
+        },
+        "linux-android" | "linux-androideabi" => Platform::Android {
+            api_level: RUST_MIN_ANDROID_API_LEVEL,
+            abi: match arch {
+                "aarch64" => "arm64_v8a",
+                "armv7" => "armeabi_v7a",
+                "x86_64" => "x86_64",
+                "i686" => "x86",
+                _ => return None,
+            }
+            .to_owned(),
+        },
+        "apple-ios" | "apple-ios-sim" => Platform::Ios {
+            version: PYTHON_MIN_IOS,
+            multiarch: match (arch, rest) {
+                ("aarch64", "apple-ios") => "arm64_iphoneos",
+                ("aarch64", _) => "arm64_iphonesimulator",
+                ("x86_64", _) => "x86_64_iphonesimulator",
+                _ => return None,
+            }
+            .to_owned(),
+        },
+        _ => return None,
+    };
+    Some(platform)
+}
+
+/// The architecture as Linux platform tags spell it, i.e. `uname -m`.
+fn linux_arch(arch: &str) -> Option<&'static str> {
+    let arch = match arch {
+        "x86_64" => "x86_64",
+        "i686" | "i586" => "i686",
+        "aarch64" => "aarch64",
+        "armv7" => "armv7l",
+        "powerpc64le" => "ppc64le",
+        "s390x" => "s390x",
+        "riscv64gc" => "riscv64",
+        _ => return None,
+    };
+    Some(arch)
+}
+
+#[derive(thiserror::Error, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TargetParseError {
+    #[error("target triple has no known Python platform")]
+    UnsupportedTarget(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_from_target_triple() -> Result<(), TargetParseError> {
+        assert_eq!(
+            Environment::from_target_triple("x86_64-unknown-linux-musl", (3, 12))?.platform,
+            Platform::Linux {
+                arch: "x86_64".to_string(),
+                libc: Libc::Musl(1, 2),
+            },
+        );
+        assert_eq!(
+            Environment::from_target_triple("armv7-unknown-linux-gnueabihf", (3, 12))?.platform,
+            Platform::Linux {
+                arch: "armv7l".to_string(),
+                libc: Libc::Glibc(2, 17),
+            },
+        );
+
+        let environment = Environment::from_target_triple("aarch64-apple-darwin", (3, 12))?;
+        let tags = environment.compatible_tags();
+        assert_eq!(tags[0].to_string(), "cp312-cp312-macosx_11_0_arm64");
+        assert!(tags
+            .iter()
+            .any(|tag| tag.platform == "macosx_10_9_universal2"));
+
+        assert_eq!(
+            Environment::from_target_triple("wasm32-unknown-unknown", (3, 12)),
+            Err(TargetParseError::UnsupportedTarget(
+                "wasm32-unknown-unknown".to_string()
+            )),
+        );
+        Ok(())
+    }
+}
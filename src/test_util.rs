@@ -0,0 +1,315 @@
+use std::io;
+use std::io::Cursor;
+use std::io::Write;
+use std::str::FromStr;
+
+use zip::write::FileOptions;
+use zip::DateTime;
+use zip::ZipWriter;
+
+use crate::hash::encode_record_digest;
+use crate::HashAlgorithm;
+use crate::Wheel;
+use crate::WheelError;
+use crate::WheelName;
+
+/// Builds minimal, valid wheels in memory, for tests which need a wheel fixture
+/// without checking binary files in.
+///
+/// The built wheel contains the added files, a `METADATA` file with `Metadata-Version`,
+/// `Name`, `Version`, and any added fields, a `WHEEL` file with a `Tag` line for each tag
+/// the file name's tags expand to, and a `RECORD` file with a digest and size for
+/// everything else except `RECORD.jws` and `RECORD.p7s`, which sign `RECORD` and so can't be
+/// listed in it. Every entry is modified at 1980-01-01, the earliest a zip can store, unless
+/// set otherwise, so building is reproducible. Add a [`Corruption`] to build an invalid one.
+#[derive(Clone, Debug)]
+pub struct WheelBuilder {
+    filename: String,
+    name: WheelName,
+    files: Vec<(String, Vec<u8>)>,
+    metadata: Vec<(String, String)>,
+    root_is_purelib: bool,
+    digest_algorithm: HashAlgorithm,
+    last_modified: Vec<(String, DateTime)>,
+    prefix: Vec<u8>,
+    zip_comment: String,
+    corruptions: Vec<Corruption>,
+}
+
+/// A controlled way for [`WheelBuilder`] to corrupt the wheel it builds, for testing error paths.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Corruption {
+    /// Inverts the byte at `offset` in the file at `path` after its `RECORD` row is written,
+    /// so the archive is intact but the file no longer matches its digest. Building fails with
+    /// an [`io::ErrorKind::InvalidInput`] error if `offset` is past the end of the file.
+    FlipByte { path: String, offset: usize },
+    /// Leaves the file at this path out of `RECORD`.
+    DropRecordRow(String),
+    /// Truncates the archive, including any [`WheelBuilder::prefix`], to this many bytes,
+    /// cutting off the central directory.
+    Truncate(usize),
+}
+
+impl WheelBuilder {
+    /// `filename` is the wheel's file name, e.g. `demo-1.0-py3-none-any.whl`.
+    pub fn new(filename: &str) -> Result<Self, WheelError> {
+        Ok(Self {
+            filename: filename.to_owned(),
+            name: WheelName::from_str(filename)?,
+            files: Vec::new(),
+            metadata: Vec::new(),
+            root_is_purelib: true,
+            digest_algorithm: HashAlgorithm::Sha256,
+            last_modified: Vec::new(),
+            prefix: Vec::new(),
+            zip_comment: String::new(),
+            corruptions: Vec::new(),
+        })
+    }
+
+    /// Adds a file, at a path relative to the root of the archive.
+    pub fn file(mut self, path: &str, contents: impl Into<Vec<u8>>) -> Self {
+        self.files.push((path.to_owned(), contents.into()));
+        self
+    }
+
+    /// Adds a `METADATA` field. Fields may be added more than once.
+    pub fn metadata(mut self, field: &str, value: &str) -> Self {
+        self.metadata.push((field.to_owned(), value.to_owned()));
+        self
+    }
+
+    pub fn root_is_purelib(mut self, root_is_purelib: bool) -> Self {
+        self.root_is_purelib = root_is_purelib;
+        self
+    }
+
+    /// The algorithm for `RECORD` digests, `sha256` by default. [`HashAlgorithm`] only has the
+    /// algorithms the spec allows, so the built `RECORD` can't use a weaker one.
+    pub fn digest_algorithm(mut self, digest_algorithm: HashAlgorithm) -> Self {
+        self.digest_algorithm = digest_algorithm;
+        self
+    }
+
+    /// Sets the modification time of the entry at `path`.
+    pub fn last_modified(mut self, path: &str, last_modified: DateTime) -> Self {
+        self.last_modified.push((path.to_owned(), last_modified));
+        self
+    }
+
+    /// Bytes to write before the archive, e.g. the shell script of a self-extracting archive.
+    /// The archive's offsets aren't adjusted for them, as `cat` leaves them.
+    pub fn prefix(mut self, prefix: impl Into<Vec<u8>>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// The comment in the zip end of central directory record.
+    pub fn zip_comment(mut self, zip_comment: &str) -> Self {
+        self.zip_comment = zip_comment.to_owned();
+        self
+    }
+
+    pub fn corrupt(mut self, corruption: Corruption) -> Self {
+        self.corruptions.push(corruption);
+        self
+    }
+
+    /// The `.dist-info` directory of the built wheel, e.g. `demo-1.0.dist-info`.
+    pub fn dist_info(&self) -> String {
+        format!(
+            "{}-{}.dist-info",
+            self.name.escaped_distribution(),
+            self.name.version
+        )
+    }
+
+    /// Builds the wheel's archive.
+    pub fn build(&self) -> Result<Cursor<Vec<u8>>, WheelError> {
+        let dist_info = self.dist_info();
+
+        let mut metadata = format!(
+            "Metadata-Version: 2.1\nName: {}\nVersion: {}\n",
+            self.name.distribution, self.name.version
+        );
+        for (field, value) in &self.metadata {
+            metadata.push_str(&format!("{field}: {value}\n"));
+        }
+        let mut wheel = format!(
+            "Wheel-Version: 1.0\nGenerator: pep-427\nRoot-Is-Purelib: {}\n",
+            self.root_is_purelib
+        );
+        for tag in self.name.tags() {
+            wheel.push_str(&format!("Tag: {tag}\n"));
+        }
+
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer.set_comment(self.zip_comment.as_str());
+        let mut record = String::new();
+        let files = self
+            .files
+            .iter()
+            .map(|(path, contents)| (path.clone(), contents.as_slice()));
+        let dist_info_files = [
+            (format!("{dist_info}/METADATA"), metadata.as_bytes()),
+            (format!("{dist_info}/WHEEL"), wheel.as_bytes()),
+        ];
+        let signature_paths = [
+            format!("{dist_info}/RECORD.jws"),
+            format!("{dist_info}/RECORD.p7s"),
+        ];
+        for (path, contents) in files.chain(dist_info_files) {
+            let digest = encode_record_digest(&self.digest_algorithm.digest(contents));
+            if !signature_paths.contains(&path)
+                && !self
+                    .corruptions
+                    .contains(&Corruption::DropRecordRow(path.clone()))
+            {
+                record.push_str(&format!(
+                    "{path},{}={digest},{}\n",
+                    self.digest_algorithm.name(),
+                    contents.len()
+                ));
+            }
+            let mut contents = contents.to_vec();
+            for corruption in &self.corruptions {
+                if let Corruption::FlipByte {
+                    path: flipped_path,
+                    offset,
+                } = corruption
+                {
+                    if *flipped_path == path {
+                        let Some(byte) = contents.get_mut(*offset) else {
+                            return Err(io::Error::new(
+                                io::ErrorKind::InvalidInput,
+                                format!("can't flip byte {offset} of {path}, which is shorter"),
+                            )
+                            .into());
+                        };
+                        *byte = !*byte;
+                    }
+                }
+            }
+            writer.start_file(path.as_str(), self.file_options(&path))?;
+            writer.write_all(&contents)?;
+        }
+        let record_path = format!("{dist_info}/RECORD");
+        record.push_str(&format!("{record_path},,\n"));
+        writer.start_file(record_path.as_str(), self.file_options(&record_path))?;
+        writer.write_all(record.as_bytes())?;
+
+        let mut archive =
+            Cursor::new([self.prefix.as_slice(), writer.finish()?.get_ref()].concat());
+        for corruption in &self.corruptions {
+            if let Corruption::Truncate(len) = corruption {
+                archive.get_mut().truncate(*len);
+            }
+        }
+        Ok(archive)
+    }
+
+    fn file_options(&self, path: &str) -> FileOptions {
+        let last_modified = self
+            .last_modified
+            .iter()
+            .rev()
+            .find(|(modified_path, _)| modified_path == path)
+            .map_or_else(DateTime::default, |(_, last_modified)| *last_modified);
+        FileOptions::default().last_modified_time(last_modified)
+    }
+
+    /// Builds the wheel, and opens it.
+    pub fn open(&self) -> Result<Wheel<Cursor<Vec<u8>>>, WheelError> {
+        Wheel::open(&self.filename, self.build()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VerifyError;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_wheel_builder() -> Result<(), WheelError> {
+        let mut wheel = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .file("demo/__init__.py", "hello world")
+            .metadata("Requires-Dist", "requests")
+            .open()?;
+        wheel.verify()?;
+        assert_eq!(
+            wheel.metadata_headers()?.get("Requires-Dist"),
+            Some("requests")
+        );
+        assert_eq!(wheel.wheel_file()?.tags, vec!["py3-none-any".to_string()]);
+        assert_eq!(wheel.record_file()?.records.len(), 4);
+
+        let mut wheel = WheelBuilder::new("demo-1.0-py2.py3-none-any.whl")?.open()?;
+        assert_eq!(
+            wheel.wheel_file()?.tags,
+            vec!["py2-none-any".to_string(), "py3-none-any".to_string()],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_digest_algorithm() -> Result<(), WheelError> {
+        let mut wheel = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .file("demo/__init__.py", "hello world")
+            .digest_algorithm(HashAlgorithm::Sha512)
+            .open()?;
+        wheel.verify()?;
+        let record_file = wheel.record_file()?;
+        let digest = record_file.records[0].digest.as_ref().unwrap();
+        assert_eq!(digest.method, "sha512");
+        assert_eq!(digest.b64_digest.len(), 86);
+        Ok(())
+    }
+
+    #[test]
+    fn test_corruption() -> Result<(), WheelError> {
+        let builder =
+            WheelBuilder::new("demo-1.0-py3-none-any.whl")?.file("demo/__init__.py", "hello world");
+
+        let mut wheel = builder
+            .clone()
+            .corrupt(Corruption::FlipByte {
+                path: "demo/__init__.py".to_string(),
+                offset: 0,
+            })
+            .open()?;
+        assert!(matches!(
+            wheel.verify(),
+            Err(WheelError::VerifyError(VerifyError::DigestMismatch(path))) if path == "demo/__init__.py",
+        ));
+        assert!(matches!(
+            builder
+                .clone()
+                .corrupt(Corruption::FlipByte {
+                    path: "demo/__init__.py".to_string(),
+                    offset: 11,
+                })
+                .build(),
+            Err(WheelError::IOError(e)) if e.kind() == io::ErrorKind::InvalidInput,
+        ));
+
+        let mut wheel = builder
+            .clone()
+            .corrupt(Corruption::DropRecordRow("demo/__init__.py".to_string()))
+            .open()?;
+        assert!(!wheel
+            .record_file()?
+            .records
+            .iter()
+            .any(|record| record.filename == "demo/__init__.py"));
+
+        let archive_len = builder.build()?.get_ref().len();
+        assert!(builder
+            .corrupt(Corruption::Truncate(archive_len - 1))
+            .open()
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use std::borrow::Cow;
+
+/// Strips a leading UTF-8 byte order mark, and converts `\r\n` and lone `\r` line endings
+/// to `\n`, so parsers only need to handle `\n`. Wheels built on Windows may have either.
+pub(crate) fn normalize_text(s: &str) -> Cow<'_, str> {
+    let s = s.strip_prefix('\u{feff}').unwrap_or(s);
+    if !s.contains('\r') {
+        return Cow::Borrowed(s);
+    }
+    Cow::Owned(s.replace("\r\n", "\n").replace('\r', "\n"))
+}
+
+/// A single-byte charset which legacy `METADATA` files were written in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Charset {
+    Latin1,
+    Windows1252,
+}
+
+/// The characters windows-1252 has in place of the C1 controls, which are kept for the
+/// five bytes it leaves undefined.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20ac}', '\u{81}', '\u{201a}', '\u{192}', '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2c6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8d}', '\u{17d}', '\u{8f}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2dc}', '\u{2122}', '\u{161}', '\u{203a}', '\u{153}', '\u{9d}', '\u{17e}', '\u{178}',
+];
+
+impl Charset {
+    /// Looks up a charset by a label such as `latin-1` or `cp1252`, ignoring case.
+    pub(crate) fn from_label(label: &str) -> Option<Charset> {
+        match label.trim().to_ascii_lowercase().as_str() {
+            "latin-1" | "latin1" | "l1" | "iso-8859-1" | "iso8859-1" | "iso_8859-1" | "cp819" => {
+                Some(Charset::Latin1)
+            }
+            "windows-1252" | "cp1252" | "x-cp1252" => Some(Charset::Windows1252),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Charset::Latin1 => "iso-8859-1",
+            Charset::Windows1252 => "windows-1252",
+        }
+    }
+
+    /// Decodes `bytes`, which never fails as every byte is a character in both charsets.
+    pub(crate) fn decode(self, bytes: &[u8]) -> String {
+        bytes
+            .iter()
+            .map(|&b| match (self, b) {
+                (Charset::Windows1252, 0x80..=0x9f) => WINDOWS_1252_HIGH[usize::from(b - 0x80)],
+                _ => char::from(b),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_normalize_text() {
+        assert!(matches!(normalize_text("a\nb\n"), Cow::Borrowed("a\nb\n")));
+        assert_eq!(normalize_text("\u{feff}a\r\nb\rc\n"), "a\nb\nc\n");
+        assert_eq!(normalize_text("a\u{feff}"), "a\u{feff}");
+    }
+
+    #[test]
+    fn test_charset() {
+        assert_eq!(Charset::from_label(" Latin-1"), Some(Charset::Latin1));
+        assert_eq!(Charset::from_label("CP1252"), Some(Charset::Windows1252));
+        assert_eq!(Charset::from_label("utf-8"), None);
+        assert_eq!(Charset::Latin1.decode(b"caf\xe9 \x93"), "caf\u{e9} \u{93}");
+        assert_eq!(
+            Charset::Windows1252.decode(b"caf\xe9 \x93q\x94 \x81"),
+            "caf\u{e9} \u{201c}q\u{201d} \u{81}"
+        );
+    }
+}
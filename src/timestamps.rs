@@ -0,0 +1,286 @@
+use std::io::Read;
+use std::io::Seek;
+
+use crate::Wheel;
+use crate::WheelError;
+
+/// Extra field header IDs which carry timestamps in addition to the DOS modification time.
+const TIMESTAMP_EXTRA_FIELDS: &[u16] = &[
+    0x000a, // NTFS
+    0x000d, // PKWARE UNIX
+    0x5455, // Info-ZIP extended timestamp
+    0x5855, // Info-ZIP UNIX, original
+];
+
+/// An archive entry's modification time, as stored in the zip (MS-DOS, no time zone).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DosTimestamp {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl DosTimestamp {
+    /// The earliest time representable in a zip, used by reproducible builds
+    /// in place of the Unix epoch.
+    pub const MIN: DosTimestamp = DosTimestamp {
+        year: 1980,
+        month: 1,
+        day: 1,
+        hour: 0,
+        minute: 0,
+        second: 0,
+    };
+
+    /// Seconds since the Unix epoch, reading the timestamp as UTC.
+    ///
+    /// Zip timestamps have no time zone. Reproducible build tools write them in UTC, but
+    /// other archivers write the builder's local time, so this may be off by its UTC offset.
+    pub fn unix_timestamp(&self) -> i64 {
+        let days = days_from_civil(self.year.into(), self.month.into(), self.day.into());
+        days * 86400
+            + i64::from(self.hour) * 3600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second)
+    }
+}
+
+impl From<zip::DateTime> for DosTimestamp {
+    fn from(date_time: zip::DateTime) -> Self {
+        Self {
+            year: date_time.year(),
+            month: date_time.month(),
+            day: date_time.day(),
+            hour: date_time.hour(),
+            minute: date_time.minute(),
+            second: date_time.second(),
+        }
+    }
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// The modification time of every entry, in archive order.
+    pub fn entry_timestamps(&mut self) -> Result<Vec<(String, DosTimestamp)>, WheelError> {
+        let mut timestamps = Vec::with_capacity(self.archive.len());
+        for i in 0..self.archive.len() {
+            let zip_file = self.archive.by_index_raw(i)?;
+            timestamps.push((zip_file.name().to_owned(), zip_file.last_modified().into()));
+        }
+        Ok(timestamps)
+    }
+
+    /// The modification time of every entry in seconds since the Unix epoch, in archive order.
+    ///
+    /// The UTC time from an Info-ZIP extended timestamp extra field is used if the entry has
+    /// one, and otherwise the DOS time is read as UTC, see [`DosTimestamp::unix_timestamp`].
+    pub fn entry_unix_timestamps(&mut self) -> Result<Vec<(String, i64)>, WheelError> {
+        let mut timestamps = Vec::with_capacity(self.archive.len());
+        for i in 0..self.archive.len() {
+            let zip_file = self.archive.by_index_raw(i)?;
+            let timestamp = extended_modification_time(zip_file.extra_data())
+                .unwrap_or_else(|| DosTimestamp::from(zip_file.last_modified()).unix_timestamp());
+            timestamps.push((zip_file.name().to_owned(), timestamp));
+        }
+        Ok(timestamps)
+    }
+
+    /// The modification time of every entry in UTC, in archive order,
+    /// see [`Wheel::entry_unix_timestamps`].
+    #[cfg(feature = "time")]
+    pub fn entry_times(&mut self) -> Result<Vec<(String, time::OffsetDateTime)>, WheelError> {
+        Ok(self
+            .entry_unix_timestamps()?
+            .into_iter()
+            .map(|(path, timestamp)| {
+                let time = time::OffsetDateTime::from_unix_timestamp(timestamp)
+                    .unwrap_or(time::OffsetDateTime::UNIX_EPOCH);
+                (path, time)
+            })
+            .collect())
+    }
+
+    /// The modification time of every entry in UTC, in archive order,
+    /// see [`Wheel::entry_unix_timestamps`].
+    #[cfg(feature = "chrono")]
+    pub fn entry_date_times(
+        &mut self,
+    ) -> Result<Vec<(String, chrono::DateTime<chrono::Utc>)>, WheelError> {
+        Ok(self
+            .entry_unix_timestamps()?
+            .into_iter()
+            .map(|(path, timestamp)| {
+                let date_time = chrono::DateTime::from_timestamp(timestamp, 0).unwrap_or_default();
+                (path, date_time)
+            })
+            .collect())
+    }
+
+    /// Whether the archive looks like the output of a reproducible build: every entry
+    /// has the same modification time and no entry carries extra timestamp fields.
+    ///
+    /// This can't prove the build is reproducible, only rule it out cheaply.
+    pub fn is_reproducible_candidate(&mut self) -> Result<bool, WheelError> {
+        let mut first_timestamp = None;
+        for i in 0..self.archive.len() {
+            let zip_file = self.archive.by_index_raw(i)?;
+            let timestamp = DosTimestamp::from(zip_file.last_modified());
+            if *first_timestamp.get_or_insert(timestamp) != timestamp {
+                return Ok(false);
+            }
+            if has_timestamp_extra_field(zip_file.extra_data()) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// The modification time from an Info-ZIP extended timestamp extra field (`0x5455`),
+/// in seconds since the Unix epoch, if there is one.
+fn extended_modification_time(mut extra_data: &[u8]) -> Option<i64> {
+    while extra_data.len() >= 4 {
+        let id = u16::from_le_bytes([extra_data[0], extra_data[1]]);
+        let size = u16::from_le_bytes([extra_data[2], extra_data[3]]) as usize;
+        let data = extra_data.get(4..4 + size)?;
+        // The first byte is a set of flags, and the modification time is present if bit 0 is.
+        if id == 0x5455 && data.len() >= 5 && data[0] & 1 == 1 {
+            return Some(i32::from_le_bytes([data[1], data[2], data[3], data[4]]).into());
+        }
+        extra_data = &extra_data[4 + size..];
+    }
+    None
+}
+
+/// The number of days from the Unix epoch to a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Walks the `(id, size, data)` records of a zip extra field.
+fn has_timestamp_extra_field(mut extra_data: &[u8]) -> bool {
+    while extra_data.len() >= 4 {
+        let id = u16::from_le_bytes([extra_data[0], extra_data[1]]);
+        let size = u16::from_le_bytes([extra_data[2], extra_data[3]]) as usize;
+        if TIMESTAMP_EXTRA_FIELDS.contains(&id) {
+            return true;
+        }
+        extra_data = extra_data.get(4 + size..).unwrap_or_default();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use zip::DateTime;
+
+    use super::*;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    fn open(timestamps: &[DateTime]) -> Result<Wheel<Cursor<Vec<u8>>>, WheelError> {
+        let mut builder = WheelBuilder::new("demo-1.0-py3-none-any.whl")?;
+        for (i, timestamp) in timestamps.iter().enumerate() {
+            let path = format!("demo/{i}.py");
+            builder = builder.file(&path, "").last_modified(&path, *timestamp);
+        }
+        builder.open()
+    }
+
+    #[test]
+    fn test_reproducible_candidate() -> Result<(), WheelError> {
+        let min = DateTime::default();
+        let later = DateTime::from_date_and_time(2023, 5, 1, 12, 30, 0).unwrap();
+
+        let mut wheel = open(&[min, min])?;
+        assert!(wheel.is_reproducible_candidate()?);
+        assert_eq!(
+            wheel.entry_timestamps()?,
+            vec![
+                ("demo/0.py".to_string(), DosTimestamp::MIN),
+                ("demo/1.py".to_string(), DosTimestamp::MIN),
+                ("demo-1.0.dist-info/METADATA".to_string(), DosTimestamp::MIN),
+                ("demo-1.0.dist-info/WHEEL".to_string(), DosTimestamp::MIN),
+                ("demo-1.0.dist-info/RECORD".to_string(), DosTimestamp::MIN),
+            ],
+        );
+
+        let mut wheel = open(&[min, later])?;
+        assert!(!wheel.is_reproducible_candidate()?);
+        assert_eq!(
+            wheel.entry_timestamps()?[1],
+            (
+                "demo/1.py".to_string(),
+                DosTimestamp {
+                    year: 2023,
+                    month: 5,
+                    day: 1,
+                    hour: 12,
+                    minute: 30,
+                    second: 0,
+                }
+            ),
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unix_timestamp() {
+        assert_eq!(DosTimestamp::MIN.unix_timestamp(), 315532800);
+        let timestamp =
+            DosTimestamp::from(DateTime::from_date_and_time(2023, 5, 1, 12, 30, 0).unwrap());
+        assert_eq!(timestamp.unix_timestamp(), 1682944200);
+    }
+
+    #[test]
+    fn test_extended_modification_time() {
+        assert_eq!(extended_modification_time(&[]), None);
+        assert_eq!(
+            extended_modification_time(&[
+                0x01, 0x00, 0x00, 0x00, 0x55, 0x54, 0x05, 0x00, 0x01, 0xc8, 0xb0, 0x4f, 0x64
+            ]),
+            Some(1682944200),
+        );
+        // Only the access time is present.
+        assert_eq!(
+            extended_modification_time(&[0x55, 0x54, 0x05, 0x00, 0x02, 0xc8, 0xb0, 0x4f, 0x64]),
+            None,
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_entry_times() -> Result<(), WheelError> {
+        let mut wheel = open(&[DateTime::default()])?;
+        assert_eq!(
+            wheel.entry_times()?[0],
+            (
+                "demo/0.py".to_string(),
+                time::OffsetDateTime::from_unix_timestamp(315532800).unwrap()
+            ),
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_has_timestamp_extra_field() {
+        assert!(!has_timestamp_extra_field(&[]));
+        assert!(!has_timestamp_extra_field(&[0x01, 0x00, 0x00, 0x00]));
+        // Info-ZIP UNIX (new) only has a user and group ID.
+        assert!(!has_timestamp_extra_field(&[0x55, 0x78, 0x00, 0x00]));
+        assert!(has_timestamp_extra_field(&[
+            0x01, 0x00, 0x00, 0x00, 0x55, 0x54, 0x01, 0x00, 0x00
+        ]));
+    }
+}
@@ -0,0 +1,101 @@
+use std::io::Read;
+use std::io::Seek;
+
+use crate::limits;
+use crate::Wheel;
+use crate::WheelError;
+
+/// The marker file which [PEP 561](https://peps.python.org/pep-0561/) packages use to
+/// declare that they ship type information.
+const PY_TYPED: &str = "py.typed";
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Paths of the `py.typed` markers in the archive, sorted.
+    pub fn py_typed_markers(&self) -> Vec<&str> {
+        let mut markers: Vec<&str> = self.file_names().filter(|path| is_py_typed(path)).collect();
+        markers.sort_unstable();
+        markers
+    }
+
+    pub fn has_py_typed(&self) -> bool {
+        self.file_names().any(is_py_typed)
+    }
+
+    /// Whether this is a stub-only package: its distribution name ends in `-stubs`,
+    /// and every file outside the `.dist-info` directory is a `.pyi` stub or `py.typed` marker.
+    pub fn is_stub_only(&self) -> bool {
+        if !self.name.distribution.ends_with("-stubs") {
+            return false;
+        }
+        let dist_info = self.dist_info_path("");
+        let mut files = self
+            .file_names()
+            .filter(|path| !path.ends_with('/') && !path.starts_with(&dist_info))
+            .peekable();
+        files.peek().is_some() && files.all(|path| path.ends_with(".pyi") || is_py_typed(path))
+    }
+
+    /// `py.typed` markers which declare their package partial, sorted.
+    ///
+    /// A partial stub package only covers some of its runtime package, and type checkers
+    /// fall back to the runtime package for the rest.
+    pub fn partial_py_typed_markers(&mut self) -> Result<Vec<String>, WheelError> {
+        let markers = self
+            .py_typed_markers()
+            .into_iter()
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+        let mut partial = Vec::new();
+        for marker in markers {
+            let contents =
+                limits::read_entry_to_string(&mut self.archive, &self.limits, &marker, None)?;
+            if contents.lines().any(|line| line.trim() == "partial") {
+                partial.push(marker);
+            }
+        }
+        Ok(partial)
+    }
+}
+
+fn is_py_typed(path: &str) -> bool {
+    path.rsplit('/').next() == Some(PY_TYPED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_inline_types() -> Result<(), WheelError> {
+        let wheel = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .file("demo/__init__.py", "")
+            .file("demo/py.typed", "")
+            .open()?;
+        assert!(wheel.has_py_typed());
+        assert_eq!(wheel.py_typed_markers(), vec!["demo/py.typed"]);
+        assert!(!wheel.is_stub_only());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stub_only() -> Result<(), WheelError> {
+        let builder = WheelBuilder::new("demo_stubs-1.0-py3-none-any.whl")?
+            .file("demo-stubs/__init__.pyi", "");
+        let mut wheel = builder
+            .clone()
+            .file("demo-stubs/py.typed", "partial\n")
+            .open()?;
+        assert!(wheel.is_stub_only());
+        assert_eq!(
+            wheel.partial_py_typed_markers()?,
+            vec!["demo-stubs/py.typed".to_string()],
+        );
+
+        let wheel = builder.file("demo-stubs/_impl.py", "").open()?;
+        assert!(!wheel.is_stub_only());
+        Ok(())
+    }
+}
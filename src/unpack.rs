@@ -0,0 +1,385 @@
+use std::collections::HashMap;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+use std::io::Write;
+use std::panic;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::thread;
+
+use globset::GlobSet;
+use zip::ZipArchive;
+
+use crate::hash::HASH_BUFFER_SIZE;
+use crate::layout::ArchiveLayoutError;
+use crate::verify::check_record;
+use crate::verify::expected_digest;
+use crate::Limits;
+use crate::Record;
+use crate::RecordFile;
+use crate::VerifyError;
+use crate::Wheel;
+use crate::WheelError;
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Extracts the files whose archive paths match `patterns` into `destination`,
+    /// returning their archive paths in archive order.
+    ///
+    /// Each extracted file which `RECORD` lists with a digest is verified while it's written;
+    /// nothing else in the archive is read. A file which fails verification is left on disk.
+    ///
+    /// A matching file which `RECORD` doesn't list can't be verified, so it's an
+    /// [`VerifyError::UnrecordedFile`] error, and isn't written. `RECORD.jws` and `RECORD.p7s`
+    /// are the exception, since they sign `RECORD` and so can't be listed in it.
+    pub fn unpack_matching(
+        &mut self,
+        patterns: &GlobSet,
+        destination: &Path,
+    ) -> Result<Vec<String>, WheelError> {
+        let record_file = self.record_file()?;
+        let records = records_by_path(&record_file);
+
+        let target = UnpackTarget {
+            patterns,
+            destination,
+            records: &records,
+            signature_paths: self.signature_paths(),
+            limits: &self.limits,
+        };
+
+        let mut unpacked = Vec::new();
+        let mut buffer = vec![0; HASH_BUFFER_SIZE];
+        for i in 0..self.archive.len() {
+            if let Some(name) = target.unpack_entry(&mut self.archive, i, &mut buffer)? {
+                unpacked.push(name);
+            }
+        }
+        Ok(unpacked)
+    }
+
+    /// Like [`Wheel::unpack_matching`], but decompresses, verifies, and writes entries on up
+    /// to `threads` worker threads, for large wheels on disks fast enough that a single
+    /// thread is the bottleneck.
+    ///
+    /// Each worker reads through its own reader from `open_reader`, which must open the same
+    /// wheel as this one, e.g. `|| File::open(&path)`. Readers are opened on the workers, so
+    /// they needn't be `Send`.
+    ///
+    /// The returned paths are in archive order, whichever thread wrote them. Once an entry
+    /// fails no new ones are started, and the error of the earliest failed entry in archive
+    /// order is returned.
+    pub fn unpack_matching_parallel<S: Read + Seek>(
+        &mut self,
+        patterns: &GlobSet,
+        destination: &Path,
+        threads: usize,
+        open_reader: impl Fn() -> io::Result<S> + Sync,
+    ) -> Result<Vec<String>, WheelError> {
+        let record_file = self.record_file()?;
+        let records = records_by_path(&record_file);
+        let target = UnpackTarget {
+            patterns,
+            destination,
+            records: &records,
+            signature_paths: self.signature_paths(),
+            limits: &self.limits,
+        };
+
+        let len = self.archive.len();
+        let next = AtomicUsize::new(0);
+        let failed = AtomicBool::new(false);
+        let mut results = thread::scope(|scope| {
+            let workers = (0..threads.clamp(1, len.max(1)))
+                .map(|_| {
+                    let (target, next, failed) = (&target, &next, &failed);
+                    let open_reader = &open_reader;
+                    scope.spawn(move || {
+                        let archive = open_reader()
+                            .map_err(WheelError::from)
+                            .and_then(|reader| Ok(ZipArchive::new(reader)?));
+                        let mut archive = match archive {
+                            Ok(archive) => archive,
+                            Err(e) => {
+                                // Sorted after every entry, so an entry's own error wins.
+                                failed.store(true, Ordering::Relaxed);
+                                return vec![(len, Err(e))];
+                            }
+                        };
+                        let mut buffer = vec![0; HASH_BUFFER_SIZE];
+                        let mut results = Vec::new();
+                        while !failed.load(Ordering::Relaxed) {
+                            let i = next.fetch_add(1, Ordering::Relaxed);
+                            if i >= len {
+                                break;
+                            }
+                            let result = target.unpack_entry(&mut archive, i, &mut buffer);
+                            if result.is_err() {
+                                failed.store(true, Ordering::Relaxed);
+                            }
+                            results.push((i, result));
+                        }
+                        results
+                    })
+                })
+                .collect::<Vec<_>>();
+            workers
+                .into_iter()
+                .flat_map(|worker| {
+                    worker
+                        .join()
+                        .unwrap_or_else(|payload| panic::resume_unwind(payload))
+                })
+                .collect::<Vec<_>>()
+        });
+
+        results.sort_by_key(|(i, _)| *i);
+        let mut unpacked = Vec::new();
+        for (_, result) in results {
+            if let Some(name) = result? {
+                unpacked.push(name);
+            }
+        }
+        Ok(unpacked)
+    }
+    /// The paths of the files which sign `RECORD`.
+    fn signature_paths(&self) -> [String; 2] {
+        [
+            self.dist_info_path("RECORD.jws"),
+            self.dist_info_path("RECORD.p7s"),
+        ]
+    }
+}
+
+/// The `RECORD` row for each path.
+fn records_by_path(record_file: &RecordFile) -> HashMap<&str, &Record> {
+    record_file
+        .records
+        .iter()
+        .map(|record| (record.filename.as_str(), record))
+        .collect()
+}
+
+/// Where and how [`Wheel::unpack_matching`] extracts entries.
+struct UnpackTarget<'a> {
+    patterns: &'a GlobSet,
+    destination: &'a Path,
+    records: &'a HashMap<&'a str, &'a Record>,
+    signature_paths: [String; 2],
+    limits: &'a Limits,
+}
+
+impl UnpackTarget<'_> {
+    /// Extracts the `i`th entry of `archive` if it's a file matching the patterns, and
+    /// returns its archive path.
+    fn unpack_entry<R: Read + Seek>(
+        &self,
+        archive: &mut ZipArchive<R>,
+        i: usize,
+        buffer: &mut [u8],
+    ) -> Result<Option<String>, WheelError> {
+        let zip_file = archive.by_index(i)?;
+        if zip_file.is_dir() || !self.patterns.is_match(zip_file.name()) {
+            return Ok(None);
+        }
+        let name = zip_file.name().to_owned();
+        let Some(relative_path) = zip_file.enclosed_name().map(Path::to_path_buf) else {
+            return Err(ArchiveLayoutError::UnsafePath(name).into());
+        };
+        self.limits.check_size(&name, None, zip_file.size())?;
+        let record = self.records.get(name.as_str());
+        if record.is_none() && !self.signature_paths.contains(&name) {
+            return Err(VerifyError::UnrecordedFile(name).into());
+        }
+
+        let path = self.destination.join(relative_path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut reader = TeeReader {
+            reader: self.limits.take_entry(zip_file),
+            writer: File::create(&path)?,
+        };
+        if let Some(record) = record {
+            if let Some((algorithm, expected)) = expected_digest(record)? {
+                let (actual, size) = algorithm.hash_reader(&mut reader, buffer)?;
+                self.limits.check_size(&name, None, size)?;
+                check_record(record, &expected, &actual, size)?;
+                return Ok(Some(name));
+            }
+        }
+        drain_entry(self.limits, &name, &mut reader)?;
+        Ok(Some(name))
+    }
+}
+
+/// Reads the rest of an entry which has no digest to check, enforcing `limits` on its size.
+fn drain_entry(limits: &Limits, name: &str, reader: &mut impl Read) -> Result<(), WheelError> {
+    let size = io::copy(reader, &mut io::sink())?;
+    limits.check_size(name, None, size)?;
+    Ok(())
+}
+
+/// Writes everything read from `reader` to `writer`.
+struct TeeReader<R, W> {
+    reader: R,
+    writer: W,
+}
+
+impl<R: Read, W: Write> Read for TeeReader<R, W> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        self.writer.write_all(&buf[..read])?;
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use globset::Glob;
+    use globset::GlobSetBuilder;
+
+    use super::*;
+    use crate::temp_dir::TempDir;
+    use crate::Corruption;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    fn globs(patterns: &[&str]) -> GlobSet {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            builder.add(Glob::new(pattern).unwrap());
+        }
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_unpack_matching() -> Result<(), WheelError> {
+        let destination = TempDir::new("unpack-matching")?;
+
+        let mut wheel = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .file("demo/__init__.py", "hello world")
+            .file("demo/__init__.pyi", "hello world")
+            .file("demo/py.typed", "")
+            .open()?;
+        assert_eq!(
+            wheel.unpack_matching(&globs(&["*/py.typed", "*.pyi"]), &destination)?,
+            vec!["demo/__init__.pyi", "demo/py.typed"],
+        );
+        assert_eq!(
+            fs::read_to_string(destination.join("demo/__init__.pyi"))?,
+            "hello world",
+        );
+        assert!(!destination.join("demo/__init__.py").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unpack_matching_digest_mismatch() -> Result<(), WheelError> {
+        let destination = TempDir::new("unpack-matching-mismatch")?;
+
+        let mut wheel = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .file("demo/__init__.py", "hello world")
+            .corrupt(Corruption::FlipByte {
+                path: "demo/__init__.py".to_string(),
+                offset: 0,
+            })
+            .open()?;
+        assert!(matches!(
+            wheel.unpack_matching(&globs(&["demo/*"]), &destination),
+            Err(WheelError::VerifyError(VerifyError::DigestMismatch(path))) if path == "demo/__init__.py",
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unpack_matching_unrecorded() -> Result<(), WheelError> {
+        let destination = TempDir::new("unpack-matching-unrecorded")?;
+
+        let mut wheel = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .file("demo/__init__.py", "hello world")
+            .file("demo-1.0.dist-info/RECORD.jws", "{}")
+            .corrupt(Corruption::DropRecordRow("demo/__init__.py".to_string()))
+            .open()?;
+        assert_eq!(
+            wheel.unpack_matching(&globs(&["*/RECORD.jws"]), &destination)?,
+            vec!["demo-1.0.dist-info/RECORD.jws"],
+        );
+        assert!(matches!(
+            wheel.unpack_matching(&globs(&["demo/*"]), &destination),
+            Err(WheelError::VerifyError(VerifyError::UnrecordedFile(path))) if path == "demo/__init__.py",
+        ));
+        assert!(!destination.join("demo/__init__.py").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unpack_matching_parallel() -> Result<(), WheelError> {
+        let mut builder = WheelBuilder::new("demo-1.0-py3-none-any.whl")?;
+        for i in 0..20 {
+            builder = builder.file(&format!("demo/module{i}.py"), "hello world");
+        }
+        let bytes = builder
+            .file("demo/bad.py", "hello world")
+            .corrupt(Corruption::FlipByte {
+                path: "demo/bad.py".to_string(),
+                offset: 0,
+            })
+            .build()?
+            .into_inner();
+        let open_reader = || Ok(Cursor::new(bytes.as_slice()));
+        let destination = TempDir::new("unpack-matching-parallel")?;
+
+        let mut wheel = Wheel::open("demo-1.0-py3-none-any.whl", open_reader()?)?;
+        let unpacked = wheel.unpack_matching_parallel(
+            &globs(&["demo/module*.py"]),
+            &destination,
+            4,
+            open_reader,
+        )?;
+        assert_eq!(
+            unpacked,
+            (0..20)
+                .map(|i| format!("demo/module{i}.py"))
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            fs::read_to_string(destination.join("demo/module19.py"))?,
+            "hello world",
+        );
+
+        assert!(matches!(
+            wheel.unpack_matching_parallel(&globs(&["demo/*"]), &destination, 4, open_reader),
+            Err(WheelError::VerifyError(VerifyError::DigestMismatch(path))) if path == "demo/bad.py",
+        ));
+
+        assert!(matches!(
+            wheel.unpack_matching_parallel(&globs(&["demo/*"]), &destination, 4, || {
+                Err::<Cursor<&[u8]>, _>(io::Error::from(io::ErrorKind::NotFound))
+            }),
+            Err(WheelError::IOError(e)) if e.kind() == io::ErrorKind::NotFound,
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unpack_matching_unsafe_path() -> Result<(), WheelError> {
+        let destination = TempDir::new("unpack-matching-unsafe")?;
+
+        let mut wheel = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .file("../evil.py", "")
+            .open()?;
+        assert!(matches!(
+            wheel.unpack_matching(&globs(&["*.py"]), &destination),
+            Err(WheelError::ArchiveLayoutError(ArchiveLayoutError::UnsafePath(path))) if path == "../evil.py",
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use crate::WheelName;
+
+/// The hardware a wheel was built for, according to common naming conventions,
+/// see [`WheelName::variant`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Variant {
+    Cpu,
+    /// NVIDIA CUDA, and the CUDA version components the name gives, e.g. `[11, 8]` for `cu118`
+    /// or `[12]` for `cupy-cuda12x`.
+    Cuda(Vec<u32>),
+    /// AMD ROCm, and the ROCm version components the name gives, e.g. `[5, 6]` for `rocm5.6`,
+    /// or none for `pytorch-triton-rocm`.
+    Rocm(Vec<u32>),
+    /// A GPU build which doesn't say which accelerator it targets, e.g. `onnxruntime-gpu`.
+    Gpu,
+}
+
+impl WheelName {
+    /// Recognizes the hardware variant a wheel was built for, so resolvers targeting an
+    /// accelerator can filter candidates. Returns `None` for wheels which don't follow
+    /// any of these conventions.
+    ///
+    /// The local version label is checked first, as used by PyTorch, e.g. `+cu118`, `+rocm5.6`,
+    /// and `+cpu`. Otherwise the last component of the distribution name is checked, e.g.
+    /// `nvidia-cublas-cu12`, `cupy-cuda12x`, `tensorflow-cpu`, and `onnxruntime-gpu`.
+    pub fn variant(&self) -> Option<Variant> {
+        if let Some(local) = self.local_version() {
+            if let Some(variant) = local_version_variant(&local) {
+                return Some(variant);
+            }
+        }
+        let suffix = self.normalized_distribution();
+        let (_, suffix) = suffix.rsplit_once('-')?;
+        distribution_suffix_variant(suffix)
+    }
+}
+
+/// PyTorch-style local versions: `cpu`, `cu` followed by the CUDA version without its
+/// dot, and `rocm` followed by the dotted ROCm version.
+fn local_version_variant(local: &str) -> Option<Variant> {
+    if let Some(version) = local.strip_prefix("rocm") {
+        return Some(Variant::Rocm(parse_dotted(version)?));
+    }
+    let first = local.split('.').next().unwrap_or(local);
+    if first == "cpu" {
+        return Some(Variant::Cpu);
+    }
+    let digits = first.strip_prefix("cu")?;
+    if digits.len() < 2 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    // The last digit is the minor version, e.g. `cu92` is CUDA 9.2 and `cu118` is 11.8.
+    let (major, minor) = digits.split_at(digits.len() - 1);
+    Some(Variant::Cuda(vec![
+        major.parse().ok()?,
+        minor.parse().ok()?,
+    ]))
+}
+
+/// Distribution name suffixes, where CUDA versions only give the major version.
+fn distribution_suffix_variant(suffix: &str) -> Option<Variant> {
+    match suffix {
+        "cpu" => return Some(Variant::Cpu),
+        "gpu" => return Some(Variant::Gpu),
+        "rocm" => return Some(Variant::Rocm(Vec::new())),
+        _ => {}
+    }
+    let major = suffix
+        .strip_prefix("cuda")
+        .map(|version| version.strip_suffix('x').unwrap_or(version))
+        .or_else(|| suffix.strip_prefix("cu"))?;
+    Some(Variant::Cuda(vec![major.parse().ok()?]))
+}
+
+/// Parses a dotted version, e.g. `5.6`, into its components.
+fn parse_dotted(version: &str) -> Option<Vec<u32>> {
+    version
+        .split('.')
+        .map(|component| component.parse().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::WheelNameParseError;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_variant() -> Result<(), WheelNameParseError> {
+        for (filename, variant) in [
+            (
+                "torch-2.1.0+cu118-cp311-cp311-linux_x86_64.whl",
+                Some(Variant::Cuda(vec![11, 8])),
+            ),
+            (
+                "torch-1.7.0+cu92-cp38-cp38-linux_x86_64.whl",
+                Some(Variant::Cuda(vec![9, 2])),
+            ),
+            (
+                "torch-2.1.0+rocm5.6-cp311-cp311-linux_x86_64.whl",
+                Some(Variant::Rocm(vec![5, 6])),
+            ),
+            (
+                "torch-2.1.0+cpu.cxx11.abi-cp311-cp311-linux_x86_64.whl",
+                Some(Variant::Cpu),
+            ),
+            (
+                "nvidia_cublas_cu12-12.1.3.1-py3-none-manylinux1_x86_64.whl",
+                Some(Variant::Cuda(vec![12])),
+            ),
+            (
+                "cupy_cuda12x-13.0.0-cp311-cp311-manylinux2014_x86_64.whl",
+                Some(Variant::Cuda(vec![12])),
+            ),
+            (
+                "pytorch_triton_rocm-2.1.0-cp311-cp311-linux_x86_64.whl",
+                Some(Variant::Rocm(vec![])),
+            ),
+            (
+                "tensorflow_cpu-2.15.0-cp311-cp311-manylinux_2_17_x86_64.whl",
+                Some(Variant::Cpu),
+            ),
+            (
+                "onnxruntime_gpu-1.16.0-cp311-cp311-manylinux_2_28_x86_64.whl",
+                Some(Variant::Gpu),
+            ),
+            ("torch-2.1.0-cp311-cp311-manylinux1_x86_64.whl", None),
+            ("requests-2.31.0+custom-py3-none-any.whl", None),
+            ("curl_cffi-0.5.10-cp38-abi3-manylinux_2_17_x86_64.whl", None),
+        ] {
+            assert_eq!(
+                WheelName::from_str(filename)?.variant(),
+                variant,
+                "{filename}"
+            );
+        }
+        Ok(())
+    }
+}
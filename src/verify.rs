@@ -0,0 +1,270 @@
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::io::Seek;
+use std::str::FromStr;
+
+use crate::hash::decode_record_digest;
+use crate::hash::encode_hex;
+use crate::hash::HashAlgorithm;
+use crate::hash::HASH_BUFFER_SIZE;
+use crate::record_file::Record;
+use crate::Wheel;
+use crate::WheelError;
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Checks every file listed in `RECORD` against its recorded digest and size.
+    ///
+    /// Entries are streamed through a single large buffer, so verification
+    /// doesn't require holding any file from the archive in memory.
+    pub fn verify(&mut self) -> Result<(), WheelError> {
+        let record_file = self.record_file()?;
+        let mut buffer = vec![0; HASH_BUFFER_SIZE];
+        for record in &record_file.records {
+            self.verify_record(record, &mut buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Like [`Wheel::verify`], but checks at most `max_entries` files which `checkpoint`
+    /// doesn't already list, so that verifying a large wheel can be split across several
+    /// calls, e.g. in time-sliced background jobs.
+    ///
+    /// Returns whether every file has now been verified. Files are only skipped if their
+    /// digest in `RECORD` still matches the one in `checkpoint`, so a checkpoint can't
+    /// vouch for a file whose `RECORD` row has since changed.
+    pub fn verify_resumable(
+        &mut self,
+        checkpoint: &mut VerifyCheckpoint,
+        max_entries: usize,
+    ) -> Result<bool, WheelError> {
+        let record_file = self.record_file()?;
+        let mut buffer = vec![0; HASH_BUFFER_SIZE];
+        let mut remaining = max_entries;
+        for record in &record_file.records {
+            let digest = record
+                .digest
+                .as_ref()
+                .map(|digest| format!("{}={}", digest.method, digest.b64_digest))
+                .unwrap_or_default();
+            if checkpoint.verified.get(&record.filename) == Some(&digest) {
+                continue;
+            }
+            if remaining == 0 {
+                return Ok(false);
+            }
+            self.verify_record(record, &mut buffer)?;
+            checkpoint.verified.insert(record.filename.clone(), digest);
+            remaining -= 1;
+        }
+        Ok(true)
+    }
+
+    /// Hashes everything in `reader` and compares it against `expected_hex`,
+    /// leaving `reader` rewound to the start.
+    pub(crate) fn verify_artifact_hash(
+        reader: &mut R,
+        algorithm: HashAlgorithm,
+        expected_hex: &str,
+    ) -> Result<(), WheelError> {
+        reader.rewind()?;
+        let mut buffer = vec![0; HASH_BUFFER_SIZE];
+        let (actual, _) = algorithm.hash_reader(reader, &mut buffer)?;
+        reader.rewind()?;
+        let actual_hex = encode_hex(&actual);
+        if actual_hex != expected_hex {
+            return Err(VerifyError::HashMismatch(expected_hex.to_owned(), actual_hex).into());
+        }
+        Ok(())
+    }
+
+    fn verify_record(&mut self, record: &Record, buffer: &mut [u8]) -> Result<(), WheelError> {
+        let Some((algorithm, expected)) = expected_digest(record)? else {
+            return Ok(());
+        };
+
+        let zip_file = self.archive.by_name(&record.filename)?;
+        self.limits
+            .check_size(&record.filename, None, zip_file.size())?;
+        let mut zip_file = self.limits.take_entry(zip_file);
+        let (actual, size) = algorithm.hash_reader(&mut zip_file, buffer)?;
+        self.limits.check_size(&record.filename, None, size)?;
+        check_record(record, &expected, &actual, size)
+    }
+}
+
+/// The algorithm and decoded digest `record` expects, or `None` if it has no digest.
+pub(crate) fn expected_digest(
+    record: &Record,
+) -> Result<Option<(HashAlgorithm, Vec<u8>)>, WheelError> {
+    let Some(digest) = &record.digest else {
+        return Ok(None);
+    };
+    let algorithm =
+        HashAlgorithm::from_str(&digest.method).map_err(VerifyError::UnsupportedHashAlgorithm)?;
+    let Some(expected) = decode_record_digest(&digest.b64_digest) else {
+        return Err(VerifyError::MalformedDigest(record.filename.clone()).into());
+    };
+    Ok(Some((algorithm, expected)))
+}
+
+/// Compares a file's `actual` digest and `size` against what `record` lists.
+pub(crate) fn check_record(
+    record: &Record,
+    expected: &[u8],
+    actual: &[u8],
+    size: u64,
+) -> Result<(), WheelError> {
+    if actual != expected {
+        return Err(VerifyError::DigestMismatch(record.filename.clone()).into());
+    }
+    if let Some(file_size) = record.file_size {
+        if file_size as u64 != size {
+            return Err(
+                VerifyError::SizeMismatch(record.filename.clone(), file_size as u64, size).into(),
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Progress saved by [`Wheel::verify_resumable`], which can be persisted between calls.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
+pub struct VerifyCheckpoint {
+    /// The paths of files which have been verified, mapped to their digests in `RECORD`,
+    /// e.g. `sha256=...`. Files without a digest map to an empty string.
+    pub verified: BTreeMap<String, String>,
+}
+
+#[derive(thiserror::Error, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum VerifyError {
+    #[error("unsupported hash algorithm")]
+    UnsupportedHashAlgorithm(String),
+
+    #[error("malformed digest")]
+    MalformedDigest(String),
+
+    #[error("file contents do not match the recorded digest")]
+    DigestMismatch(String),
+
+    #[error("file size does not match the recorded size")]
+    SizeMismatch(String, u64, u64),
+
+    #[error("wheel does not match the expected hash")]
+    HashMismatch(String, String),
+
+    #[error("file listed in RECORD is not in the archive")]
+    MissingFile(String),
+
+    #[error("file in the archive is not listed in RECORD")]
+    UnrecordedFile(String),
+}
+
+impl VerifyError {
+    /// The archive path of the file which failed verification, if any.
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            VerifyError::UnsupportedHashAlgorithm(_) | VerifyError::HashMismatch(_, _) => None,
+            VerifyError::MalformedDigest(path)
+            | VerifyError::DigestMismatch(path)
+            | VerifyError::SizeMismatch(path, _, _)
+            | VerifyError::MissingFile(path)
+            | VerifyError::UnrecordedFile(path) => Some(path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Corruption;
+    use crate::LimitError;
+    use crate::Limits;
+    use crate::WheelBuilder;
+
+    fn builder() -> Result<WheelBuilder, WheelError> {
+        Ok(WheelBuilder::new("demo-1.0-py3-none-any.whl")?.file("demo/__init__.py", "hello world"))
+    }
+
+    #[test]
+    fn test_verify_ok() -> Result<(), WheelError> {
+        builder()?.open()?.verify()
+    }
+
+    #[test]
+    fn test_verify_digest_mismatch() -> Result<(), WheelError> {
+        let mut wheel = builder()?
+            .corrupt(Corruption::FlipByte {
+                path: "demo/__init__.py".to_string(),
+                offset: 0,
+            })
+            .open()?;
+        assert!(matches!(
+            wheel.verify(),
+            Err(WheelError::VerifyError(VerifyError::DigestMismatch(path))) if path == "demo/__init__.py",
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_entry_too_large() -> Result<(), WheelError> {
+        let mut wheel = builder()?.open()?.with_limits(Limits {
+            max_entry_size: Some(1000),
+            ..Limits::default()
+        });
+        wheel.verify()?;
+
+        wheel = wheel.with_limits(Limits {
+            max_entry_size: Some(10),
+            ..Limits::default()
+        });
+        assert!(matches!(
+            wheel.verify(),
+            Err(WheelError::LimitError(LimitError::EntryTooLarge(path, 10))) if path == "demo-1.0.dist-info/RECORD",
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_resumable() -> Result<(), WheelError> {
+        let mut wheel = builder()?.file("demo/other.py", "hello world").open()?;
+        let mut checkpoint = VerifyCheckpoint::default();
+        assert!(!wheel.verify_resumable(&mut checkpoint, 2)?);
+        assert_eq!(
+            checkpoint.verified.keys().collect::<Vec<_>>(),
+            ["demo/__init__.py", "demo/other.py"],
+        );
+        assert!(wheel.verify_resumable(&mut checkpoint, 3)?);
+        assert_eq!(checkpoint.verified["demo-1.0.dist-info/RECORD"], "");
+
+        // A checkpoint entry whose digest no longer matches RECORD is verified again.
+        checkpoint
+            .verified
+            .insert("demo/other.py".to_string(), "sha256=stale".to_string());
+        assert!(!wheel.verify_resumable(&mut checkpoint, 0)?);
+        assert!(wheel.verify_resumable(&mut checkpoint, 1)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_expected_hash() -> Result<(), WheelError> {
+        let mut reader = builder()?.build()?;
+        let (digest, _) = HashAlgorithm::Sha256.hash_reader(&mut reader, &mut [0; 64])?;
+        let hex_digest = encode_hex(&digest).to_ascii_uppercase();
+
+        Wheel::options()
+            .expected_hash(HashAlgorithm::Sha256, &hex_digest)
+            .open("demo-1.0-py3-none-any.whl", reader.clone())?;
+
+        assert!(matches!(
+            Wheel::options()
+                .expected_hash(HashAlgorithm::Sha256, "00")
+                .open("demo-1.0-py3-none-any.whl", reader),
+            Err(WheelError::VerifyError(VerifyError::HashMismatch(expected, actual)))
+                if expected == "00" && actual == hex_digest.to_ascii_lowercase(),
+        ));
+        Ok(())
+    }
+}
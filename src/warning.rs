@@ -0,0 +1,133 @@
+use std::fmt;
+
+/// A deviation from the spec which was tolerated rather than treated as an error.
+///
+/// Operations on a [`Wheel`](crate::Wheel) which tolerate deviations record them,
+/// see [`Wheel::warnings`](crate::Wheel::warnings).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Warning {
+    /// `Summary` spanned multiple lines, and was truncated to its first line.
+    MultiLineSummary(String),
+
+    /// A URL-valued field, and the value which isn't a valid URL.
+    InvalidUrl(&'static str, String),
+
+    /// Both `License` and `License-Expression` are set.
+    LicenseWithLicenseExpression,
+
+    /// `License-Expression` is set alongside these `License ::` classifiers.
+    LicenseClassifiersWithLicenseExpression(Vec<String>),
+
+    /// The `License` field names a license which none of these `License ::` classifiers match.
+    LicenseClassifierMismatch(String, Vec<String>),
+
+    /// A `License-File` entry which names no file in the archive.
+    MissingLicenseFile(String),
+
+    /// A blank or `#` comment line in `RECORD` which was skipped, and its line number.
+    IgnoredRecordLine(usize, String),
+
+    /// The `.dist-info` directory the file name implies, and the differently spelled one
+    /// which was read instead.
+    NameMismatch(String, String),
+
+    /// A `console_scripts` or `gui_scripts` entry point, and its module which isn't in `RECORD`.
+    MissingEntryPointModule(String, String),
+
+    /// `METADATA` wasn't plain UTF-8 as declared: the charset its `Content-Type` or
+    /// `Description-Content-Type` declares, if any, and the charset it was decoded as.
+    MetadataCharset(Option<String>, &'static str),
+
+    /// A field, the `Metadata-Version` which introduced it, and the older `Metadata-Version`
+    /// which `METADATA` declares.
+    FieldRequiresMetadataVersion(&'static str, String, String),
+
+    /// A `Dynamic` value naming a field which may not be dynamic.
+    InvalidDynamic(String),
+
+    /// A `License-Expression` which isn't a valid SPDX license expression.
+    InvalidLicenseExpression(String),
+
+    /// A `Requires-Dist` which isn't a valid requirement, and was kept in
+    /// [`MetadataFile::extra_fields`](crate::MetadataFile::extra_fields) instead.
+    InvalidRequirement(String),
+
+    /// A `Requires-Python` which isn't a valid version specifier set, and was kept in
+    /// [`MetadataFile::extra_fields`](crate::MetadataFile::extra_fields) instead.
+    InvalidRequiresPython(String),
+}
+
+impl Warning {
+    /// A stable, machine-readable identifier for the kind of warning, which is also the
+    /// name of its [`DiagnosticCode`](crate::DiagnosticCode).
+    pub fn code(&self) -> &'static str {
+        self.diagnostic_code().name()
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::MultiLineSummary(_) => write!(f, "Summary spans multiple lines"),
+            Warning::InvalidUrl(field, value) => write!(f, "{field} is not a valid URL: {value}"),
+            Warning::LicenseWithLicenseExpression => {
+                write!(f, "both License and License-Expression are set")
+            }
+            Warning::LicenseClassifiersWithLicenseExpression(classifiers) => write!(
+                f,
+                "License-Expression is set alongside license classifiers: {}",
+                classifiers.join(", ")
+            ),
+            Warning::LicenseClassifierMismatch(license, classifiers) => write!(
+                f,
+                "License {license:?} matches none of the license classifiers: {}",
+                classifiers.join(", ")
+            ),
+            Warning::MissingLicenseFile(path) => {
+                write!(f, "License-File {path:?} is not in the archive")
+            }
+            Warning::IgnoredRecordLine(line_number, line) => {
+                write!(f, "RECORD line {line_number} is not a row: {line:?}")
+            }
+            Warning::NameMismatch(expected, actual) => {
+                write!(f, "expected {expected}, but the archive has {actual}")
+            }
+            Warning::MissingEntryPointModule(name, module) => {
+                write!(
+                    f,
+                    "entry point {name} refers to {module}, which is not in RECORD"
+                )
+            }
+            Warning::MetadataCharset(Some(declared), charset) => {
+                write!(
+                    f,
+                    "METADATA declares charset {declared}, and was decoded as {charset}"
+                )
+            }
+            Warning::MetadataCharset(None, charset) => {
+                write!(
+                    f,
+                    "METADATA is not valid UTF-8, and was decoded as {charset}"
+                )
+            }
+            Warning::FieldRequiresMetadataVersion(field, required, declared) => write!(
+                f,
+                "{field} requires Metadata-Version {required}, but METADATA declares {declared}"
+            ),
+            Warning::InvalidDynamic(field) => write!(f, "{field} may not be Dynamic"),
+            Warning::InvalidLicenseExpression(expression) => {
+                write!(
+                    f,
+                    "License-Expression is not a valid SPDX expression: {expression}"
+                )
+            }
+            Warning::InvalidRequirement(value) => {
+                write!(f, "Requires-Dist is not a valid requirement: {value}")
+            }
+            Warning::InvalidRequiresPython(value) => {
+                write!(f, "Requires-Python is not a valid specifier set: {value}")
+            }
+        }
+    }
+}
@@ -1,12 +1,18 @@
+use std::collections::BTreeSet;
 use std::str;
 use std::str::FromStr;
 
+use crate::text::normalize_text;
+
 /// Used for parsing `... .dist-info/WHEEL` files.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct WheelFile {
     pub wheel_version: String,
     pub generator: String,
     pub root_is_purelib: bool,
+    /// The `Tag` lines in file order, without duplicates. See [`WheelFile::sorted_tags`]
+    /// to compare the tags of wheels from generators which order them differently.
     pub tags: Vec<String>,
     pub build: Option<usize>,
 }
@@ -23,7 +29,7 @@ impl FromStr for WheelFile {
         let mut tags = Vec::new();
         let mut build = None;
 
-        for line in s.lines() {
+        for line in normalize_text(s).lines() {
             if let Some(line) = line.strip_prefix("Wheel-Version: ") {
                 if wheel_version.is_some() {
                     return Err(DuplicateField("wheel_version"));
@@ -49,7 +55,9 @@ impl FromStr for WheelFile {
             }
 
             if let Some(line) = line.strip_prefix("Tag: ") {
-                tags.push(line.to_owned());
+                if !tags.iter().any(|tag| tag == line) {
+                    tags.push(line.to_owned());
+                }
             }
 
             if let Some(line) = line.strip_prefix("Build: ") {
@@ -73,7 +81,15 @@ impl FromStr for WheelFile {
     }
 }
 
+impl WheelFile {
+    /// The `Tag` lines as a sorted set, independent of the order they were written in.
+    pub fn sorted_tags(&self) -> BTreeSet<&str> {
+        self.tags.iter().map(String::as_str).collect()
+    }
+}
+
 #[derive(thiserror::Error, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum WheelFileParseError {
     #[error("there is at least one duplicate field")]
     DuplicateField(&'static str),
@@ -85,6 +101,17 @@ pub enum WheelFileParseError {
     MissingField(&'static str),
 }
 
+impl WheelFileParseError {
+    /// The name of the field which failed to parse.
+    pub fn field(&self) -> &'static str {
+        match self {
+            WheelFileParseError::DuplicateField(field)
+            | WheelFileParseError::InvalidFieldValue(field, _)
+            | WheelFileParseError::MissingField(field) => field,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -107,4 +134,36 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_duplicate_tags() -> Result<(), WheelFileParseError> {
+        let wheel_file = WheelFile::from_str(concat!(
+            "Wheel-Version: 1.0\n",
+            "Generator: demo\n",
+            "Root-Is-Purelib: true\n",
+            "Tag: py3-none-any\n",
+            "Tag: py2-none-any\n",
+            "Tag: py3-none-any\n",
+        ))?;
+        assert_eq!(wheel_file.tags, ["py3-none-any", "py2-none-any"]);
+        assert_eq!(
+            wheel_file.sorted_tags().into_iter().collect::<Vec<_>>(),
+            ["py2-none-any", "py3-none-any"],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_bom_crlf() -> Result<(), WheelFileParseError> {
+        let wheel_file_contents = fs::read_to_string("fixtures/simple_WHEEL.txt").unwrap();
+        for line_ending in ["\r\n", "\r"] {
+            let windows_contents =
+                format!("\u{feff}{}", wheel_file_contents.replace('\n', line_ending));
+            assert_eq!(
+                WheelFile::from_str(&windows_contents)?,
+                WheelFile::from_str(&wheel_file_contents)?,
+            );
+        }
+        Ok(())
+    }
 }
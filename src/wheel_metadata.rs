@@ -0,0 +1,107 @@
+use std::io::Read;
+use std::io::Seek;
+
+use pep440_rs::Version;
+
+use crate::MetadataFile;
+use crate::MetadataFileParseError;
+use crate::Wheel;
+use crate::WheelError;
+
+/// A wheel's metadata, which falls back to what its file name says when `METADATA` can't be
+/// parsed, see [`Wheel::wheel_metadata`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum WheelMetadata {
+    /// `METADATA` was parsed.
+    Full(Box<MetadataFile>),
+    /// `METADATA` couldn't be parsed.
+    Partial {
+        /// The distribution name from the file name.
+        name: String,
+        /// The version from the file name.
+        version: Version,
+        /// The contents of `METADATA`.
+        raw: Vec<u8>,
+        /// Why `METADATA` couldn't be parsed.
+        error: MetadataFileParseError,
+    },
+}
+
+impl WheelMetadata {
+    /// The distribution name, from `METADATA` if it was parsed, or else the file name.
+    pub fn name(&self) -> &str {
+        match self {
+            WheelMetadata::Full(metadata_file) => &metadata_file.name,
+            WheelMetadata::Partial { name, .. } => name,
+        }
+    }
+
+    /// The parsed `METADATA`, unless it couldn't be parsed.
+    pub fn metadata_file(&self) -> Option<&MetadataFile> {
+        match self {
+            WheelMetadata::Full(metadata_file) => Some(metadata_file),
+            WheelMetadata::Partial { .. } => None,
+        }
+    }
+
+    pub fn is_partial(&self) -> bool {
+        matches!(self, WheelMetadata::Partial { .. })
+    }
+}
+
+impl<R: Read + Seek> Wheel<R> {
+    /// Like [`Wheel::metadata_file`], but when `METADATA` can't be decoded, e.g. because it
+    /// isn't valid UTF-8 in strict mode, or can't be parsed, returns [`WheelMetadata::Partial`]
+    /// with the name and version from the file name and the raw contents instead of failing,
+    /// so that indexers can still catalogue the wheel. Failing to read `METADATA` at all, e.g.
+    /// because it's missing or exceeds the configured limits, is still an error.
+    pub fn wheel_metadata(&mut self) -> Result<WheelMetadata, WheelError> {
+        let raw = self.metadata_bytes()?;
+        let parsed = self
+            .decode_metadata(&raw)
+            .and_then(|contents| self.parse_metadata_file(&contents));
+        Ok(match parsed {
+            Ok(metadata_file) => WheelMetadata::Full(Box::new(metadata_file)),
+            Err(error) => WheelMetadata::Partial {
+                name: self.name.distribution.clone(),
+                version: self.name.version.clone(),
+                raw,
+                error,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_wheel_metadata() -> Result<(), WheelError> {
+        let mut wheel = WheelBuilder::new("demo-1.0-py3-none-any.whl")?.open()?;
+        let metadata = wheel.wheel_metadata()?;
+        assert!(!metadata.is_partial());
+        assert_eq!(metadata.name(), "demo");
+
+        let mut wheel = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .metadata("Summary", "A demo.\nNot a header")
+            .open()?;
+        let metadata = wheel.wheel_metadata()?;
+        assert_eq!(metadata.name(), "demo");
+        assert!(metadata.metadata_file().is_none());
+        assert!(matches!(
+            metadata,
+            WheelMetadata::Partial {
+                version,
+                raw,
+                error: MetadataFileParseError::MalformedHeader(_),
+                ..
+            } if version.to_string() == "1.0" && raw.starts_with(b"Metadata-Version: 2.1\n"),
+        ));
+        Ok(())
+    }
+}
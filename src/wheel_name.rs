@@ -1,3 +1,4 @@
+use std::fmt;
 use std::str::FromStr;
 
 use lazy_static::lazy_static;
@@ -5,13 +6,16 @@ use pep440_rs::Version;
 use regex::Regex;
 
 lazy_static! {
-    static ref NAME_RE: Regex = Regex::new(r#"^[\w\d._]*$"#).unwrap();
-    static ref BUILD_TAG_RE: Regex = Regex::new(r#"^(?P<number>\d)+(?P<remainder>.*)$"#).unwrap();
+    static ref NAME_SEPARATOR_RE: Regex = Regex::new(r#"[-_.]+"#).unwrap();
+    static ref BUILD_TAG_RE: Regex = Regex::new(r#"^(?P<number>\d+)(?P<remainder>.*)$"#).unwrap();
 }
 
 /// Used for parsing `*.whl` file names.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct WheelName {
+    /// The normalized distribution name, e.g. `charset-normalizer`, or the name exactly as it
+    /// appeared in the file name if parsed with [`NameCanonicalization::Preserve`].
     pub distribution: String,
     pub version: Version,
     pub build_tag: Option<BuildTag>,
@@ -20,47 +24,485 @@ pub struct WheelName {
     pub platform_tag: String,
 }
 
+impl WheelName {
+    /// Builds a wheel name from its components, validating each one. The distribution name
+    /// may be written as a project name, e.g. `charset-normalizer`, and is normalized.
+    pub fn new(
+        distribution: &str,
+        version: Version,
+        python_tag: &str,
+        abi_tag: &str,
+        platform_tag: &str,
+    ) -> Result<Self, WheelNameParseError> {
+        validate_project_name(distribution)?;
+        for tag in [python_tag, abi_tag, platform_tag] {
+            validate_tag(tag)?;
+        }
+        Ok(Self {
+            distribution: normalize_distribution_name(distribution),
+            version,
+            build_tag: None,
+            python_tag: python_tag.to_owned(),
+            abi_tag: abi_tag.to_owned(),
+            platform_tag: platform_tag.to_owned(),
+        })
+    }
+
+    /// The distribution name as it appears in file names, e.g. `charset_normalizer`.
+    pub fn escaped_distribution(&self) -> String {
+        escape_distribution_name(&self.distribution)
+    }
+
+    /// The normalized distribution name, e.g. `charset-normalizer`, even if the name was
+    /// parsed with [`NameCanonicalization::Preserve`].
+    pub fn normalized_distribution(&self) -> String {
+        normalize_distribution_name(&self.distribution)
+    }
+
+    /// The local version label, e.g. `cu118` for `2.1.0+cu118`, as found in PyTorch-style wheels.
+    pub fn local_version(&self) -> Option<String> {
+        let version = self.version.to_string();
+        let (_, local) = version.split_once('+')?;
+        Some(local.to_owned())
+    }
+
+    /// The version without its local version label, e.g. `2.1.0` for `2.1.0+cu118`.
+    pub fn public_version(&self) -> String {
+        let version = self.version.to_string();
+        match version.split_once('+') {
+            Some((public, _)) => public.to_owned(),
+            None => version,
+        }
+    }
+
+    /// Replaces the distribution name, which is validated and normalized as in
+    /// [`WheelName::new`].
+    pub fn with_distribution(mut self, distribution: &str) -> Result<Self, WheelNameParseError> {
+        validate_project_name(distribution)?;
+        self.distribution = normalize_distribution_name(distribution);
+        Ok(self)
+    }
+
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn with_build_tag(
+        mut self,
+        build_tag: Option<BuildTag>,
+    ) -> Result<Self, WheelNameParseError> {
+        if let Some(build_tag) = &build_tag {
+            if build_tag
+                .remainder
+                .as_deref()
+                .is_some_and(|remainder| remainder.contains('-'))
+            {
+                return Err(WheelNameParseError::InvalidBuildTag(build_tag.to_string()));
+            }
+        }
+        self.build_tag = build_tag;
+        Ok(self)
+    }
+
+    pub fn with_tags(
+        mut self,
+        python_tag: &str,
+        abi_tag: &str,
+        platform_tag: &str,
+    ) -> Result<Self, WheelNameParseError> {
+        for tag in [python_tag, abi_tag, platform_tag] {
+            validate_tag(tag)?;
+        }
+        self.python_tag = python_tag.to_owned();
+        self.abi_tag = abi_tag.to_owned();
+        self.platform_tag = platform_tag.to_owned();
+        Ok(self)
+    }
+
+    /// Checks whether `s` is a valid wheel file name without building a [`WheelName`].
+    /// This is cheaper than [`WheelName::from_str`] when only validity matters.
+    pub fn validate(s: &str) -> Result<(), WheelNameParseError> {
+        let parts = WheelNameParts::split(s, NameValidation::Legacy)?;
+        if let Err(reason) = Version::from_str(parts.version) {
+            return Err(WheelNameParseError::InvalidVersion(reason));
+        }
+        if let Some(build_tag) = parts.build_tag {
+            if !BUILD_TAG_RE.is_match(build_tag) {
+                return Err(WheelNameParseError::InvalidBuildTag(build_tag.to_owned()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a wheel file name, checking its distribution name according to `validation`.
+    /// [`WheelName::from_str`] uses [`NameValidation::Legacy`].
+    pub fn parse(s: &str, validation: NameValidation) -> Result<Self, WheelNameParseError> {
+        Self::parse_with(s, validation, NameCanonicalization::Normalize)
+    }
+
+    /// Like [`WheelName::parse`], but `canonicalization` controls whether the distribution
+    /// name is normalized, for consumers which must reproduce the original file name exactly.
+    pub fn parse_with(
+        s: &str,
+        validation: NameValidation,
+        canonicalization: NameCanonicalization,
+    ) -> Result<Self, WheelNameParseError> {
+        let parts = WheelNameParts::split(s, validation)?;
+
+        let distribution = match canonicalization {
+            NameCanonicalization::Normalize => normalize_distribution_name(parts.distribution),
+            NameCanonicalization::Preserve => parts.distribution.to_owned(),
+        };
+
+        let version = match Version::from_str(parts.version) {
+            Err(reason) => return Err(WheelNameParseError::InvalidVersion(reason)),
+            Ok(version) => version,
+        };
+
+        let build_tag = match parts.build_tag {
+            Some(build_tag) => Some(BuildTag::from_str(build_tag)?),
+            None => None,
+        };
+
+        Ok(Self {
+            distribution,
+            version,
+            build_tag,
+            python_tag: parts.python_tag.to_owned(),
+            abi_tag: parts.abi_tag.to_owned(),
+            platform_tag: parts.platform_tag.to_owned(),
+        })
+    }
+
+    /// Parses a wheel file name, repairing malformations which are common in older
+    /// artifacts on mirrors: distribution names or versions containing `-`, distribution
+    /// names with characters the spec forbids, and local versions whose `+` was
+    /// percent-encoded as `%2B` by a URL, e.g. `torch-2.1.0%2Bcu118-...`.
+    ///
+    /// Well-formed names are parsed as by [`WheelName::from_str`], with no repairs.
+    /// Otherwise the last three parts are taken as tags, and the remaining parts are
+    /// split into the shortest distribution name followed by a valid version and
+    /// an optional build tag.
+    pub fn parse_lenient(s: &str) -> Result<(Self, Vec<NameRepair>), WheelNameParseError> {
+        let error = match WheelName::from_str(s) {
+            Ok(name) => return Ok((name, Vec::new())),
+            Err(
+                error @ (WheelNameParseError::PartMismatch
+                | WheelNameParseError::InvalidDistributionName(_)
+                | WheelNameParseError::InvalidDistributionNameCharacter(..)
+                | WheelNameParseError::InvalidVersion(_)
+                | WheelNameParseError::InvalidBuildTag(_)),
+            ) => error,
+            Err(error) => return Err(error),
+        };
+
+        let decoded = s.replace("%2B", "+").replace("%2b", "+");
+        if decoded != s {
+            if let Ok(name) = WheelName::from_str(&decoded) {
+                let repair = NameRepair::EncodedLocalVersion(name.version.to_string());
+                return Ok((name, vec![repair]));
+            }
+        }
+
+        let Some(filename) = s.strip_suffix(".whl") else {
+            return Err(WheelNameParseError::NotAWheel);
+        };
+        let parts: Vec<&str> = filename.split('-').collect();
+        if parts.len() < 5 {
+            return Err(error);
+        }
+        let (middle, tags) = parts.split_at(parts.len() - 3);
+        for tag in tags {
+            validate_tag(tag)?;
+        }
+
+        for distribution_len in 1..middle.len() {
+            let distribution = middle[..distribution_len].join("-");
+            if distribution.is_empty() {
+                continue;
+            }
+            // Prefer reading a trailing part as a build tag over folding it into the version.
+            for build_tag_len in [1, 0] {
+                let Some(version_end) = middle.len().checked_sub(build_tag_len) else {
+                    continue;
+                };
+                if version_end <= distribution_len {
+                    continue;
+                }
+                let build_tag = match build_tag_len {
+                    0 => None,
+                    _ => match BuildTag::from_str(middle[version_end]) {
+                        Ok(build_tag) => Some(build_tag),
+                        Err(_) => continue,
+                    },
+                };
+                let version = middle[distribution_len..version_end].join("-");
+                let Ok(parsed_version) = Version::from_str(&version) else {
+                    continue;
+                };
+
+                let mut repairs = Vec::new();
+                if distribution_len > 1 {
+                    repairs.push(NameRepair::JoinedDistribution(distribution.clone()));
+                } else if validate_distribution(&distribution, NameValidation::Legacy).is_err() {
+                    repairs.push(NameRepair::InvalidDistributionName(distribution.clone()));
+                }
+                if version_end - distribution_len > 1 {
+                    repairs.push(NameRepair::JoinedVersion(version));
+                }
+                let name = WheelName {
+                    distribution: normalize_distribution_name(&distribution),
+                    version: parsed_version,
+                    build_tag,
+                    python_tag: tags[0].to_owned(),
+                    abi_tag: tags[1].to_owned(),
+                    platform_tag: tags[2].to_owned(),
+                };
+                return Ok((name, repairs));
+            }
+        }
+        Err(error)
+    }
+}
+
+/// How [`WheelName::parse_lenient`] repaired a malformed file name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum NameRepair {
+    /// Several `-`-separated parts were joined into this distribution name.
+    JoinedDistribution(String),
+    /// Several `-`-separated parts were joined into this version.
+    JoinedVersion(String),
+    /// The distribution name contains characters the spec forbids, and was normalized anyway.
+    InvalidDistributionName(String),
+    /// The `+` before this version's local version label was decoded from `%2B`.
+    EncodedLocalVersion(String),
+}
+
 impl FromStr for WheelName {
     type Err = WheelNameParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let Some(filename) = s.strip_suffix(".whl") else {
-	    return Err(WheelNameParseError::NotAWheel)
-	};
+        WheelName::parse(s, NameValidation::Legacy)
+    }
+}
 
-        let parts = filename.split('-').collect::<Vec<&str>>();
-        if parts.len() != 5 && parts.len() != 6 {
-            return Err(WheelNameParseError::PartMismatch);
+/// How strictly distribution names are checked when parsing a [`WheelName`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum NameValidation {
+    /// Only names PyPI accepts: ASCII letters and digits, separated by `.` or `_`,
+    /// starting and ending with a letter or digit.
+    Strict,
+    /// Any Unicode letters or digits, `.` and `_` in any position, as found in older wheels.
+    #[default]
+    Legacy,
+}
+
+/// Whether [`WheelName::parse_with`] normalizes the distribution name.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum NameCanonicalization {
+    /// Lowercase the name and replace runs of `-`, `_`, and `.` with `-`, e.g. `Foo_Bar`
+    /// becomes `foo-bar`.
+    #[default]
+    Normalize,
+    /// Keep the name exactly as it appears in the file name, e.g. `Foo_Bar`. Names parsed this
+    /// way only compare equal to names with the same spelling.
+    Preserve,
+}
+
+/// Parses many wheel file names, collecting every failure instead of stopping at the first one.
+/// Useful when scanning directories or ingesting index listings.
+pub fn parse_wheel_names<I>(filenames: I) -> (Vec<WheelName>, Vec<WheelNameFailure>)
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut names = Vec::new();
+    let mut failures = Vec::new();
+    for (index, filename) in filenames.into_iter().enumerate() {
+        let filename = filename.as_ref();
+        match WheelName::from_str(filename) {
+            Ok(name) => names.push(name),
+            Err(reason) => failures.push(WheelNameFailure {
+                index,
+                filename: filename.to_owned(),
+                reason,
+            }),
         }
+    }
+    (names, failures)
+}
 
-        let distribution = parts[0].to_owned();
-        if distribution.contains("__") || !NAME_RE.is_match(&distribution) {
-            return Err(WheelNameParseError::InvalidDistributionName(distribution));
+/// A file name which [`parse_wheel_names`] failed to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WheelNameFailure {
+    /// Position of the file name in the input.
+    pub index: usize,
+    pub filename: String,
+    pub reason: WheelNameParseError,
+}
+
+/// Formats the name as a wheel file name, e.g. `charset_normalizer-3.1.0-py3-none-any.whl`.
+/// A distribution name parsed with [`NameCanonicalization::Preserve`] is written as-is.
+impl fmt::Display for WheelName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.distribution == self.normalized_distribution() {
+            write!(f, "{}", self.escaped_distribution())?;
+        } else {
+            write!(f, "{}", self.distribution)?;
+        }
+        write!(f, "-{}", self.version)?;
+        if let Some(build_tag) = &self.build_tag {
+            write!(f, "-{build_tag}")?;
         }
-        let distribution = distribution.to_lowercase().replace(['_', '.'], "-");
+        write!(
+            f,
+            "-{}-{}-{}.whl",
+            self.python_tag, self.abi_tag, self.platform_tag
+        )
+    }
+}
 
-        let version = match Version::from_str(parts[1]) {
-            Err(reason) => return Err(WheelNameParseError::InvalidVersion(reason)),
-            Ok(version) => version,
+fn validate_distribution(
+    distribution: &str,
+    validation: NameValidation,
+) -> Result<(), WheelNameParseError> {
+    if distribution.is_empty() {
+        return Err(WheelNameParseError::InvalidDistributionName(
+            distribution.to_owned(),
+        ));
+    }
+    for (position, character) in distribution.char_indices() {
+        let is_separator = character == '.' || character == '_';
+        let allowed = match validation {
+            NameValidation::Strict => {
+                character.is_ascii_alphanumeric()
+                    || (is_separator && position != 0 && position + 1 != distribution.len())
+            }
+            NameValidation::Legacy => character.is_alphanumeric() || is_separator,
+        };
+        if !allowed {
+            return Err(WheelNameParseError::InvalidDistributionNameCharacter(
+                distribution.to_owned(),
+                character,
+                position,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks a project name as users write it, which unlike a distribution name in a file name
+/// may use `-` as well as `_` and `.` to separate words, per
+/// [PEP 503](https://peps.python.org/pep-0503/#normalized-names).
+fn validate_project_name(distribution: &str) -> Result<(), WheelNameParseError> {
+    if distribution.is_empty() {
+        return Err(WheelNameParseError::InvalidDistributionName(
+            distribution.to_owned(),
+        ));
+    }
+    if let Some((position, character)) = distribution
+        .char_indices()
+        .find(|&(_, c)| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.')))
+    {
+        return Err(WheelNameParseError::InvalidDistributionNameCharacter(
+            distribution.to_owned(),
+            character,
+            position,
+        ));
+    }
+    Ok(())
+}
+
+/// Normalizes a distribution name per [PEP 503](https://peps.python.org/pep-0503/#normalized-names):
+/// lowercased, with runs of `-`, `_`, and `.` replaced by a single `-`.
+/// This is the canonical form stored in [`WheelName::distribution`].
+pub fn normalize_distribution_name(name: &str) -> String {
+    NAME_SEPARATOR_RE
+        .replace_all(&name.to_lowercase(), "-")
+        .into_owned()
+}
+
+/// Escapes a distribution name for use in wheel and dist-info file names:
+/// normalized, with `-` replaced by `_`.
+pub fn escape_distribution_name(name: &str) -> String {
+    NAME_SEPARATOR_RE
+        .replace_all(&name.to_lowercase(), "_")
+        .into_owned()
+}
+
+/// Checks a (possibly compressed) compatibility tag: one or more `.`-separated,
+/// non-empty components of ASCII alphanumerics and underscores.
+pub(crate) fn validate_tag(tag: &str) -> Result<(), WheelNameParseError> {
+    if tag.split('.').any(str::is_empty) {
+        return Err(WheelNameParseError::InvalidTag(tag.to_owned()));
+    }
+    if let Some((offset, c)) = tag
+        .char_indices()
+        .find(|&(_, c)| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+    {
+        return Err(WheelNameParseError::InvalidTagCharacter(
+            tag.to_owned(),
+            c,
+            offset,
+        ));
+    }
+    Ok(())
+}
+
+/// The `-`-separated components of a wheel file name, borrowed from the file name.
+struct WheelNameParts<'a> {
+    distribution: &'a str,
+    version: &'a str,
+    build_tag: Option<&'a str>,
+    python_tag: &'a str,
+    abi_tag: &'a str,
+    platform_tag: &'a str,
+}
+
+impl<'a> WheelNameParts<'a> {
+    /// Splits a file name into its components, validating the distribution name along the way.
+    fn split(s: &'a str, validation: NameValidation) -> Result<Self, WheelNameParseError> {
+        let Some(filename) = s.strip_suffix(".whl") else {
+            return Err(WheelNameParseError::NotAWheel);
         };
 
-        let (build_tag, index_offset) = if parts.len() == 6 {
-            let build_tag = BuildTag::from_str(parts[2])?;
-            (Some(build_tag), 1)
+        let mut parts = [""; 6];
+        let mut len = 0;
+        for part in filename.split('-') {
+            if len == parts.len() {
+                return Err(WheelNameParseError::PartMismatch);
+            }
+            parts[len] = part;
+            len += 1;
+        }
+        if len != 5 && len != 6 {
+            return Err(WheelNameParseError::PartMismatch);
+        }
+
+        let distribution = parts[0];
+        validate_distribution(distribution, validation)?;
+
+        let (build_tag, index_offset) = if len == 6 {
+            (Some(parts[2]), 1)
         } else {
             (None, 0)
         };
+        let tags = &parts[2 + index_offset..len];
+        for tag in tags {
+            validate_tag(tag)?;
+        }
 
-        let python_tag = parts[2 + index_offset].to_owned();
-        let abi_tag = parts[3 + index_offset].to_owned();
-        let platform_tag = parts[4 + index_offset].to_owned();
         Ok(Self {
             distribution,
-            version,
+            version: parts[1],
             build_tag,
-            python_tag,
-            abi_tag,
-            platform_tag,
+            python_tag: tags[0],
+            abi_tag: tags[1],
+            platform_tag: tags[2],
         })
     }
 }
@@ -79,11 +521,16 @@ impl FromStr for BuildTag {
 	    return Err(WheelNameParseError::InvalidBuildTag(s.to_owned()));
 	};
 
-        let Ok(number) = captures.name("number").unwrap().as_str().parse::<usize>() else {
+        let (Some(number), Some(raw_remainder)) =
+            (captures.name("number"), captures.name("remainder"))
+        else {
+            return Err(WheelNameParseError::InvalidBuildTag(s.to_owned()));
+        };
+        let Ok(number) = number.as_str().parse::<usize>() else {
 	    return Err(WheelNameParseError::InvalidBuildTag(s.to_owned()));
 	};
         let remainder = {
-            let raw_remainder = captures.name("remainder").unwrap().as_str();
+            let raw_remainder = raw_remainder.as_str();
             if raw_remainder.is_empty() {
                 None
             } else {
@@ -95,7 +542,18 @@ impl FromStr for BuildTag {
     }
 }
 
-#[derive(thiserror::Error, Debug, Eq, PartialEq)]
+impl fmt::Display for BuildTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number)?;
+        if let Some(remainder) = &self.remainder {
+            write!(f, "{remainder}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(thiserror::Error, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum WheelNameParseError {
     #[error("provided file name does not end with a .whl")]
     NotAWheel,
@@ -106,11 +564,23 @@ pub enum WheelNameParseError {
     #[error("invalid distribution name")]
     InvalidDistributionName(String),
 
+    /// The distribution name, the offending character, and its byte offset in the name.
+    #[error("invalid character {1:?} at position {2} in distribution name")]
+    InvalidDistributionNameCharacter(String, char, usize),
+
     #[error("invalid PEP440 version")]
     InvalidVersion(String),
 
     #[error("invalid build tag")]
     InvalidBuildTag(String),
+
+    /// A tag, or one of its `.`-separated components, is empty.
+    #[error("invalid compatibility tag")]
+    InvalidTag(String),
+
+    /// The tag, the offending character, and its byte offset in the tag.
+    #[error("invalid character {1:?} at position {2} in compatibility tag")]
+    InvalidTagCharacter(String, char, usize),
 }
 
 #[cfg(test)]
@@ -212,6 +682,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_preserving_name() -> Result<(), WheelNameParseError> {
+        let filename = "Charset.Normalizer-3.1.0-py3-none-any.whl";
+        let wheel_name = WheelName::parse_with(
+            filename,
+            NameValidation::Legacy,
+            NameCanonicalization::Preserve,
+        )?;
+        assert_eq!(wheel_name.distribution, "Charset.Normalizer");
+        assert_eq!(wheel_name.normalized_distribution(), "charset-normalizer");
+        assert_eq!(wheel_name.escaped_distribution(), "charset_normalizer");
+        assert_eq!(wheel_name.to_string(), filename);
+        assert_eq!(
+            WheelName::from_str(filename)?.to_string(),
+            "charset_normalizer-3.1.0-py3-none-any.whl",
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_local_version() -> Result<(), WheelNameParseError> {
+        for filename in [
+            "torch-2.1.0+cu118-cp311-cp311-linux_x86_64.whl",
+            "torch-2.1.0+cpu.cxx11.abi-cp311-cp311-linux_x86_64.whl",
+            "torch-2.1.0-cp311-cp311-linux_x86_64.whl",
+        ] {
+            assert_eq!(WheelName::from_str(filename)?.to_string(), filename);
+        }
+
+        let wheel_name = WheelName::from_str("torch-2.1.0+cu118-cp311-cp311-linux_x86_64.whl")?;
+        assert_eq!(wheel_name.local_version(), Some("cu118".to_string()));
+        assert_eq!(wheel_name.public_version(), "2.1.0");
+        let wheel_name = WheelName::from_str("torch-2.1.0-cp311-cp311-linux_x86_64.whl")?;
+        assert_eq!(wheel_name.local_version(), None);
+        assert_eq!(wheel_name.public_version(), "2.1.0");
+
+        let (wheel_name, repairs) =
+            WheelName::parse_lenient("torch-2.1.0%2Bcu118-cp311-cp311-linux_x86_64.whl")?;
+        assert_eq!(
+            wheel_name.to_string(),
+            "torch-2.1.0+cu118-cp311-cp311-linux_x86_64.whl"
+        );
+        assert_eq!(
+            repairs,
+            vec![NameRepair::EncodedLocalVersion("2.1.0+cu118".to_string())],
+        );
+        assert!(WheelName::from_str("torch-2.1.0%2Bcu118-cp311-cp311-linux_x86_64.whl").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_from_str_not_wheel() -> Result<(), WheelNameParseError> {
         let wheel_name = WheelName::from_str("charset-normalizer-3.1.0.tar.gz");
@@ -219,6 +739,209 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            WheelName::validate("requests-2.29.0-1-py3-none-any.whl"),
+            Ok(()),
+        );
+        assert_eq!(
+            WheelName::validate("requests-2.29.0.tar.gz"),
+            Err(WheelNameParseError::NotAWheel),
+        );
+        assert_eq!(
+            WheelName::validate("requests-2.29.0-a-b-c-d-e.whl"),
+            Err(WheelNameParseError::PartMismatch),
+        );
+        assert_eq!(
+            WheelName::validate("requests-2.29.0-x1-py3-none-any.whl"),
+            Err(WheelNameParseError::InvalidBuildTag("x1".to_string())),
+        );
+    }
+
+    #[test]
+    fn test_parse_lenient() -> Result<(), WheelNameParseError> {
+        let (name, repairs) = WheelName::parse_lenient("demo-1.0-py3-none-any.whl")?;
+        assert_eq!(name, WheelName::from_str("demo-1.0-py3-none-any.whl")?);
+        assert!(repairs.is_empty());
+
+        let (name, repairs) = WheelName::parse_lenient("foo-bar-1.0-2-py3-none-any.whl")?;
+        assert_eq!(name.distribution, "foo-bar");
+        assert_eq!(name.build_tag.unwrap().number, 2);
+        assert_eq!(
+            repairs,
+            vec![NameRepair::JoinedDistribution("foo-bar".to_string())],
+        );
+
+        let (name, repairs) = WheelName::parse_lenient("demo-1.0-beta-py3-none-any.whl")?;
+        assert_eq!(name.version, Version::from_str("1.0b0").unwrap());
+        assert_eq!(name.build_tag, None);
+        assert_eq!(
+            repairs,
+            vec![NameRepair::JoinedVersion("1.0-beta".to_string())],
+        );
+
+        let (name, repairs) = WheelName::parse_lenient("demo+x-1.0-py3-none-any.whl")?;
+        assert_eq!(name.distribution, "demo+x");
+        assert_eq!(
+            repairs,
+            vec![NameRepair::InvalidDistributionName("demo+x".to_string())],
+        );
+
+        assert!(matches!(
+            WheelName::parse_lenient("demo-nope-py3-none-any.whl"),
+            Err(WheelNameParseError::InvalidVersion(_)),
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_tag_validation() {
+        assert_eq!(
+            WheelName::validate("demo-1.0-py2.py3-none-macosx_10_9_universal2.whl"),
+            Ok(()),
+        );
+        assert_eq!(
+            WheelName::validate("demo-1.0-py2..py3-none-any.whl"),
+            Err(WheelNameParseError::InvalidTag("py2..py3".to_string())),
+        );
+        assert_eq!(
+            WheelName::validate("demo-1.0-py3-none-.whl"),
+            Err(WheelNameParseError::InvalidTag("".to_string())),
+        );
+        assert_eq!(
+            WheelName::from_str("demo-1.0-py3-none-any+linux.whl"),
+            Err(WheelNameParseError::InvalidTagCharacter(
+                "any+linux".to_string(),
+                '+',
+                3,
+            )),
+        );
+    }
+
+    #[test]
+    fn test_parse_wheel_names() {
+        let (names, failures) = parse_wheel_names([
+            "requests-2.29.0-py3-none-any.whl",
+            "requests-2.29.0.tar.gz",
+            "charset_normalizer-3.1.0-py3-none-any.whl",
+        ]);
+        assert_eq!(
+            names
+                .iter()
+                .map(|name| name.distribution.as_str())
+                .collect::<Vec<_>>(),
+            vec!["requests", "charset-normalizer"],
+        );
+        assert_eq!(
+            failures,
+            vec![WheelNameFailure {
+                index: 1,
+                filename: "requests-2.29.0.tar.gz".to_string(),
+                reason: WheelNameParseError::NotAWheel,
+            }],
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() -> Result<(), WheelNameParseError> {
+        for filename in [
+            "requests-2.29.0-py3-none-any.whl",
+            "charset_normalizer-3.1.0-1asdf-py3-none-any.whl",
+            "demo-1.0-12-py3-none-any.whl",
+            "demo-1.0-123rc-py3-none-any.whl",
+        ] {
+            assert_eq!(WheelName::from_str(filename)?.to_string(), filename);
+        }
+        assert_eq!(
+            WheelName::from_str("demo-1.0-12-py3-none-any.whl")?.build_tag,
+            Some(BuildTag {
+                number: 12,
+                remainder: None,
+            }),
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder() -> Result<(), WheelNameParseError> {
+        let wheel_name = WheelName::new(
+            "Charset.Normalizer",
+            Version::from_str("3.1.0").unwrap(),
+            "py3",
+            "none",
+            "any",
+        )?
+        .with_version(Version::from_str("3.2.0").unwrap())
+        .with_tags("cp311", "cp311", "manylinux_2_17_x86_64")?;
+        assert_eq!(
+            wheel_name.to_string(),
+            "charset_normalizer-3.2.0-cp311-cp311-manylinux_2_17_x86_64.whl",
+        );
+
+        assert_eq!(
+            wheel_name.clone().with_tags("py3", "none", "any-thing"),
+            Err(WheelNameParseError::InvalidTagCharacter(
+                "any-thing".to_string(),
+                '-',
+                3,
+            )),
+        );
+        assert_eq!(
+            wheel_name
+                .clone()
+                .with_distribution("Charset-Normalizer")?
+                .distribution,
+            "charset-normalizer",
+        );
+        assert_eq!(
+            wheel_name.with_distribution("charset normalizer"),
+            Err(WheelNameParseError::InvalidDistributionNameCharacter(
+                "charset normalizer".to_string(),
+                ' ',
+                7,
+            )),
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_name_escaping() {
+        assert_eq!(normalize_distribution_name("Foo.._Bar"), "foo-bar");
+        assert_eq!(escape_distribution_name("Foo.._Bar"), "foo_bar");
+        assert_eq!(escape_distribution_name("foo-bar"), "foo_bar");
+        assert_eq!(normalize_distribution_name("foo_bar"), "foo-bar");
+    }
+
+    #[test]
+    fn test_name_validation() -> Result<(), WheelNameParseError> {
+        let wheel_name = WheelName::parse("Foo__Bar-1.0-py3-none-any.whl", NameValidation::Legacy)?;
+        assert_eq!(wheel_name.distribution, "foo-bar");
+        assert_eq!(
+            WheelName::parse("Foo__Bar-1.0-py3-none-any.whl", NameValidation::Strict)?.distribution,
+            "foo-bar",
+        );
+
+        assert_eq!(
+            WheelName::parse("_foo-1.0-py3-none-any.whl", NameValidation::Strict),
+            Err(WheelNameParseError::InvalidDistributionNameCharacter(
+                "_foo".to_string(),
+                '_',
+                0,
+            )),
+        );
+        assert_eq!(
+            WheelName::parse("caf\u{e9}-1.0-py3-none-any.whl", NameValidation::Strict),
+            Err(WheelNameParseError::InvalidDistributionNameCharacter(
+                "caf\u{e9}".to_string(),
+                '\u{e9}',
+                3,
+            )),
+        );
+        assert!(WheelName::parse("caf\u{e9}-1.0-py3-none-any.whl", NameValidation::Legacy).is_ok());
+        Ok(())
+    }
+
     #[test]
     fn test_from_str_kekab() -> Result<(), WheelNameParseError> {
         // Wheel name `distribution` field is not allowed to have a dash in it.
@@ -0,0 +1,269 @@
+//! Experimental: combining pure-Python wheels into a [PEP 441](https://peps.python.org/pep-0441/)
+//! zipapp.
+
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::io::Seek;
+use std::io::Write;
+
+use zip::write::FileOptions;
+use zip::ZipWriter;
+
+use crate::EntryPoint;
+use crate::HashAlgorithm;
+use crate::Wheel;
+use crate::WheelError;
+
+/// Builds a `.pyz` zipapp which runs a `console_scripts` entry point, from the wheels of an
+/// application and its dependencies. This is experimental.
+///
+/// Files at the root of each wheel and in its `.data/purelib/` are placed at the root of the
+/// zipapp, and its `.dist-info` directory is kept so that `importlib.metadata` works. Every
+/// other `.data/` file, e.g. scripts and headers, is dropped. Zipapps can't import extension
+/// modules, so wheels which aren't pure Python fail with [`ZipappError::PlatlibWheel`].
+pub struct ZipappBuilder<R> {
+    wheels: Vec<Wheel<R>>,
+    script: Option<String>,
+    interpreter: Option<String>,
+}
+
+impl<R> Default for ZipappBuilder<R> {
+    fn default() -> Self {
+        Self {
+            wheels: Vec::new(),
+            script: None,
+            interpreter: None,
+        }
+    }
+}
+
+impl<R: Read + Seek> ZipappBuilder<R> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn wheel(mut self, wheel: Wheel<R>) -> Self {
+        self.wheels.push(wheel);
+        self
+    }
+
+    /// The name of the `console_scripts` entry point to run, which is needed when the wheels
+    /// have more than one.
+    pub fn script(mut self, name: &str) -> Self {
+        self.script = Some(name.to_owned());
+        self
+    }
+
+    /// Writes a `#!` line to run the zipapp with, e.g. `/usr/bin/env python3`.
+    pub fn interpreter(mut self, interpreter: &str) -> Self {
+        self.interpreter = Some(interpreter.to_owned());
+        self
+    }
+
+    /// Writes the zipapp to `writer`, and returns the entry point its `__main__.py` runs.
+    ///
+    /// Files which more than one wheel contains must be identical, or this fails with
+    /// [`ZipappError::ConflictingFile`].
+    pub fn build<W: Write + Seek>(mut self, mut writer: W) -> Result<EntryPoint, WheelError> {
+        let mut scripts = Vec::new();
+        for wheel in &mut self.wheels {
+            let platlib = format!("{}platlib/", wheel.data_prefix());
+            if !wheel.wheel_file()?.root_is_purelib
+                || wheel.file_names().any(|path| path.starts_with(&platlib))
+            {
+                return Err(ZipappError::PlatlibWheel(wheel.name.to_string()).into());
+            }
+            scripts.extend(
+                wheel
+                    .entry_points()?
+                    .entry_points
+                    .into_iter()
+                    .filter(|entry_point| entry_point.group == "console_scripts"),
+            );
+        }
+        let entry_point = self.choose_script(scripts)?;
+
+        if let Some(interpreter) = &self.interpreter {
+            writer.write_all(format!("#!{interpreter}\n").as_bytes())?;
+        }
+        let mut zip = ZipWriter::new(writer);
+        zip.start_file("__main__.py", FileOptions::default())?;
+        zip.write_all(main_py(&entry_point).as_bytes())?;
+
+        // The `sha256` digest of every file written so far, to detect conflicts.
+        let mut written: BTreeMap<String, Vec<u8>> = BTreeMap::new();
+        for wheel in &mut self.wheels {
+            let data = wheel.data_prefix();
+            let purelib = format!("{data}purelib/");
+            let paths: Vec<(String, String)> = wheel
+                .file_names()
+                .filter(|path| !path.ends_with('/'))
+                .filter_map(|path| match path.strip_prefix(&purelib) {
+                    Some(file) => Some((path.to_owned(), file.to_owned())),
+                    None if path.starts_with(&data) => None,
+                    None => Some((path.to_owned(), path.to_owned())),
+                })
+                .collect();
+            for (path, new_path) in paths {
+                let contents = wheel.read_entry(&path)?;
+                let digest = HashAlgorithm::Sha256.digest(&contents);
+                match written.get(&new_path) {
+                    Some(existing) if *existing == digest => continue,
+                    Some(_) => return Err(ZipappError::ConflictingFile(new_path).into()),
+                    None if new_path == "__main__.py" => {
+                        return Err(ZipappError::ConflictingFile(new_path).into())
+                    }
+                    None => {}
+                }
+                zip.start_file(new_path.as_str(), FileOptions::default())?;
+                zip.write_all(&contents)?;
+                written.insert(new_path, digest);
+            }
+        }
+        zip.finish()?;
+        Ok(entry_point)
+    }
+
+    fn choose_script(&self, mut scripts: Vec<EntryPoint>) -> Result<EntryPoint, ZipappError> {
+        match &self.script {
+            Some(name) => scripts
+                .into_iter()
+                .find(|entry_point| entry_point.name == *name)
+                .ok_or_else(|| ZipappError::MissingScript(name.clone())),
+            None if scripts.len() == 1 => Ok(scripts.remove(0)),
+            None if scripts.is_empty() => Err(ZipappError::NoScripts),
+            None => Err(ZipappError::AmbiguousScript(
+                scripts
+                    .into_iter()
+                    .map(|entry_point| entry_point.name)
+                    .collect(),
+            )),
+        }
+    }
+}
+
+/// A `__main__.py` which calls `entry_point` like the scripts installers generate.
+fn main_py(entry_point: &EntryPoint) -> String {
+    let module = entry_point.module();
+    match entry_point.attr() {
+        Some(attr) => {
+            let head = attr.split('.').next().unwrap_or(attr);
+            format!("import sys\n\nfrom {module} import {head}\n\nsys.exit({attr}())\n")
+        }
+        None => format!("import runpy\n\nrunpy.run_module({module:?}, run_name=\"__main__\")\n"),
+    }
+}
+
+#[derive(thiserror::Error, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ZipappError {
+    /// The name of the wheel, which has files which must be installed to platlib.
+    #[error("zipapps can only contain pure Python wheels")]
+    PlatlibWheel(String),
+
+    #[error("no wheel has a console_scripts entry point")]
+    NoScripts,
+
+    /// The requested script.
+    #[error("no wheel has the requested console_scripts entry point")]
+    MissingScript(String),
+
+    /// The names of every script, one of which must be chosen.
+    #[error("more than one console_scripts entry point, choose one with a script name")]
+    AmbiguousScript(Vec<String>),
+
+    /// A path which more than one wheel has with different contents.
+    #[error("wheels have different files at the same path")]
+    ConflictingFile(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use zip::ZipArchive;
+
+    use super::*;
+    use crate::WheelBuilder;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_build_zipapp() -> Result<(), WheelError> {
+        let demo = WheelBuilder::new("demo-1.0-py3-none-any.whl")?
+            .file("demo/__init__.py", "")
+            .file("demo/cli.py", "def main(): pass\n")
+            .file(
+                "demo-1.0.dist-info/entry_points.txt",
+                "[console_scripts]\ndemo = demo.cli:main\n",
+            );
+        let helper = WheelBuilder::new("helper-2.0-py3-none-any.whl")?
+            .file("helper-2.0.data/purelib/helper.py", "")
+            .file("helper-2.0.data/scripts/helper", "#!python");
+
+        let mut zipapp = Cursor::new(Vec::new());
+        let entry_point = ZipappBuilder::new()
+            .wheel(demo.open()?)
+            .wheel(helper.open()?)
+            .interpreter("/usr/bin/env python3")
+            .build(&mut zipapp)?;
+        assert_eq!(entry_point.value, "demo.cli:main");
+
+        let zipapp = zipapp.into_inner();
+        assert!(zipapp.starts_with(b"#!/usr/bin/env python3\n"));
+        let mut archive = ZipArchive::new(Cursor::new(zipapp))?;
+        let mut main = String::new();
+        archive.by_name("__main__.py")?.read_to_string(&mut main)?;
+        assert_eq!(
+            main,
+            "import sys\n\nfrom demo.cli import main\n\nsys.exit(main())\n"
+        );
+        let mut file_names = archive.file_names().collect::<Vec<_>>();
+        file_names.sort_unstable();
+        assert_eq!(
+            file_names,
+            [
+                "__main__.py",
+                "demo-1.0.dist-info/METADATA",
+                "demo-1.0.dist-info/RECORD",
+                "demo-1.0.dist-info/WHEEL",
+                "demo-1.0.dist-info/entry_points.txt",
+                "demo/__init__.py",
+                "demo/cli.py",
+                "helper-2.0.dist-info/METADATA",
+                "helper-2.0.dist-info/RECORD",
+                "helper-2.0.dist-info/WHEEL",
+                "helper.py",
+            ],
+        );
+
+        assert!(matches!(
+            ZipappBuilder::new()
+                .wheel(demo.open()?)
+                .script("missing")
+                .build(Cursor::new(Vec::new())),
+            Err(WheelError::ZipappError(ZipappError::MissingScript(name))) if name == "missing",
+        ));
+        assert!(matches!(
+            ZipappBuilder::new()
+                .wheel(helper.open()?)
+                .build(Cursor::new(Vec::new())),
+            Err(WheelError::ZipappError(ZipappError::NoScripts)),
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_zipapp_platlib() -> Result<(), WheelError> {
+        let native = WheelBuilder::new("native-1.0-cp311-cp311-linux_x86_64.whl")?
+            .file("native.so", "")
+            .root_is_purelib(false)
+            .open()?;
+        assert!(matches!(
+            ZipappBuilder::new().wheel(native).build(Cursor::new(Vec::new())),
+            Err(WheelError::ZipappError(ZipappError::PlatlibWheel(name)))
+                if name == "native-1.0-cp311-cp311-linux_x86_64.whl",
+        ));
+        Ok(())
+    }
+}